@@ -1,9 +1,13 @@
 use crate::input_validation::{
-    validate_cluster_name, validate_context_name, validate_description, validate_tags,
+    validate_cluster_name, validate_context_name, validate_description, validate_group_name,
+    validate_namespace_name, validate_tags,
 };
-use rusqlite::{params, Connection, OptionalExtension};
+use crate::settings::AppSettings;
+use kube::config::Kubeconfig;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -18,10 +22,322 @@ pub struct Cluster {
     pub tags: String, // JSON-encoded array
     pub created_at: i64,
     pub last_accessed: i64,
+    /// Kubernetes version reported by the last successful health check, e.g. `v1.31.2`
+    pub server_version: Option<String>,
+    /// Pinned clusters are listed before all others
+    pub pinned: bool,
+    /// Workspace the cluster is organized under, e.g. "prod" or "personal"
+    pub group_name: Option<String>,
+    /// Namespace the UI opens this cluster in. Only a preference: clients still
+    /// use the context's own namespace.
+    pub default_namespace: Option<String>,
+    /// Connect without verifying the API server's certificate, for self-signed
+    /// clusters whose kubeconfig doesn't say `insecure-skip-tls-verify` itself
+    pub accept_invalid_certs: bool,
+}
+
+/// A cluster group and how many clusters are in it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ClusterGroup {
+    pub name: String,
+    pub cluster_count: i64,
+}
+
+/// Result of checking a stored cluster's config file against the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterValidation {
+    pub id: String,
+    pub ok: bool,
+    pub issue: Option<String>,
+}
+
+/// Check that a cluster's extracted config exists and holds exactly its context
+fn check_cluster_config(cluster: &Cluster) -> Option<String> {
+    let config_path = PathBuf::from(&cluster.config_path);
+    if !config_path.exists() {
+        return Some(format!("Config file not found: {:?}", config_path));
+    }
+
+    let kubeconfig = match Kubeconfig::read_from(&config_path) {
+        Ok(kubeconfig) => kubeconfig,
+        Err(e) => return Some(format!("Failed to read kubeconfig: {}", e)),
+    };
+
+    if kubeconfig.contexts.len() != 1 {
+        return Some(format!(
+            "Expected a single context, found {}",
+            kubeconfig.contexts.len()
+        ));
+    }
+    if kubeconfig.contexts[0].name != cluster.context_name {
+        return Some(format!(
+            "Config contains context '{}' instead of '{}'",
+            kubeconfig.contexts[0].name, cluster.context_name
+        ));
+    }
+    if kubeconfig.current_context.as_deref() != Some(cluster.context_name.as_str()) {
+        return Some("Current context is missing or does not match".to_string());
+    }
+
+    None
+}
+
+/// A cluster entry that shares its API server with other entries
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateClusterEntry {
+    pub id: String,
+    pub name: String,
+    pub context_name: String,
+}
+
+/// Clusters whose configs point at the same API server
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateClusterGroup {
+    pub server: String,
+    pub clusters: Vec<DuplicateClusterEntry>,
+}
+
+/// An extracted kubeconfig in the kubeconfigs dir that no cluster row references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OrphanedConfig {
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+/// Connection details of a saved cluster's context, for the cluster detail panel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ClusterContextInfo {
+    pub cluster_name: String,
+    pub server: Option<String>,
+    pub user: Option<String>,
+    pub namespace: Option<String>,
+    pub insecure_skip_tls_verify: bool,
+}
+
+/// Copy a cluster's saved config to `dest`, a new file outside `protected_dir`
+fn export_kubeconfig(
+    cluster: &Cluster,
+    dest: &Path,
+    protected_dir: &Path,
+) -> Result<PathBuf, String> {
+    let config_path = Path::new(&cluster.config_path);
+    if !config_path.is_file() {
+        return Err(format!("Config file not found: {:?}", config_path));
+    }
+
+    let dest = crate::config::validate_new_file_destination(dest)?;
+    let protected_dir = protected_dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve kubeconfigs directory: {}", e))?;
+    if dest.starts_with(&protected_dir) {
+        return Err("Cannot export into the app's kubeconfigs directory".to_string());
+    }
+
+    std::fs::copy(config_path, &dest).map_err(|e| format!("Failed to export config: {}", e))?;
+    crate::config::set_owner_only_file_permissions(&dest)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    Ok(dest)
+}
+
+/// Read the connection details of a cluster's context from its config file
+fn read_context_info(cluster: &Cluster) -> Result<ClusterContextInfo, String> {
+    let config_path = Path::new(&cluster.config_path);
+    if !config_path.exists() {
+        return Err(format!("Config file not found: {:?}", config_path));
+    }
+    let kubeconfig = Kubeconfig::read_from(config_path)
+        .map_err(|e| format!("Failed to read kubeconfig {:?}: {}", config_path, e))?;
+
+    let context = kubeconfig
+        .contexts
+        .iter()
+        .find(|c| c.name == cluster.context_name)
+        .and_then(|c| c.context.as_ref())
+        .ok_or_else(|| format!("Context '{}' not found in config", cluster.context_name))?;
+    let server_cluster = kubeconfig
+        .clusters
+        .iter()
+        .find(|c| c.name == context.cluster)
+        .and_then(|c| c.cluster.as_ref());
+
+    Ok(ClusterContextInfo {
+        cluster_name: context.cluster.clone(),
+        server: server_cluster.and_then(|c| c.server.clone()),
+        user: context.user.clone(),
+        namespace: context.namespace.clone(),
+        insecure_skip_tls_verify: server_cluster
+            .and_then(|c| c.insecure_skip_tls_verify)
+            .unwrap_or(false),
+    })
+}
+
+/// Resolve the API server URL of a cluster's context from its config file
+fn read_cluster_server(cluster: &Cluster) -> Option<String> {
+    let kubeconfig = Kubeconfig::read_from(&cluster.config_path).ok()?;
+    context_server(&kubeconfig, &cluster.context_name)
+}
+
+/// The normalized API server URL that `context_name` points at
+pub(crate) fn context_server(kubeconfig: &Kubeconfig, context_name: &str) -> Option<String> {
+    let context = kubeconfig
+        .contexts
+        .iter()
+        .find(|c| c.name == context_name)?;
+    let cluster_name = &context.context.as_ref()?.cluster;
+    let server = kubeconfig
+        .clusters
+        .iter()
+        .find(|c| &c.name == cluster_name)?
+        .cluster
+        .as_ref()?
+        .server
+        .clone()?;
+
+    // `https://Host:6443/` and `https://host:6443` are the same server
+    Some(server.trim_end_matches('/').to_lowercase())
 }
 
 pub struct ClusterManager {
     conn: Mutex<Connection>,
+    db_path: PathBuf,
+}
+
+/// Current schema version, stored in the database's `user_version` pragma
+const SCHEMA_VERSION: i64 = 7;
+
+/// Create any missing tables and stamp the schema version
+fn init_schema(conn: &Connection) -> Result<(), String> {
+    // Create clusters table if it doesn't exist
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS clusters (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            context_name TEXT NOT NULL,
+            config_path TEXT NOT NULL,
+            icon TEXT,
+            description TEXT,
+            tags TEXT NOT NULL DEFAULT '[]',
+            created_at INTEGER NOT NULL,
+            last_accessed INTEGER NOT NULL
+        )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create clusters table: {}", e))?;
+
+    // Added in schema version 2
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create settings table: {}", e))?;
+
+    // Added in schema version 3
+    add_column_if_missing(conn, "clusters", "server_version", "TEXT")?;
+    // Added in schema version 4
+    add_column_if_missing(conn, "clusters", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    // Added in schema version 5
+    add_column_if_missing(conn, "clusters", "group_name", "TEXT")?;
+    // Added in schema version 6
+    add_column_if_missing(conn, "clusters", "default_namespace", "TEXT")?;
+    // Added in schema version 7
+    add_column_if_missing(
+        conn,
+        "clusters",
+        "accept_invalid_certs",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+
+    conn.execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))
+        .map_err(|e| format!("Failed to set schema version: {}", e))?;
+
+    Ok(())
+}
+
+/// Add a column to a table created by an older schema version. Columns added this
+/// way must be nullable or have a default so existing rows stay valid.
+fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("Failed to read {} columns: {}", table, e))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Failed to read {} columns: {}", table, e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read {} columns: {}", table, e))?
+        .iter()
+        .any(|name| name == column);
+    if exists {
+        return Ok(());
+    }
+
+    conn.execute(
+        &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+        [],
+    )
+    .map_err(|e| format!("Failed to add {}.{} column: {}", table, column, e))?;
+
+    Ok(())
+}
+
+/// Columns read into a `Cluster`, in the order `cluster_from_row` expects
+const CLUSTER_COLUMNS: &str = "id, name, context_name, config_path, icon, description, tags, \
+    created_at, last_accessed, server_version, pinned, group_name, default_namespace, \
+    accept_invalid_certs";
+
+fn cluster_from_row(row: &rusqlite::Row) -> rusqlite::Result<Cluster> {
+    Ok(Cluster {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        context_name: row.get(2)?,
+        config_path: row.get(3)?,
+        icon: row.get(4)?,
+        description: row.get(5)?,
+        tags: row.get(6)?,
+        created_at: row.get(7)?,
+        last_accessed: row.get(8)?,
+        server_version: row.get(9)?,
+        pinned: row.get(10)?,
+        group_name: row.get(11)?,
+        default_namespace: row.get(12)?,
+        accept_invalid_certs: row.get(13)?,
+    })
+}
+
+fn read_schema_version(conn: &Connection) -> Result<i64, String> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+        .map_err(|e| format!("Failed to read schema version: {}", e))
+}
+
+/// Check that a file is a clusters database this version of the app can load.
+/// Returns the schema version found in the file.
+pub fn validate_backup(path: &Path) -> Result<i64, String> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Failed to open backup: {}", e))?;
+
+    let version = read_schema_version(&conn)
+        .map_err(|_| "File is not a valid clusters database".to_string())?;
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "Backup schema version {} is newer than supported version {}",
+            version, SCHEMA_VERSION
+        ));
+    }
+
+    conn.query_row("SELECT COUNT(*) FROM clusters", [], |row| {
+        row.get::<_, i64>(0)
+    })
+    .map_err(|_| "File is not a valid clusters database".to_string())?;
+
+    Ok(version)
 }
 
 impl ClusterManager {
@@ -29,28 +345,61 @@ impl ClusterManager {
         let conn =
             Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-        // Create clusters table if it doesn't exist
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS clusters (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                context_name TEXT NOT NULL,
-                config_path TEXT NOT NULL,
-                icon TEXT,
-                description TEXT,
-                tags TEXT NOT NULL DEFAULT '[]',
-                created_at INTEGER NOT NULL,
-                last_accessed INTEGER NOT NULL
-            )",
-            [],
-        )
-        .map_err(|e| format!("Failed to create clusters table: {}", e))?;
+        init_schema(&conn)?;
 
         Ok(ClusterManager {
             conn: Mutex::new(conn),
+            db_path,
         })
     }
 
+    /// Write a consistent snapshot of the database to `dest`
+    pub fn backup_to(&self, dest: &Path) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute(
+            "VACUUM INTO ?1",
+            params![dest.to_string_lossy().to_string()],
+        )
+        .map_err(|e| format!("Failed to back up database: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Replace the live database with a validated backup and reload it
+    pub fn restore_from(&self, src: &Path) -> Result<(), String> {
+        validate_backup(src)?;
+
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+
+        // Stage the copy next to the live database so the final swap is a rename
+        let staging = self.db_path.with_extension("db.restore");
+        std::fs::copy(src, &staging).map_err(|e| format!("Failed to stage backup: {}", e))?;
+
+        // Release the live database file before swapping it out
+        *conn = Connection::open_in_memory()
+            .map_err(|e| format!("Failed to release database: {}", e))?;
+
+        let swap_result = std::fs::rename(&staging, &self.db_path)
+            .map_err(|e| format!("Failed to restore database: {}", e));
+        if swap_result.is_err() {
+            let _ = std::fs::remove_file(&staging);
+        }
+
+        // Reopen whichever database is now in place, upgrading an older schema if needed
+        let reopened = Connection::open(&self.db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))?;
+        init_schema(&reopened)?;
+        *conn = reopened;
+
+        swap_result
+    }
+
     pub fn add_cluster(
         &self,
         name: String,
@@ -59,11 +408,13 @@ impl ClusterManager {
         icon: Option<String>,
         description: Option<String>,
         tags: Vec<String>,
+        group_name: Option<String>,
     ) -> Result<Cluster, String> {
         let name = validate_cluster_name(name)?;
         let context_name = validate_context_name(context_name)?;
         let description = validate_description(description)?;
         let tags = validate_tags(tags)?;
+        let group_name = validate_group_name(group_name)?;
 
         let id = Uuid::new_v4().to_string();
         let now = std::time::SystemTime::now()
@@ -81,8 +432,8 @@ impl ClusterManager {
             .lock()
             .map_err(|e| format!("Database lock poisoned: {}", e))?;
         conn.execute(
-            "INSERT INTO clusters (id, name, context_name, config_path, icon, description, tags, created_at, last_accessed)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            "INSERT INTO clusters (id, name, context_name, config_path, icon, description, tags, created_at, last_accessed, group_name)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             params![
                 &id,
                 &name,
@@ -93,6 +444,7 @@ impl ClusterManager {
                 &tags_json,
                 now,
                 now,
+                &group_name,
             ],
         )
         .map_err(|e| format!("Failed to insert cluster: {}", e))?;
@@ -107,6 +459,11 @@ impl ClusterManager {
             tags: tags_json,
             created_at: now,
             last_accessed: now,
+            server_version: None,
+            pinned: false,
+            group_name,
+            default_namespace: None,
+            accept_invalid_certs: false,
         })
     }
 
@@ -116,23 +473,14 @@ impl ClusterManager {
             .lock()
             .map_err(|e| format!("Database lock poisoned: {}", e))?;
         let mut stmt = conn
-            .prepare("SELECT id, name, context_name, config_path, icon, description, tags, created_at, last_accessed FROM clusters ORDER BY last_accessed DESC")
+            .prepare(&format!(
+                "SELECT {} FROM clusters ORDER BY pinned DESC, last_accessed DESC",
+                CLUSTER_COLUMNS
+            ))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let clusters = stmt
-            .query_map([], |row| {
-                Ok(Cluster {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    context_name: row.get(2)?,
-                    config_path: row.get(3)?,
-                    icon: row.get(4)?,
-                    description: row.get(5)?,
-                    tags: row.get(6)?,
-                    created_at: row.get(7)?,
-                    last_accessed: row.get(8)?,
-                })
-            })
+            .query_map([], cluster_from_row)
             .map_err(|e| format!("Failed to query clusters: {}", e))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("Failed to collect clusters: {}", e))?;
@@ -146,23 +494,14 @@ impl ClusterManager {
             .lock()
             .map_err(|e| format!("Database lock poisoned: {}", e))?;
         let mut stmt = conn
-            .prepare("SELECT id, name, context_name, config_path, icon, description, tags, created_at, last_accessed FROM clusters WHERE id = ?1")
+            .prepare(&format!(
+                "SELECT {} FROM clusters WHERE id = ?1",
+                CLUSTER_COLUMNS
+            ))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let cluster = stmt
-            .query_row([id], |row| {
-                Ok(Cluster {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    context_name: row.get(2)?,
-                    config_path: row.get(3)?,
-                    icon: row.get(4)?,
-                    description: row.get(5)?,
-                    tags: row.get(6)?,
-                    created_at: row.get(7)?,
-                    last_accessed: row.get(8)?,
-                })
-            })
+            .query_row([id], cluster_from_row)
             .optional()
             .map_err(|e| format!("Failed to query cluster: {}", e))?;
 
@@ -176,6 +515,8 @@ impl ClusterManager {
         icon: Option<Option<String>>,
         description: Option<Option<String>>,
         tags: Option<Vec<String>>,
+        group_name: Option<Option<String>>,
+        default_namespace: Option<Option<String>>,
     ) -> Result<(), String> {
         let conn = self
             .conn
@@ -211,6 +552,18 @@ impl ClusterManager {
             params.push(Box::new(tags_json));
         }
 
+        if let Some(group_val) = group_name {
+            let group_val = validate_group_name(group_val)?;
+            updates.push("group_name = ?");
+            params.push(Box::new(group_val));
+        }
+
+        if let Some(namespace_val) = default_namespace {
+            let namespace_val = validate_namespace_name(namespace_val)?;
+            updates.push("default_namespace = ?");
+            params.push(Box::new(namespace_val));
+        }
+
         if updates.is_empty() {
             return Ok(());
         }
@@ -245,6 +598,247 @@ impl ClusterManager {
         Ok(())
     }
 
+    pub fn update_server_version(&self, id: &str, version: &str) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute(
+            "UPDATE clusters SET server_version = ?1 WHERE id = ?2",
+            params![version, id],
+        )
+        .map_err(|e| format!("Failed to update server_version: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Distinct group names in use, with how many clusters each holds
+    pub fn list_groups(&self) -> Result<Vec<ClusterGroup>, String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT group_name, COUNT(*) FROM clusters WHERE group_name IS NOT NULL
+                 GROUP BY group_name ORDER BY group_name",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let groups = stmt
+            .query_map([], |row| {
+                Ok(ClusterGroup {
+                    name: row.get(0)?,
+                    cluster_count: row.get(1)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query groups: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect groups: {}", e))?;
+
+        Ok(groups)
+    }
+
+    pub fn set_cluster_pinned(&self, id: &str, pinned: bool) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute(
+            "UPDATE clusters SET pinned = ?1 WHERE id = ?2",
+            params![pinned, id],
+        )
+        .map_err(|e| format!("Failed to update pinned: {}", e))?;
+
+        Ok(())
+    }
+
+    pub fn set_accept_invalid_certs(&self, id: &str, accept: bool) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute(
+            "UPDATE clusters SET accept_invalid_certs = ?1 WHERE id = ?2",
+            params![accept, id],
+        )
+        .map_err(|e| format!("Failed to update accept_invalid_certs: {}", e))?;
+
+        Ok(())
+    }
+
+    pub fn update_config_path(&self, id: &str, config_path: &Path) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute(
+            "UPDATE clusters SET config_path = ?1 WHERE id = ?2",
+            params![config_path.to_string_lossy().to_string(), id],
+        )
+        .map_err(|e| format!("Failed to update config_path: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Check every stored cluster's config file for drift from the database
+    pub fn validate_clusters(&self) -> Result<Vec<ClusterValidation>, String> {
+        let clusters = self.list_clusters()?;
+
+        Ok(clusters
+            .iter()
+            .map(|cluster| {
+                let issue = check_cluster_config(cluster);
+                ClusterValidation {
+                    id: cluster.id.clone(),
+                    ok: issue.is_none(),
+                    issue,
+                }
+            })
+            .collect())
+    }
+
+    /// The API server URL from a saved cluster's config, or `None` when the
+    /// config can't be read
+    pub fn cluster_server(&self, id: &str) -> Result<Option<String>, String> {
+        let cluster = self
+            .get_cluster(id)?
+            .ok_or_else(|| format!("Cluster '{}' not found", id))?;
+        Ok(read_cluster_server(&cluster))
+    }
+
+    /// Group clusters by API server, keeping only servers with more than one entry.
+    /// Clusters whose config can't be read are left out.
+    pub fn find_duplicate_clusters(&self) -> Result<Vec<DuplicateClusterGroup>, String> {
+        let clusters = self.list_clusters()?;
+
+        let mut by_server: BTreeMap<String, Vec<DuplicateClusterEntry>> = BTreeMap::new();
+        for cluster in &clusters {
+            if let Some(server) = read_cluster_server(cluster) {
+                by_server
+                    .entry(server)
+                    .or_default()
+                    .push(DuplicateClusterEntry {
+                        id: cluster.id.clone(),
+                        name: cluster.name.clone(),
+                        context_name: cluster.context_name.clone(),
+                    });
+            }
+        }
+
+        Ok(by_server
+            .into_iter()
+            .filter(|(_, clusters)| clusters.len() > 1)
+            .map(|(server, clusters)| DuplicateClusterGroup { server, clusters })
+            .collect())
+    }
+
+    /// `*.yaml` files in `dir` whose stem isn't a known cluster id and that no
+    /// cluster's `config_path` points at
+    pub fn find_orphaned_configs(&self, dir: &Path) -> Result<Vec<OrphanedConfig>, String> {
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let clusters = self.list_clusters()?;
+        let referenced: Vec<PathBuf> = clusters
+            .iter()
+            .map(|c| PathBuf::from(&c.config_path))
+            .collect();
+
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read kubeconfigs directory: {}", e))?;
+
+        let mut orphans = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some("yaml") {
+                continue;
+            }
+
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            if clusters.iter().any(|c| c.id == stem) || referenced.contains(&path) {
+                continue;
+            }
+
+            orphans.push(OrphanedConfig {
+                path: path.to_string_lossy().to_string(),
+                size_bytes: entry.metadata().map(|m| m.len()).unwrap_or(0),
+            });
+        }
+
+        orphans.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(orphans)
+    }
+
+    pub fn get_settings(&self) -> Result<AppSettings, String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        let mut stmt = conn
+            .prepare("SELECT key, value FROM settings")
+            .map_err(|e| format!("Failed to prepare query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| format!("Failed to query settings: {}", e))?
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| format!("Failed to read settings: {}", e))?;
+
+        Ok(AppSettings::from_rows(&rows))
+    }
+
+    pub fn set_settings(&self, settings: &AppSettings) -> Result<(), String> {
+        settings.validate()?;
+
+        let mut conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+        for (key, value) in settings.to_rows() {
+            tx.execute(
+                "INSERT INTO settings (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                params![key, value],
+            )
+            .map_err(|e| format!("Failed to save setting '{}': {}", key, e))?;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Run `f` in one transaction so a batch of writes is committed together.
+    /// A statement that fails inside `f` only undoes itself; if the commit fails
+    /// nothing from the batch is kept.
+    pub fn in_transaction<T>(&self, f: impl FnOnce(&Self) -> T) -> Result<T, String> {
+        self.execute_batch("BEGIN")?;
+        let result = f(self);
+        if let Err(e) = self.execute_batch("COMMIT") {
+            let _ = self.execute_batch("ROLLBACK");
+            return Err(e);
+        }
+        Ok(result)
+    }
+
+    fn execute_batch(&self, sql: &str) -> Result<(), String> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| format!("Database lock poisoned: {}", e))?;
+        conn.execute_batch(sql)
+            .map_err(|e| format!("Failed to run {}: {}", sql, e))
+    }
+
     pub fn delete_cluster(&self, id: &str) -> Result<(), String> {
         let conn = self
             .conn
@@ -258,9 +852,17 @@ impl ClusterManager {
 }
 
 // Tauri commands
+use crate::k8s::client::{check_cluster_health, ClientCache, ClusterHealth};
+use crate::k8s::limiter::RequestLimiterState;
 use tauri::State;
 
-pub struct ClusterManagerState(pub Arc<Mutex<ClusterManager>>);
+/// The cluster database (`.0`), the clients built from its configs (`.1`) and the
+/// per-cluster request limits those clients share (`.2`)
+pub struct ClusterManagerState(
+    pub Arc<Mutex<ClusterManager>>,
+    pub Arc<ClientCache>,
+    pub Arc<RequestLimiterState>,
+);
 
 #[tauri::command]
 pub fn db_list_clusters(state: State<ClusterManagerState>) -> Result<Vec<Cluster>, String> {
@@ -290,75 +892,396 @@ pub fn db_update_cluster(
     icon: Option<Option<String>>,
     description: Option<Option<String>>,
     tags: Option<Vec<String>>,
+    group_name: Option<Option<String>>,
+    default_namespace: Option<Option<String>>,
     state: State<ClusterManagerState>,
 ) -> Result<(), String> {
     let manager = state
         .0
         .lock()
         .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-    manager.update_cluster(&id, name, icon, description, tags)
+    state.1.invalidate(&id);
+    // The icon being replaced, so a stored file it points at can be removed once the
+    // new value is saved
+    let replaced_icon = match &icon {
+        Some(new_icon) => manager
+            .get_cluster(&id)?
+            .and_then(|cluster| cluster.icon)
+            .filter(|old_icon| Some(old_icon) != new_icon.as_ref()),
+        None => None,
+    };
+    manager.update_cluster(
+        &id,
+        name,
+        icon,
+        description,
+        tags,
+        group_name,
+        default_namespace,
+    )?;
+    if let Some(old_icon) = replaced_icon {
+        crate::image_utils::remove_stored_icon(&old_icon, &crate::config::get_icons_dir());
+    }
+    Ok(())
 }
 
+/// Set the namespace the UI opens a cluster in; `None` clears it
 #[tauri::command]
-pub fn db_update_last_accessed(
+pub fn db_set_cluster_default_namespace(
     id: String,
+    namespace: Option<String>,
     state: State<ClusterManagerState>,
 ) -> Result<(), String> {
     let manager = state
         .0
         .lock()
         .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-    manager.update_last_accessed(&id)
+    manager.update_cluster(&id, None, None, None, None, None, Some(namespace))
 }
 
 #[tauri::command]
-pub fn db_delete_cluster(id: String, state: State<ClusterManagerState>) -> Result<(), String> {
+pub fn db_list_groups(state: State<ClusterManagerState>) -> Result<Vec<ClusterGroup>, String> {
     let manager = state
         .0
         .lock()
         .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-
-    // Get cluster to find config file path
-    if let Some(cluster) = manager.get_cluster(&id)? {
-        // Delete the config file
-        let config_path = PathBuf::from(&cluster.config_path);
-
-        // Validate the path before deletion to prevent path traversal
-        if let Ok(validated_path) = crate::config::validate_kubeconfig_path(&config_path) {
-            // Attempt to delete the file, ignore NotFound errors (file already deleted)
-            match std::fs::remove_file(&validated_path) {
-                Ok(_) => {}
-                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
-                Err(e) => return Err(format!("Failed to delete config file: {}", e)),
-            }
-        } else {
-            // Log warning but don't fail the deletion if path validation fails
-            eprintln!(
-                "Warning: Could not validate path for deletion: {:?}",
-                config_path
-            );
-        }
-    }
-
-    // Delete from database
-    manager.delete_cluster(&id)
+    manager.list_groups()
 }
 
 #[tauri::command]
-pub fn db_migrate_legacy_configs(state: State<ClusterManagerState>) -> Result<Vec<String>, String> {
-    use crate::import::{discover_contexts_in_folder, extract_context};
-
+pub fn db_update_last_accessed(
+    id: String,
+    state: State<ClusterManagerState>,
+) -> Result<(), String> {
     let manager = state
         .0
         .lock()
         .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-    let kubeconfigs_dir = crate::config::get_kubeconfigs_dir();
-
-    if !kubeconfigs_dir.exists() {
-        return Ok(vec![]); // No legacy configs to migrate
-    }
+    manager.update_last_accessed(&id)
+}
 
-    // Discover all contexts from the kubeconfigs directory
+#[tauri::command]
+pub fn db_set_cluster_pinned(
+    id: String,
+    pinned: bool,
+    state: State<ClusterManagerState>,
+) -> Result<(), String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.set_cluster_pinned(&id, pinned)
+}
+
+/// Connect to a cluster without verifying its certificate, or verify again.
+///
+/// Only for self-signed clusters that can't be given a proper CA: with verification
+/// off, anyone able to intercept the connection can impersonate the API server and
+/// read the credentials and data sent to it. Prefer adding the cluster's CA
+/// (`certificate-authority-data`) to its kubeconfig.
+#[tauri::command]
+pub fn db_set_cluster_accept_invalid_certs(
+    id: String,
+    accept: bool,
+    state: State<ClusterManagerState>,
+) -> Result<(), String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.set_accept_invalid_certs(&id, accept)?;
+    state.1.invalidate(&id);
+    Ok(())
+}
+
+/// Health-check the cluster and, when it answers, store the version it reports
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn db_refresh_cluster_version(
+    id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ClusterHealth, String> {
+    let health = check_cluster_health(&id, &state).await;
+
+    if let Some(version) = health.server_version.as_deref() {
+        let manager = state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+        manager.update_server_version(&id, version)?;
+    }
+
+    Ok(health)
+}
+
+#[tauri::command]
+pub fn db_delete_cluster(id: String, state: State<ClusterManagerState>) -> Result<(), String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+
+    // Get cluster to find config file path
+    if let Some(cluster) = manager.get_cluster(&id)? {
+        // Delete the config file
+        let config_path = PathBuf::from(&cluster.config_path);
+
+        // Validate the path before deletion to prevent path traversal
+        if let Ok(validated_path) = crate::config::validate_kubeconfig_path(&config_path) {
+            // Attempt to delete the file, ignore NotFound errors (file already deleted)
+            match std::fs::remove_file(&validated_path) {
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(format!("Failed to delete config file: {}", e)),
+            }
+        } else {
+            // Log warning but don't fail the deletion if path validation fails
+            eprintln!(
+                "Warning: Could not validate path for deletion: {:?}",
+                config_path
+            );
+        }
+
+        // Remove a stored icon file; failing to do so does not block the deletion
+        if let Some(icon) = cluster.icon.as_deref() {
+            crate::image_utils::remove_stored_icon(icon, &crate::config::get_icons_dir());
+        }
+    }
+
+    // Delete from database
+    state.1.invalidate(&id);
+    manager.delete_cluster(&id)
+}
+
+#[tauri::command]
+pub fn db_validate_clusters(
+    state: State<ClusterManagerState>,
+) -> Result<Vec<ClusterValidation>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.validate_clusters()
+}
+
+/// Groups of stored clusters that point at the same API server
+#[tauri::command]
+pub fn db_find_duplicate_clusters(
+    state: State<ClusterManagerState>,
+) -> Result<Vec<DuplicateClusterGroup>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.find_duplicate_clusters()
+}
+
+/// Extracted kubeconfig files left behind without a cluster row
+#[tauri::command]
+pub fn db_find_orphaned_configs(
+    state: State<ClusterManagerState>,
+) -> Result<Vec<OrphanedConfig>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.find_orphaned_configs(&crate::config::get_kubeconfigs_dir())
+}
+
+/// Delete orphaned kubeconfig files, returning the paths removed
+#[tauri::command]
+pub fn db_delete_orphaned_configs(
+    state: State<ClusterManagerState>,
+) -> Result<Vec<String>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+
+    let mut deleted = Vec::new();
+    for orphan in manager.find_orphaned_configs(&crate::config::get_kubeconfigs_dir())? {
+        // Validate the path before deletion to prevent path traversal
+        let validated_path = crate::config::validate_kubeconfig_path(&PathBuf::from(&orphan.path))?;
+        match std::fs::remove_file(&validated_path) {
+            Ok(_) => deleted.push(orphan.path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("Failed to delete config file: {}", e)),
+        }
+    }
+
+    Ok(deleted)
+}
+
+/// Re-extract a cluster's recorded context from its config file, producing a clean
+/// single-context `<id>.yaml` with the correct current-context
+#[tauri::command]
+pub fn db_normalize_cluster_config(
+    id: String,
+    state: State<ClusterManagerState>,
+) -> Result<Cluster, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let cluster = normalize_cluster_config(&manager, &id, &crate::config::get_kubeconfigs_dir())?;
+    state.1.invalidate(&cluster.id);
+    Ok(cluster)
+}
+
+/// Rewrite a cluster's config as `<id>.yaml` in `kubeconfigs_dir` and point the cluster
+/// at it. A previous config with another name inside that directory is removed;
+/// files elsewhere (e.g. ~/.kube/config) are the user's and are left alone.
+fn normalize_cluster_config(
+    manager: &ClusterManager,
+    id: &str,
+    kubeconfigs_dir: &Path,
+) -> Result<Cluster, String> {
+    let cluster = manager
+        .get_cluster(id)?
+        .ok_or_else(|| format!("Cluster '{}' not found", id))?;
+
+    let source_path = PathBuf::from(&cluster.config_path);
+    if !source_path.exists() {
+        return Err(format!("Config file not found: {:?}", source_path));
+    }
+
+    let config_path = crate::import::extract_context_to(
+        &source_path,
+        &cluster.context_name,
+        &cluster.id,
+        kubeconfigs_dir,
+    )?;
+    manager.update_config_path(&cluster.id, &config_path)?;
+
+    let stale = match (
+        source_path.canonicalize(),
+        config_path.canonicalize(),
+        kubeconfigs_dir.canonicalize(),
+    ) {
+        (Ok(source), Ok(config), Ok(dir)) => source != config && source.starts_with(dir),
+        _ => false,
+    };
+    if stale {
+        if let Err(e) = std::fs::remove_file(&source_path) {
+            eprintln!(
+                "Warning: Could not remove old config {:?}: {}",
+                source_path, e
+            );
+        }
+    }
+
+    manager
+        .get_cluster(id)?
+        .ok_or_else(|| format!("Cluster '{}' not found", id))
+}
+
+/// A cluster's icon for display. Icons saved to disk are returned as data URIs;
+/// emoji, URLs and inline data URIs are returned as stored.
+#[tauri::command]
+pub fn get_cluster_icon(
+    id: String,
+    state: State<ClusterManagerState>,
+) -> Result<Option<String>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let cluster = manager
+        .get_cluster(&id)?
+        .ok_or_else(|| format!("Cluster '{}' not found", id))?;
+    drop(manager);
+
+    cluster
+        .icon
+        .map(|icon| {
+            crate::image_utils::resolve_cluster_icon(&icon, &crate::config::get_icons_dir())
+        })
+        .transpose()
+}
+
+/// Server, user and namespace of a saved cluster's context, read from its config file
+#[tauri::command]
+pub fn db_get_cluster_context_info(
+    id: String,
+    state: State<ClusterManagerState>,
+) -> Result<ClusterContextInfo, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let cluster = manager
+        .get_cluster(&id)?
+        .ok_or_else(|| format!("Cluster '{}' not found", id))?;
+    read_context_info(&cluster)
+}
+
+/// Write a saved cluster's kubeconfig to a standalone file; the inverse of `import_kubeconfig`
+#[tauri::command]
+pub fn db_export_cluster_kubeconfig(
+    id: String,
+    dest_path: String,
+    state: State<ClusterManagerState>,
+) -> Result<String, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let cluster = manager
+        .get_cluster(&id)?
+        .ok_or_else(|| format!("Cluster '{}' not found", id))?;
+    drop(manager);
+
+    let dest = export_kubeconfig(
+        &cluster,
+        &PathBuf::from(dest_path),
+        &crate::config::get_kubeconfigs_dir(),
+    )?;
+    Ok(dest.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn db_backup(dest_path: String, state: State<ClusterManagerState>) -> Result<String, String> {
+    let dest = crate::config::validate_new_file_destination(&PathBuf::from(dest_path))?;
+
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.backup_to(&dest)?;
+    crate::config::set_owner_only_file_permissions(&dest)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn db_restore(src_path: String, state: State<ClusterManagerState>) -> Result<(), String> {
+    let src = crate::config::validate_import_source(&PathBuf::from(src_path))?;
+
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.restore_from(&src)?;
+    state.1.clear();
+    Ok(())
+}
+
+#[tauri::command]
+pub fn db_migrate_legacy_configs(state: State<ClusterManagerState>) -> Result<Vec<String>, String> {
+    use crate::import::{discover_contexts_in_folder, extract_context};
+
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let kubeconfigs_dir = crate::config::get_kubeconfigs_dir();
+
+    if !kubeconfigs_dir.exists() {
+        return Ok(vec![]); // No legacy configs to migrate
+    }
+
+    // Discover all contexts from the kubeconfigs directory
     let discovered = discover_contexts_in_folder(&kubeconfigs_dir)
         .map_err(|e| format!("Failed to discover legacy configs: {}", e))?;
 
@@ -459,10 +1382,42 @@ mod tests {
             None,
             None,
             vec![],
+            None,
         );
         assert!(result.is_err());
     }
 
+    #[test]
+    fn in_transaction_keeps_entries_that_succeed() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let add = |manager: &ClusterManager, name: &str| {
+            manager.add_cluster(
+                name.to_string(),
+                "valid-context".to_string(),
+                PathBuf::from("/tmp/config.yaml"),
+                None,
+                None,
+                vec![],
+                None,
+            )
+        };
+
+        let results = manager
+            .in_transaction(|manager| vec![add(manager, "first"), add(manager, "bad\nname")])
+            .unwrap();
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+
+        let names: Vec<_> = manager
+            .list_clusters()
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, vec!["first"]);
+    }
+
     #[test]
     fn add_cluster_rejects_duplicate_tags() {
         let temp = TempDir::new().unwrap();
@@ -474,6 +1429,7 @@ mod tests {
             None,
             None,
             vec!["prod".to_string(), "prod".to_string()],
+            None,
         );
         assert!(result.is_err());
     }
@@ -490,6 +1446,7 @@ mod tests {
                 None,
                 None,
                 vec!["prod".to_string()],
+                None,
             )
             .unwrap();
 
@@ -499,7 +1456,598 @@ mod tests {
             None,
             Some(Some("bad\u{0007}".to_string())),
             None,
+            None,
+            None,
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn backup_and_restore_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let cluster = manager
+            .add_cluster(
+                "valid".to_string(),
+                "valid-context".to_string(),
+                PathBuf::from("/tmp/config.yaml"),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        let backup = temp.path().join("backup.db");
+        manager.backup_to(&backup).unwrap();
+        assert_eq!(validate_backup(&backup).unwrap(), SCHEMA_VERSION);
+
+        manager.delete_cluster(&cluster.id).unwrap();
+        assert!(manager.list_clusters().unwrap().is_empty());
+
+        manager.restore_from(&backup).unwrap();
+        let restored = manager.list_clusters().unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].id, cluster.id);
+    }
+
+    #[test]
+    fn new_upgrades_schema_without_losing_clusters() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("clusters.db");
+        {
+            // A version 2 database, from before server_version was stored
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE clusters (
+                    id TEXT PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    context_name TEXT NOT NULL,
+                    config_path TEXT NOT NULL,
+                    icon TEXT,
+                    description TEXT,
+                    tags TEXT NOT NULL DEFAULT '[]',
+                    created_at INTEGER NOT NULL,
+                    last_accessed INTEGER NOT NULL
+                );
+                INSERT INTO clusters (id, name, context_name, config_path, created_at, last_accessed)
+                VALUES ('old', 'old', 'old-context', '/tmp/config.yaml', 1, 1);
+                PRAGMA user_version = 2;",
+            )
+            .unwrap();
+        }
+
+        let manager = ClusterManager::new(path.clone()).unwrap();
+        let cluster = manager.get_cluster("old").unwrap().unwrap();
+        assert_eq!(cluster.name, "old");
+        assert_eq!(cluster.server_version, None);
+        assert!(!cluster.pinned);
+        assert_eq!(cluster.group_name, None);
+
+        manager.update_server_version("old", "v1.31.2").unwrap();
+        let cluster = manager.get_cluster("old").unwrap().unwrap();
+        assert_eq!(cluster.server_version.as_deref(), Some("v1.31.2"));
+
+        // Opening an up-to-date database again is a no-op
+        drop(manager);
+        let manager = ClusterManager::new(path).unwrap();
+        assert_eq!(manager.list_clusters().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn pinned_clusters_list_first() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let add = |name: &str| {
+            manager
+                .add_cluster(
+                    name.to_string(),
+                    format!("{}-context", name),
+                    PathBuf::from("/tmp/config.yaml"),
+                    None,
+                    None,
+                    vec![],
+                    None,
+                )
+                .unwrap()
+        };
+        let older = add("older");
+        let newer = add("newer");
+        manager.update_last_accessed(&newer.id).unwrap();
+        conn_set_last_accessed(&manager, &older.id, 1);
+
+        let names = |m: &ClusterManager| {
+            m.list_clusters()
+                .unwrap()
+                .into_iter()
+                .map(|c| c.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&manager), vec!["newer", "older"]);
+
+        // Pinning twice is the same as pinning once
+        manager.set_cluster_pinned(&older.id, true).unwrap();
+        manager.set_cluster_pinned(&older.id, true).unwrap();
+        assert_eq!(names(&manager), vec!["older", "newer"]);
+        assert!(manager.get_cluster(&older.id).unwrap().unwrap().pinned);
+
+        manager.set_cluster_pinned(&older.id, false).unwrap();
+        assert_eq!(names(&manager), vec!["newer", "older"]);
+    }
+
+    #[test]
+    fn groups_are_counted_and_can_be_cleared() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let add = |name: &str, group: Option<&str>| {
+            manager
+                .add_cluster(
+                    name.to_string(),
+                    format!("{}-context", name),
+                    PathBuf::from("/tmp/config.yaml"),
+                    None,
+                    None,
+                    vec![],
+                    group.map(str::to_string),
+                )
+                .unwrap()
+        };
+        add("eu", Some("prod"));
+        let us = add("us", Some(" prod "));
+        add("dev", Some("staging"));
+        add("scratch", None);
+
+        let group = |name: &str, cluster_count: i64| ClusterGroup {
+            name: name.to_string(),
+            cluster_count,
+        };
+        assert_eq!(
+            manager.list_groups().unwrap(),
+            vec![group("prod", 2), group("staging", 1)]
+        );
+
+        manager
+            .update_cluster(&us.id, None, None, None, None, Some(None), None)
+            .unwrap();
+        assert_eq!(
+            manager.get_cluster(&us.id).unwrap().unwrap().group_name,
+            None
+        );
+        assert_eq!(
+            manager.list_groups().unwrap(),
+            vec![group("prod", 1), group("staging", 1)]
+        );
+    }
+
+    #[test]
+    fn default_namespace_can_be_set_and_cleared() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod-context".to_string(),
+                PathBuf::from("/tmp/config.yaml"),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+        assert_eq!(cluster.default_namespace, None);
+        let default_namespace = || {
+            manager
+                .get_cluster(&cluster.id)
+                .unwrap()
+                .unwrap()
+                .default_namespace
+        };
+
+        let set = |namespace: Option<&str>| {
+            manager.update_cluster(
+                &cluster.id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(namespace.map(str::to_string)),
+            )
+        };
+        set(Some("payments")).unwrap();
+        assert_eq!(default_namespace(), Some("payments".to_string()));
+        assert!(set(Some("Payments")).is_err());
+
+        set(None).unwrap();
+        assert_eq!(default_namespace(), None);
+    }
+
+    #[test]
+    fn accept_invalid_certs_defaults_off_and_can_be_toggled() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let cluster = manager
+            .add_cluster(
+                "lab".to_string(),
+                "lab-context".to_string(),
+                PathBuf::from("/tmp/config.yaml"),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+        let accepts = || {
+            manager
+                .get_cluster(&cluster.id)
+                .unwrap()
+                .unwrap()
+                .accept_invalid_certs
+        };
+        assert!(!accepts());
+
+        manager.set_accept_invalid_certs(&cluster.id, true).unwrap();
+        assert!(accepts());
+        manager
+            .set_accept_invalid_certs(&cluster.id, false)
+            .unwrap();
+        assert!(!accepts());
+    }
+
+    fn conn_set_last_accessed(manager: &ClusterManager, id: &str, last_accessed: i64) {
+        manager
+            .conn
+            .lock()
+            .unwrap()
+            .execute(
+                "UPDATE clusters SET last_accessed = ?1 WHERE id = ?2",
+                params![last_accessed, id],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn validate_backup_rejects_non_database_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("not-a-db.db");
+        std::fs::write(&path, "definitely not sqlite").unwrap();
+
+        assert!(validate_backup(&path).is_err());
+    }
+
+    #[test]
+    fn validate_backup_rejects_newer_schema() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("future.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            init_schema(&conn).unwrap();
+            conn.execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION + 1))
+                .unwrap();
+        }
+
+        let err = validate_backup(&path).unwrap_err();
+        assert!(err.contains("newer"));
+    }
+
+    #[test]
+    fn validate_clusters_reports_missing_and_mismatched_configs() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+
+        let good_path = temp.path().join("good.yaml");
+        std::fs::write(
+            &good_path,
+            "apiVersion: v1
+kind: Config
+current-context: good-context
+clusters:
+- name: c
+  cluster:
+    server: https://example.com
+users:
+- name: u
+  user:
+    token: t
+contexts:
+- name: good-context
+  context:
+    cluster: c
+    user: u
+",
+        )
+        .unwrap();
+
+        let good = manager
+            .add_cluster(
+                "good".to_string(),
+                "good-context".to_string(),
+                good_path.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+        let missing = manager
+            .add_cluster(
+                "missing".to_string(),
+                "missing-context".to_string(),
+                temp.path().join("missing.yaml"),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+        let mismatched = manager
+            .add_cluster(
+                "mismatched".to_string(),
+                "other-context".to_string(),
+                good_path,
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        let report = manager.validate_clusters().unwrap();
+        let find = |id: &str| report.iter().find(|r| r.id == id).unwrap();
+
+        assert!(find(&good.id).ok);
+        assert!(!find(&missing.id).ok);
+        assert!(find(&missing.id)
+            .issue
+            .as_ref()
+            .unwrap()
+            .contains("not found"));
+        assert!(!find(&mismatched.id).ok);
+    }
+
+    fn write_single_context_config(path: &Path, context: &str, server: &str) {
+        std::fs::write(
+            path,
+            format!(
+                "apiVersion: v1
+kind: Config
+current-context: {context}
+clusters:
+- name: c
+  cluster:
+    server: {server}
+users:
+- name: u
+  user:
+    token: t
+contexts:
+- name: {context}
+  context:
+    cluster: c
+    user: u
+"
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn read_context_info_reports_connection_details() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let path = temp.path().join("prod.yaml");
+        write_single_context_config(&path, "prod", "https://prod.example.com:6443");
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                path.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            read_context_info(&cluster).unwrap(),
+            ClusterContextInfo {
+                cluster_name: "c".to_string(),
+                server: Some("https://prod.example.com:6443".to_string()),
+                user: Some("u".to_string()),
+                namespace: None,
+                insecure_skip_tls_verify: false,
+            }
+        );
+
+        std::fs::write(&path, "clusters: [").unwrap();
+        assert!(read_context_info(&cluster)
+            .unwrap_err()
+            .starts_with("Failed to read kubeconfig"));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(read_context_info(&cluster)
+            .unwrap_err()
+            .starts_with("Config file not found"));
+    }
+
+    #[test]
+    fn normalize_cluster_config_renames_and_removes_old_file() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let dir = temp.path().join("kubeconfigs");
+        std::fs::create_dir(&dir).unwrap();
+        let old_path = dir.join("legacy_config_123");
+        write_single_context_config(&old_path, "prod", "https://prod.example.com:6443");
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                old_path.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        let normalized = normalize_cluster_config(&manager, &cluster.id, &dir).unwrap();
+
+        let new_path = dir.join(format!("{}.yaml", cluster.id));
+        assert_eq!(PathBuf::from(&normalized.config_path), new_path);
+        assert!(new_path.is_file());
+        assert!(!old_path.exists());
+        let config = Kubeconfig::read_from(&new_path).unwrap();
+        assert_eq!(config.current_context.as_deref(), Some("prod"));
+    }
+
+    #[test]
+    fn normalize_cluster_config_keeps_files_outside_app_dir() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let dir = temp.path().join("kubeconfigs");
+        std::fs::create_dir(&dir).unwrap();
+        let user_config = temp.path().join("config");
+        write_single_context_config(&user_config, "prod", "https://prod.example.com:6443");
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                user_config.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        normalize_cluster_config(&manager, &cluster.id, &dir).unwrap();
+
+        assert!(user_config.is_file());
+        assert!(dir.join(format!("{}.yaml", cluster.id)).is_file());
+    }
+
+    #[test]
+    fn export_kubeconfig_copies_config_outside_protected_dir() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let protected = temp.path().join("kubeconfigs");
+        std::fs::create_dir(&protected).unwrap();
+        let path = protected.join("prod.yaml");
+        write_single_context_config(&path, "prod", "https://prod.example.com:6443");
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                path.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        let dest =
+            export_kubeconfig(&cluster, &temp.path().join("export.yaml"), &protected).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&dest).unwrap(),
+            std::fs::read_to_string(&path).unwrap()
+        );
+
+        let err =
+            export_kubeconfig(&cluster, &protected.join("copy.yaml"), &protected).unwrap_err();
+        assert!(err.contains("kubeconfigs directory"));
+
+        std::fs::remove_file(&path).unwrap();
+        let err =
+            export_kubeconfig(&cluster, &temp.path().join("again.yaml"), &protected).unwrap_err();
+        assert!(err.starts_with("Config file not found"));
+    }
+
+    #[test]
+    fn settings_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        assert_eq!(manager.get_settings().unwrap(), AppSettings::default());
+
+        let settings = AppSettings {
+            log_tail_lines: 200,
+            log_max_buffer: 5000,
+            default_delete_grace_period: Some(10),
+            ..Default::default()
+        };
+        manager.set_settings(&settings).unwrap();
+
+        assert_eq!(manager.get_settings().unwrap(), settings);
+    }
+
+    #[test]
+    fn find_orphaned_configs_skips_referenced_files() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+        let dir = temp.path().join("kubeconfigs");
+        std::fs::create_dir(&dir).unwrap();
+
+        let referenced = dir.join("imported.yaml");
+        write_single_context_config(&referenced, "prod", "https://prod.example.com:6443");
+        let cluster = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                referenced,
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+        std::fs::write(dir.join(format!("{}.yaml", cluster.id)), "").unwrap();
+        std::fs::write(dir.join("stale.yaml"), "apiVersion: v1").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let orphans = manager.find_orphaned_configs(&dir).unwrap();
+
+        assert_eq!(orphans.len(), 1);
+        assert!(orphans[0].path.ends_with("stale.yaml"));
+        assert_eq!(orphans[0].size_bytes, 13);
+    }
+
+    #[test]
+    fn find_duplicate_clusters_groups_by_server() {
+        let temp = TempDir::new().unwrap();
+        let manager = ClusterManager::new(temp.path().join("clusters.db")).unwrap();
+
+        let configs = [
+            ("prod-a", "https://prod.example.com:6443"),
+            ("prod-b", "https://PROD.example.com:6443/"),
+            ("staging", "https://staging.example.com:6443"),
+        ];
+        for (context, server) in configs {
+            let path = temp.path().join(format!("{}.yaml", context));
+            write_single_context_config(&path, context, server);
+            manager
+                .add_cluster(
+                    context.to_string(),
+                    context.to_string(),
+                    path,
+                    None,
+                    None,
+                    vec![],
+                    None,
+                )
+                .unwrap();
+        }
+
+        let groups = manager.find_duplicate_clusters().unwrap();
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].server, "https://prod.example.com:6443");
+        let mut contexts: Vec<&str> = groups[0]
+            .clusters
+            .iter()
+            .map(|c| c.context_name.as_str())
+            .collect();
+        contexts.sort();
+        assert_eq!(contexts, vec!["prod-a", "prod-b"]);
+    }
 }
@@ -3,6 +3,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[allow(dead_code)]
@@ -31,18 +33,27 @@ pub fn get_kubeconfigs_dir() -> PathBuf {
     path
 }
 
+/// Processed cluster icons saved by `process_icon_file`
+pub fn get_icons_dir() -> PathBuf {
+    let mut path = get_app_config_dir();
+    path.push("icons");
+    path
+}
+
 pub fn init_directories() -> std::io::Result<()> {
     let app_dir = get_app_config_dir();
-    if !app_dir.exists() {
-        fs::create_dir_all(&app_dir)?;
-    }
-    set_owner_only_dir_permissions(&app_dir)?;
-
     let kube_dir = get_kubeconfigs_dir();
-    if !kube_dir.exists() {
-        fs::create_dir_all(&kube_dir)?;
+    // Create both before restricting either, so a permissions failure can't leave the
+    // kubeconfigs directory missing; the audit reports paths that stay too open
+    fs::create_dir_all(&kube_dir)?;
+    for dir in [&app_dir, &kube_dir] {
+        if let Err(e) = set_owner_only_dir_permissions(dir) {
+            eprintln!(
+                "Warning: Could not restrict permissions on {:?}: {}",
+                dir, e
+            );
+        }
     }
-    set_owner_only_dir_permissions(&kube_dir)?;
 
     Ok(())
 }
@@ -90,6 +101,9 @@ pub(crate) fn set_owner_only_dir_permissions(path: &Path) -> std::io::Result<()>
             }
         }
     }
+    // Inherited by files and subdirectories created inside
+    #[cfg(windows)]
+    restrict_to_current_user(path, "(OI)(CI)F")?;
     Ok(())
 }
 
@@ -103,9 +117,133 @@ pub(crate) fn set_owner_only_file_permissions(path: &Path) -> std::io::Result<()
             }
         }
     }
+    #[cfg(windows)]
+    restrict_to_current_user(path, "F")?;
     Ok(())
 }
 
+/// Replace the ACL on `path` with a single entry granting the current user `access`
+/// (icacls syntax), dropping inherited entries such as Users or Everyone
+#[cfg(windows)]
+fn restrict_to_current_user(path: &Path, access: &str) -> std::io::Result<()> {
+    let user = std::env::var("USERNAME")
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::NotFound, "USERNAME is not set"))?;
+    let principal = match std::env::var("USERDOMAIN") {
+        Ok(domain) if !domain.is_empty() => format!("{}\\{}", domain, user),
+        _ => user,
+    };
+
+    // CREATE_NO_WINDOW: don't flash a console window from the GUI app
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    let output = std::process::Command::new("icacls")
+        .arg(path)
+        .args(["/inheritance:r", "/grant:r"])
+        .arg(format!("{}:{}", principal, access))
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()?;
+    if !output.status.success() {
+        // icacls reports most failures on stdout, some (bad arguments) on stderr
+        let message = format!(
+            "{} {}",
+            String::from_utf8_lossy(&output.stdout).trim(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Err(std::io::Error::other(format!(
+            "icacls failed: {}",
+            message.trim()
+        )));
+    }
+    Ok(())
+}
+
+/// A path under the app directory that other users can access
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PermissionIssue {
+    pub path: String,
+    /// Current mode in octal, e.g. "644"
+    pub mode: String,
+    pub expected: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PermissionAudit {
+    /// False where modes can't be audited (non-Unix)
+    pub supported: bool,
+    pub checked: usize,
+    pub issues: Vec<PermissionIssue>,
+}
+
+/// Directories and kubeconfig files that must be owner-only, with the mode each should have
+fn owner_only_paths(app_dir: &Path, kube_dir: &Path) -> std::io::Result<Vec<(PathBuf, u32)>> {
+    let mut paths = vec![
+        (app_dir.to_path_buf(), 0o700),
+        (kube_dir.to_path_buf(), 0o700),
+    ];
+    for entry in fs::read_dir(kube_dir)? {
+        let entry = entry?;
+        // Symlinks are skipped: chmod would follow them out of the directory
+        if entry.file_type()?.is_file() {
+            paths.push((entry.path(), 0o600));
+        }
+    }
+    Ok(paths)
+}
+
+fn audit_permissions(app_dir: &Path, kube_dir: &Path) -> std::io::Result<PermissionAudit> {
+    let paths = owner_only_paths(app_dir, kube_dir)?;
+
+    #[cfg(unix)]
+    let issues = {
+        let mut issues = Vec::new();
+        for (path, expected) in &paths {
+            let mode = fs::metadata(path)?.permissions().mode() & 0o777;
+            if mode & !expected != 0 {
+                issues.push(PermissionIssue {
+                    path: path.to_string_lossy().to_string(),
+                    mode: format!("{:o}", mode),
+                    expected: format!("{:o}", expected),
+                });
+            }
+        }
+        issues
+    };
+    #[cfg(not(unix))]
+    let issues = vec![];
+
+    Ok(PermissionAudit {
+        supported: cfg!(unix),
+        checked: paths.len(),
+        issues,
+    })
+}
+
+fn repair_permissions(app_dir: &Path, kube_dir: &Path) -> std::io::Result<PermissionAudit> {
+    for (path, _) in owner_only_paths(app_dir, kube_dir)? {
+        if path.is_dir() {
+            set_owner_only_dir_permissions(&path)?;
+        } else {
+            set_owner_only_file_permissions(&path)?;
+        }
+    }
+    // Re-check, since chmod failures on restricted filesystems are tolerated
+    audit_permissions(app_dir, kube_dir)
+}
+
+/// Report app and kubeconfig paths whose permissions have drifted from owner-only
+#[tauri::command]
+pub fn config_audit_permissions() -> Result<PermissionAudit, String> {
+    audit_permissions(&get_app_config_dir(), &get_kubeconfigs_dir())
+        .map_err(|e| format!("Failed to audit permissions: {}", e))
+}
+
+/// Re-apply owner-only permissions and return the audit afterwards
+#[tauri::command]
+pub fn config_repair_permissions() -> Result<PermissionAudit, String> {
+    repair_permissions(&get_app_config_dir(), &get_kubeconfigs_dir())
+        .map_err(|e| format!("Failed to repair permissions: {}", e))
+}
+
 /// Validate that a path is within the allowed kubeconfigs directory
 /// Returns the canonicalized path if valid, otherwise returns an error
 pub fn validate_kubeconfig_path(path: &Path) -> Result<PathBuf, String> {
@@ -114,6 +252,28 @@ pub fn validate_kubeconfig_path(path: &Path) -> Result<PathBuf, String> {
         .canonicalize()
         .map_err(|e| format!("Failed to resolve kubeconfigs directory: {}", e))?;
 
+    validate_path_within(path, &allowed_dir)
+}
+
+/// Validate that a file the user chose to save (e.g. downloaded logs) is within their
+/// home directory. The file may already exist and will be overwritten.
+pub fn validate_download_path(path: &Path) -> Result<PathBuf, String> {
+    let allowed_dir = dirs::home_dir()
+        .ok_or_else(|| "Could not find home directory".to_string())?
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve home directory: {}", e))?;
+
+    let canonical = validate_path_within(path, &allowed_dir)?;
+    if canonical.is_dir() {
+        return Err("Destination is a directory".to_string());
+    }
+
+    Ok(canonical)
+}
+
+/// Resolve `path` (following symlinks) and check it stays inside `allowed_dir`,
+/// which must already be canonical
+fn validate_path_within(path: &Path, allowed_dir: &Path) -> Result<PathBuf, String> {
     // Attempt to canonicalize the provided path
     // If the file doesn't exist yet, we need to check the parent directory
     let canonical = if path.exists() {
@@ -134,7 +294,7 @@ pub fn validate_kubeconfig_path(path: &Path) -> Result<PathBuf, String> {
     };
 
     // Check if the canonical path is within the allowed directory
-    if !canonical.starts_with(&allowed_dir) {
+    if !canonical.starts_with(allowed_dir) {
         return Err(format!(
             "Path traversal detected: path must be within {:?}",
             allowed_dir
@@ -165,6 +325,33 @@ pub fn validate_import_source(path: &Path) -> Result<PathBuf, String> {
     Ok(canonical)
 }
 
+/// Validate a destination for a new file the app is about to write.
+/// The parent directory must exist and the file itself must not.
+/// Returns the path with its parent directory canonicalized.
+pub fn validate_new_file_destination(path: &Path) -> Result<PathBuf, String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| "Destination has no filename".to_string())?;
+    let parent = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => return Err("Destination has no parent directory".to_string()),
+    };
+
+    let canonical_parent = parent
+        .canonicalize()
+        .map_err(|e| format!("Invalid destination directory: {}", e))?;
+    if !canonical_parent.is_dir() {
+        return Err("Destination parent is not a directory".to_string());
+    }
+
+    let destination = canonical_parent.join(file_name);
+    if destination.exists() {
+        return Err("Destination file already exists".to_string());
+    }
+
+    Ok(destination)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +364,19 @@ mod tests {
         assert!(err.contains("Path traversal detected"));
     }
 
+    #[test]
+    fn validate_path_within_rejects_escape() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let allowed = temp_dir.path().canonicalize().unwrap();
+        fs::create_dir(allowed.join("logs")).unwrap();
+
+        let inside = validate_path_within(&allowed.join("logs/web.log"), &allowed).unwrap();
+        assert_eq!(inside, allowed.join("logs/web.log"));
+
+        let err = validate_path_within(&allowed.join("logs/../../web.log"), &allowed).unwrap_err();
+        assert!(err.contains("Path traversal detected"));
+    }
+
     #[test]
     fn validate_import_source_rejects_directory() {
         init_directories().unwrap();
@@ -184,6 +384,19 @@ mod tests {
         assert!(err.contains("not a file"));
     }
 
+    #[test]
+    fn validate_new_file_destination_rejects_existing_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let existing = temp_dir.path().join("existing.db");
+        fs::write(&existing, "data").unwrap();
+
+        let err = validate_new_file_destination(&existing).unwrap_err();
+        assert!(err.contains("already exists"));
+
+        let fresh = temp_dir.path().join("fresh.db");
+        assert!(validate_new_file_destination(&fresh).is_ok());
+    }
+
     #[cfg(unix)]
     #[test]
     fn set_owner_only_permissions_on_file_and_dir() {
@@ -202,6 +415,51 @@ mod tests {
         assert_eq!(file_mode, 0o600);
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn set_owner_only_permissions_restricts_acl_to_current_user() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let file = temp_dir.path().join("secret.yaml");
+        fs::write(&file, "secret").unwrap();
+
+        set_owner_only_file_permissions(&file).unwrap();
+
+        let output = std::process::Command::new("icacls")
+            .arg(&file)
+            .output()
+            .unwrap();
+        let acl = String::from_utf8_lossy(&output.stdout);
+        let user = std::env::var("USERNAME").unwrap();
+        assert!(acl.contains(&user), "{}", acl);
+        for other in ["Everyone", "BUILTIN\\Users", "Authenticated Users"] {
+            assert!(!acl.contains(other), "{}", acl);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn audit_and_repair_permissions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let app_dir = temp_dir.path().join("app");
+        let kube_dir = app_dir.join("kubeconfigs");
+        let config = kube_dir.join("cluster.yaml");
+        fs::create_dir_all(&kube_dir).unwrap();
+        fs::write(&config, "apiVersion: v1\nkind: Config\n").unwrap();
+        fs::set_permissions(&app_dir, fs::Permissions::from_mode(0o700)).unwrap();
+        fs::set_permissions(&kube_dir, fs::Permissions::from_mode(0o755)).unwrap();
+        fs::set_permissions(&config, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let audit = audit_permissions(&app_dir, &kube_dir).unwrap();
+        assert!(audit.supported);
+        assert_eq!(audit.checked, 3);
+        assert_eq!(audit.issues.len(), 2);
+        assert_eq!(audit.issues[1].mode, "644");
+        assert_eq!(audit.issues[1].expected, "600");
+
+        let repaired = repair_permissions(&app_dir, &kube_dir).unwrap();
+        assert!(repaired.issues.is_empty());
+    }
+
     #[cfg(unix)]
     #[test]
     fn validate_kubeconfig_path_rejects_symlink_escape() {
@@ -0,0 +1,201 @@
+use crate::k8s::client::with_tls_hint;
+use serde::ser::SerializeStruct;
+use std::fmt;
+
+/// Error returned by commands. Serialized as `{ kind, message }` so the frontend can
+/// tell a missing object from denied access or an unreachable cluster.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    NotFound(String),
+    Forbidden(String),
+    Unauthorized(String),
+    Conflict(String),
+    /// Any other error status from the API server
+    ApiError {
+        status: u16,
+        message: String,
+    },
+    /// The API server couldn't be reached
+    Network(String),
+    Io(String),
+    /// Kubeconfig or cluster registration problems
+    Config(String),
+    /// Arguments the command can't act on
+    InvalidInput(String),
+    Internal(String),
+}
+
+impl AppError {
+    /// Map an API status code onto the matching variant
+    pub fn from_status(status: u16, message: String) -> Self {
+        match status {
+            401 => AppError::Unauthorized(message),
+            403 => AppError::Forbidden(message),
+            404 => AppError::NotFound(message),
+            409 => AppError::Conflict(message),
+            _ => AppError::ApiError { status, message },
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "NotFound",
+            AppError::Forbidden(_) => "Forbidden",
+            AppError::Unauthorized(_) => "Unauthorized",
+            AppError::Conflict(_) => "Conflict",
+            AppError::ApiError { .. } => "ApiError",
+            AppError::Network(_) => "Network",
+            AppError::Io(_) => "Io",
+            AppError::Config(_) => "Config",
+            AppError::InvalidInput(_) => "InvalidInput",
+            AppError::Internal(_) => "Internal",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::NotFound(message)
+            | AppError::Forbidden(message)
+            | AppError::Unauthorized(message)
+            | AppError::Conflict(message)
+            | AppError::ApiError { message, .. }
+            | AppError::Network(message)
+            | AppError::Io(message)
+            | AppError::Config(message)
+            | AppError::InvalidInput(message)
+            | AppError::Internal(message) => message,
+        }
+    }
+
+    /// Prefix the message with what was being done, keeping the kind,
+    /// e.g. `Failed to delete pod: pods "web" not found`
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        let message = format!("{}: {}", context, self.message());
+        match self {
+            AppError::NotFound(_) => AppError::NotFound(message),
+            AppError::Forbidden(_) => AppError::Forbidden(message),
+            AppError::Unauthorized(_) => AppError::Unauthorized(message),
+            AppError::Conflict(_) => AppError::Conflict(message),
+            AppError::ApiError { status, .. } => AppError::ApiError { status, message },
+            AppError::Network(_) => AppError::Network(message),
+            AppError::Io(_) => AppError::Io(message),
+            AppError::Config(_) => AppError::Config(message),
+            AppError::InvalidInput(_) => AppError::InvalidInput(message),
+            AppError::Internal(_) => AppError::Internal(message),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl serde::Serialize for AppError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut error = serializer.serialize_struct("AppError", 2)?;
+        error.serialize_field("kind", self.kind())?;
+        error.serialize_field("message", self.message())?;
+        error.end()
+    }
+}
+
+/// Errors raised before the API server answered (TLS, connection, client setup) get
+/// the self-signed cluster hint when they're certificate failures
+impl From<kube::Error> for AppError {
+    fn from(err: kube::Error) -> Self {
+        match err {
+            kube::Error::Api(resp) => AppError::from_status(resp.code, resp.message.clone()),
+            kube::Error::Auth(e) => AppError::Unauthorized(with_tls_hint(e.to_string())),
+            kube::Error::HyperError(e) => AppError::Network(with_tls_hint(e.to_string())),
+            kube::Error::Service(e) => AppError::Network(with_tls_hint(e.to_string())),
+            other => AppError::Internal(with_tls_hint(other.to_string())),
+        }
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Io(err.to_string())
+    }
+}
+
+/// Helpers that still return `String` errors
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Internal(message)
+    }
+}
+
+/// Lets `String`-returning commands call helpers already migrated to `AppError`
+impl From<AppError> for String {
+    fn from(err: AppError) -> Self {
+        err.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_status() {
+        assert_eq!(
+            AppError::from_status(404, "gone".to_string()),
+            AppError::NotFound("gone".to_string())
+        );
+        assert_eq!(
+            AppError::from_status(401, String::new()).kind(),
+            "Unauthorized"
+        );
+        assert_eq!(
+            AppError::from_status(403, String::new()).kind(),
+            "Forbidden"
+        );
+        assert_eq!(AppError::from_status(409, String::new()).kind(), "Conflict");
+        assert_eq!(
+            AppError::from_status(500, "boom".to_string()),
+            AppError::ApiError {
+                status: 500,
+                message: "boom".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_context_keeps_kind() {
+        let err =
+            AppError::NotFound("pods \"web\" not found".to_string()).context("Failed to get pod");
+
+        assert_eq!(err.kind(), "NotFound");
+        assert_eq!(err.to_string(), "Failed to get pod: pods \"web\" not found");
+    }
+
+    #[test]
+    fn test_serializes_kind_and_message() {
+        let err = AppError::ApiError {
+            status: 500,
+            message: "boom".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({ "kind": "ApiError", "message": "boom" })
+        );
+    }
+
+    #[test]
+    fn test_kube_errors_get_the_tls_hint() {
+        let err = AppError::from(kube::Error::Service(
+            "invalid peer certificate: UnknownIssuer".into(),
+        ));
+        assert_eq!(err.kind(), "Network");
+        assert!(err.message().contains("insecure-skip-tls-verify"));
+
+        let err = AppError::from(kube::Error::Service("connection refused".into()));
+        assert_eq!(err.message(), "connection refused");
+    }
+}
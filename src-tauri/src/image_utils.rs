@@ -1,25 +1,243 @@
+use crate::cluster_manager::ClusterManagerState;
 use image::{DynamicImage, GenericImageView, ImageFormat, ImageReader};
 use std::io::Cursor;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use tauri::State;
 
 const MAX_ICON_SIZE: u32 = 512;
+/// Larger source files are rejected before being read
+const MAX_ICON_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Images claiming more pixels than this are rejected before decoding, so a small
+/// file with a huge declared size can't exhaust memory
+const MAX_ICON_PIXELS: u64 = 40_000_000;
 
-/// Process an image file: resize if needed, convert to PNG, return as base64 data URI
-pub fn process_cluster_icon(path: &Path) -> Result<String, String> {
-    // Load the image
-    let img = ImageReader::open(path)
-        .map_err(|e| format!("Failed to open image: {}", e))?
-        .decode()
-        .map_err(|e| format!("Failed to decode image: {}", e))?;
+/// Output encoding for processed icons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconFormat {
+    #[default]
+    Png,
+    WebP,
+}
+
+impl IconFormat {
+    /// Parse the optional format argument from the frontend; PNG when omitted
+    pub fn parse(format: Option<&str>) -> Result<Self, String> {
+        match format.map(|f| f.to_ascii_lowercase()).as_deref() {
+            None | Some("png") => Ok(IconFormat::Png),
+            Some("webp") => Ok(IconFormat::WebP),
+            Some(other) => Err(format!("Unsupported icon format: {}", other)),
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            IconFormat::Png => "image/png",
+            IconFormat::WebP => "image/webp",
+        }
+    }
+
+    fn image_format(self) -> ImageFormat {
+        match self {
+            IconFormat::Png => ImageFormat::Png,
+            IconFormat::WebP => ImageFormat::WebP,
+        }
+    }
 
-    // Resize if necessary
-    let resized = resize_if_needed(img);
+    fn extension(self) -> &'static str {
+        match self {
+            IconFormat::Png => "png",
+            IconFormat::WebP => "webp",
+        }
+    }
+
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "png" => Some(IconFormat::Png),
+            "webp" => Some(IconFormat::WebP),
+            _ => None,
+        }
+    }
+}
 
-    // Convert to PNG and encode as base64
-    let base64_data = encode_as_png_base64(&resized)?;
+/// Process an image file: resize if needed, re-encode, return as base64 data URI.
+/// SVGs are rasterized to fit MAX_ICON_SIZE first; transparency is kept either way.
+pub fn process_cluster_icon(path: &Path, format: IconFormat) -> Result<String, String> {
+    let resized = load_icon(path)?;
+
+    // Re-encode and convert to base64
+    let base64_data = encode_as_base64(&resized, format)?;
 
     // Return as data URI
-    Ok(format!("data:image/png;base64,{}", base64_data))
+    Ok(format!(
+        "data:{};base64,{}",
+        format.mime_type(),
+        base64_data
+    ))
+}
+
+/// Process an image file like `process_cluster_icon`, but write it to `dir` as
+/// `<cluster_id>.<ext>` and return that file name for the cluster's `icon` column
+pub fn save_cluster_icon(
+    path: &Path,
+    format: IconFormat,
+    cluster_id: &str,
+    dir: &Path,
+) -> Result<String, String> {
+    uuid::Uuid::parse_str(cluster_id).map_err(|_| format!("Invalid cluster id: {}", cluster_id))?;
+    let resized = load_icon(path)?;
+    let bytes = encode_image(&resized, format)?;
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create icons directory: {}", e))?;
+    crate::config::set_owner_only_dir_permissions(dir)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    let file_name = format!("{}.{}", cluster_id, format.extension());
+    let dest = dir.join(&file_name);
+    std::fs::write(&dest, bytes).map_err(|e| format!("Failed to save icon: {}", e))?;
+    crate::config::set_owner_only_file_permissions(&dest)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    Ok(file_name)
+}
+
+/// The format of an `icon` value written by `save_cluster_icon`, or None for
+/// emoji, URLs and inline data URIs
+fn stored_icon_format(icon: &str) -> Option<IconFormat> {
+    let (stem, extension) = icon.rsplit_once('.')?;
+    uuid::Uuid::parse_str(stem).ok()?;
+    IconFormat::from_extension(extension)
+}
+
+/// Resolve an `icon` value for display: stored icon files are read back as data URIs,
+/// anything else is returned unchanged
+pub fn resolve_cluster_icon(icon: &str, dir: &Path) -> Result<String, String> {
+    let Some(format) = stored_icon_format(icon) else {
+        return Ok(icon.to_string());
+    };
+    let bytes = std::fs::read(dir.join(icon)).map_err(|e| format!("Failed to read icon: {}", e))?;
+
+    use base64::Engine;
+    Ok(format!(
+        "data:{};base64,{}",
+        format.mime_type(),
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    ))
+}
+
+/// Path of the icon file behind an `icon` value, if it was written by `save_cluster_icon`
+pub fn stored_icon_path(icon: &str, dir: &Path) -> Option<PathBuf> {
+    stored_icon_format(icon).map(|_| dir.join(icon))
+}
+
+/// Delete the icon file behind an `icon` value that's no longer used. Emoji and data
+/// URIs have no file; failures are logged, since a leftover file only wastes space.
+pub fn remove_stored_icon(icon: &str, dir: &Path) {
+    let Some(path) = stored_icon_path(icon, dir) else {
+        return;
+    };
+    if let Err(e) = std::fs::remove_file(&path) {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            eprintln!("Warning: Could not delete icon {:?}: {}", path, e);
+        }
+    }
+}
+
+/// Read, size-check and decode an icon, resized to fit MAX_ICON_SIZE
+fn load_icon(path: &Path) -> Result<DynamicImage, String> {
+    let file_size = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to open image: {}", e))?
+        .len();
+    check_file_size(file_size, MAX_ICON_FILE_BYTES)?;
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to open image: {}", e))?;
+
+    let resized = if is_svg(path, &bytes) {
+        rasterize_svg(&bytes)?
+    } else {
+        let reader = || {
+            ImageReader::new(Cursor::new(&bytes))
+                .with_guessed_format()
+                .map_err(|e| format!("Failed to open image: {}", e))
+        };
+        let (width, height) = reader()?
+            .into_dimensions()
+            .map_err(|e| format!("Failed to read image dimensions: {}", e))?;
+        check_dimensions(width, height, MAX_ICON_PIXELS)?;
+
+        // Load the image
+        let img = reader()?
+            .decode()
+            .map_err(|e| format!("Failed to decode image: {}", e))?;
+
+        // Resize if necessary
+        resize_if_needed(img)
+    };
+
+    Ok(resized)
+}
+
+fn check_file_size(size: u64, max_bytes: u64) -> Result<(), String> {
+    if size > max_bytes {
+        return Err(format!(
+            "Image is too large ({} bytes); the limit is {} MB",
+            size,
+            max_bytes / (1024 * 1024)
+        ));
+    }
+    Ok(())
+}
+
+fn check_dimensions(width: u32, height: u32, max_pixels: u64) -> Result<(), String> {
+    if width as u64 * height as u64 > max_pixels {
+        return Err(format!(
+            "Image dimensions {}x{} exceed the limit of {} pixels",
+            width, height, max_pixels
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a file is an SVG, by extension or by an `<svg` root near the start
+fn is_svg(path: &Path, bytes: &[u8]) -> bool {
+    let by_extension = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+    // Sniff the root element, allowing for a BOM and an XML declaration
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_lowercase();
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    by_extension || head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg"))
+}
+
+/// Render an SVG so its longer side is MAX_ICON_SIZE, on a transparent background
+fn rasterize_svg(bytes: &[u8]) -> Result<DynamicImage, String> {
+    use resvg::{tiny_skia, usvg};
+
+    let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())
+        .map_err(|e| format!("Failed to decode SVG: {}", e))?;
+    let size = tree.size();
+    let scale = MAX_ICON_SIZE as f32 / size.width().max(size.height());
+    let width = ((size.width() * scale).round() as u32).clamp(1, MAX_ICON_SIZE);
+    let height = ((size.height() * scale).round() as u32).clamp(1, MAX_ICON_SIZE);
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| "Failed to allocate SVG canvas".to_string())?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    // tiny-skia stores premultiplied alpha; image expects straight alpha
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    image::RgbaImage::from_raw(width, height, pixels)
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| "Failed to convert rendered SVG".to_string())
 }
 
 /// Resize image to fit within MAX_ICON_SIZE while maintaining aspect ratio
@@ -43,24 +261,61 @@ fn resize_if_needed(img: DynamicImage) -> DynamicImage {
     img.resize(new_width, new_height, image::imageops::FilterType::Lanczos3)
 }
 
-/// Encode image as PNG and return base64 string
-fn encode_as_png_base64(img: &DynamicImage) -> Result<String, String> {
+/// Encode image and return base64 string
+fn encode_as_base64(img: &DynamicImage, format: IconFormat) -> Result<String, String> {
+    let buffer = encode_image(img, format)?;
+
+    use base64::Engine;
+    Ok(base64::engine::general_purpose::STANDARD.encode(&buffer))
+}
+
+/// Encode image in the given format.
+/// Only decoded pixels are written: EXIF/ICC metadata from the source file is never carried over.
+fn encode_image(img: &DynamicImage, format: IconFormat) -> Result<Vec<u8>, String> {
     let mut buffer = Vec::new();
     let mut cursor = Cursor::new(&mut buffer);
 
-    img.write_to(&mut cursor, ImageFormat::Png)
-        .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+    // The WebP encoder only accepts 8-bit RGB(A)
+    let pixels = match format {
+        IconFormat::Png => img.clone(),
+        IconFormat::WebP => DynamicImage::ImageRgba8(img.to_rgba8()),
+    };
 
-    use base64::Engine;
-    Ok(base64::engine::general_purpose::STANDARD.encode(&buffer))
+    pixels
+        .write_to(&mut cursor, format.image_format())
+        .map_err(|e| format!("Failed to encode {:?}: {}", format, e))?;
+
+    Ok(buffer)
 }
 
 // Tauri Commands
 
+/// Process an icon file. Returns a data URI, or with `cluster_id` writes the icon to
+/// the icons directory and returns its file name to store in the cluster's `icon`
+/// column instead; `get_cluster_icon` reads it back.
 #[tauri::command]
-pub fn process_icon_file(path: String) -> Result<String, String> {
+pub fn process_icon_file(
+    path: String,
+    format: Option<String>,
+    cluster_id: Option<String>,
+    state: State<ClusterManagerState>,
+) -> Result<String, String> {
+    let format = IconFormat::parse(format.as_deref())?;
     let path = Path::new(&path);
-    process_cluster_icon(path)
+    match cluster_id {
+        Some(id) => {
+            let manager = state
+                .0
+                .lock()
+                .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            if manager.get_cluster(&id)?.is_none() {
+                return Err(format!("Cluster '{}' not found", id));
+            }
+            drop(manager);
+            save_cluster_icon(path, format, &id, &crate::config::get_icons_dir())
+        }
+        None => process_cluster_icon(path, format),
+    }
 }
 
 #[cfg(test)]
@@ -94,9 +349,9 @@ mod tests {
     }
 
     #[test]
-    fn test_encode_as_png_base64() {
+    fn test_encode_as_base64_png() {
         let img = DynamicImage::new_rgb8(100, 100);
-        let result = encode_as_png_base64(&img);
+        let result = encode_as_base64(&img, IconFormat::Png);
 
         assert!(result.is_ok());
         let base64_str = result.unwrap();
@@ -110,4 +365,225 @@ mod tests {
             .decode(&base64_str)
             .is_ok());
     }
+
+    fn decode_base64(data: &str) -> Vec<u8> {
+        use base64::Engine;
+        base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_encode_formats_decode_back() {
+        let img = DynamicImage::new_rgb8(64, 32);
+
+        for (format, image_format) in [
+            (IconFormat::Png, ImageFormat::Png),
+            (IconFormat::WebP, ImageFormat::WebP),
+        ] {
+            let bytes = decode_base64(&encode_as_base64(&img, format).unwrap());
+            let decoded = image::load_from_memory_with_format(&bytes, image_format).unwrap();
+            assert_eq!(decoded.dimensions(), (64, 32));
+        }
+    }
+
+    #[test]
+    fn test_parse_icon_format() {
+        assert_eq!(IconFormat::parse(None).unwrap(), IconFormat::Png);
+        assert_eq!(IconFormat::parse(Some("WebP")).unwrap(), IconFormat::WebP);
+        assert!(IconFormat::parse(Some("gif")).is_err());
+    }
+
+    #[test]
+    fn test_process_icon_strips_icc_profile() {
+        use image::codecs::png::PngEncoder;
+        use image::ImageEncoder;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("icon.png");
+
+        // Write a source PNG carrying an ICC profile chunk
+        let mut source = Vec::new();
+        let mut encoder = PngEncoder::new(&mut source);
+        encoder.set_icc_profile(vec![0u8; 128]).unwrap();
+        encoder
+            .write_image(&[255u8; 4 * 4 * 3], 4, 4, image::ExtendedColorType::Rgb8)
+            .unwrap();
+        assert!(source.windows(4).any(|w| w == b"iCCP"));
+        std::fs::write(&path, &source).unwrap();
+
+        let uri = process_cluster_icon(&path, IconFormat::Png).unwrap();
+        let encoded = uri.strip_prefix("data:image/png;base64,").unwrap();
+        let output = decode_base64(encoded);
+
+        assert!(!output.windows(4).any(|w| w == b"iCCP"));
+        assert!(!output.windows(4).any(|w| w == b"eXIf"));
+    }
+
+    #[test]
+    fn test_process_icon_keeps_png_transparency() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("icon.png");
+        let mut source = image::RgbaImage::new(4, 4);
+        source.put_pixel(1, 2, image::Rgba([10, 20, 30, 128]));
+        source.save(&path).unwrap();
+
+        let uri = process_cluster_icon(&path, IconFormat::Png).unwrap();
+        let encoded = uri.strip_prefix("data:image/png;base64,").unwrap();
+        let output = image::load_from_memory(&decode_base64(encoded))
+            .unwrap()
+            .to_rgba8();
+
+        assert_eq!(output.get_pixel(0, 0), &image::Rgba([0, 0, 0, 0]));
+        assert_eq!(output.get_pixel(1, 2), &image::Rgba([10, 20, 30, 128]));
+    }
+
+    #[test]
+    fn test_process_icon_rasterizes_svg() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("icon");
+        std::fs::write(
+            &path,
+            r##"<?xml version="1.0"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10">
+  <rect x="0" y="0" width="10" height="10" fill="#ff0000"/>
+</svg>"##,
+        )
+        .unwrap();
+
+        let uri = process_cluster_icon(&path, IconFormat::Png).unwrap();
+        let encoded = uri.strip_prefix("data:image/png;base64,").unwrap();
+        let output = image::load_from_memory(&decode_base64(encoded))
+            .unwrap()
+            .to_rgba8();
+
+        assert_eq!(output.dimensions(), (MAX_ICON_SIZE, MAX_ICON_SIZE / 2));
+        assert_eq!(output.get_pixel(10, 10), &image::Rgba([255, 0, 0, 255]));
+        // The right half is outside the rect and stays transparent
+        assert_eq!(output.get_pixel(MAX_ICON_SIZE - 10, 10)[3], 0);
+    }
+
+    #[test]
+    fn test_check_file_size() {
+        assert!(check_file_size(1024, MAX_ICON_FILE_BYTES).is_ok());
+        assert!(
+            check_file_size(MAX_ICON_FILE_BYTES + 1, MAX_ICON_FILE_BYTES)
+                .unwrap_err()
+                .contains("too large")
+        );
+    }
+
+    #[test]
+    fn test_process_icon_accepts_small_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("icon.png");
+        DynamicImage::new_rgb8(2, 2).save(&path).unwrap();
+
+        assert!(process_cluster_icon(&path, IconFormat::Png).is_ok());
+    }
+
+    fn png_chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        fn crc32(bytes: &[u8]) -> u32 {
+            let mut crc = 0xffff_ffffu32;
+            for &byte in bytes {
+                crc ^= byte as u32;
+                for _ in 0..8 {
+                    crc = if crc & 1 != 0 {
+                        (crc >> 1) ^ 0xedb8_8320
+                    } else {
+                        crc >> 1
+                    };
+                }
+            }
+            !crc
+        }
+
+        let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(kind);
+        chunk.extend_from_slice(data);
+        let crc = crc32(&chunk[4..]);
+        chunk.extend_from_slice(&crc.to_be_bytes());
+        chunk
+    }
+
+    #[test]
+    fn test_process_icon_rejects_huge_declared_dimensions() {
+        // A valid PNG header declaring 100000x100000 RGBA pixels, with no pixel data
+        let mut header = Vec::new();
+        header.extend_from_slice(&100_000u32.to_be_bytes());
+        header.extend_from_slice(&100_000u32.to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend(png_chunk(b"IHDR", &header));
+        png.extend(png_chunk(b"IDAT", &[]));
+        png.extend(png_chunk(b"IEND", &[]));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bomb.png");
+        std::fs::write(&path, &png).unwrap();
+
+        let err = process_cluster_icon(&path, IconFormat::Png).unwrap_err();
+        assert!(err.contains("100000x100000"), "{}", err);
+    }
+
+    #[test]
+    fn test_save_and_resolve_cluster_icon() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.png");
+        DynamicImage::new_rgba8(4, 4).save(&source).unwrap();
+        let icons_dir = dir.path().join("icons");
+        let id = uuid::Uuid::new_v4().to_string();
+
+        let name = save_cluster_icon(&source, IconFormat::Png, &id, &icons_dir).unwrap();
+        assert_eq!(name, format!("{}.png", id));
+        assert!(icons_dir.join(&name).is_file());
+
+        let uri = resolve_cluster_icon(&name, &icons_dir).unwrap();
+        assert!(uri.starts_with("data:image/png;base64,"));
+
+        // Emoji and inline data URIs pass through untouched
+        assert_eq!(resolve_cluster_icon("🚀", &icons_dir).unwrap(), "🚀");
+        assert_eq!(
+            resolve_cluster_icon("data:image/png;base64,AAAA", &icons_dir).unwrap(),
+            "data:image/png;base64,AAAA"
+        );
+    }
+
+    #[test]
+    fn test_remove_stored_icon() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.png");
+        DynamicImage::new_rgba8(4, 4).save(&source).unwrap();
+        let id = uuid::Uuid::new_v4().to_string();
+        let name = save_cluster_icon(&source, IconFormat::Png, &id, dir.path()).unwrap();
+
+        remove_stored_icon(&name, dir.path());
+        assert!(!dir.path().join(&name).exists());
+        // Already gone, and values that aren't stored files, are no-ops
+        remove_stored_icon(&name, dir.path());
+        remove_stored_icon("source.png", dir.path());
+        assert!(source.is_file());
+    }
+
+    #[test]
+    fn test_save_cluster_icon_rejects_invalid_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.png");
+        DynamicImage::new_rgba8(4, 4).save(&source).unwrap();
+
+        let err = save_cluster_icon(&source, IconFormat::Png, "../escape", dir.path()).unwrap_err();
+        assert!(err.contains("Invalid cluster id"));
+    }
+
+    #[test]
+    fn test_process_icon_webp_mime_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("icon.png");
+        DynamicImage::new_rgb8(8, 8).save(&path).unwrap();
+
+        let uri = process_cluster_icon(&path, IconFormat::WebP).unwrap();
+
+        assert!(uri.starts_with("data:image/webp;base64,"));
+    }
 }
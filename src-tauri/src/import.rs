@@ -1,4 +1,5 @@
-use crate::cluster_manager::ClusterManagerState;
+use crate::cluster_manager::{context_server, ClusterManager, ClusterManagerState};
+use crate::k8s::client::verify_kubeconfig;
 use kube::config::Kubeconfig;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -102,10 +103,51 @@ pub fn extract_context(
     source_path: &Path,
     context_name: &str,
     cluster_id: &str,
+) -> Result<PathBuf, String> {
+    extract_context_to(
+        source_path,
+        context_name,
+        cluster_id,
+        &crate::config::get_kubeconfigs_dir(),
+    )
+}
+
+/// Like [`extract_context`], writing `<cluster_id>.yaml` into `kubeconfigs_dir`
+pub(crate) fn extract_context_to(
+    source_path: &Path,
+    context_name: &str,
+    cluster_id: &str,
+    kubeconfigs_dir: &Path,
 ) -> Result<PathBuf, String> {
     let kubeconfig = Kubeconfig::read_from(source_path)
         .map_err(|e| format!("Failed to read kubeconfig: {}", e))?;
+    let new_config = isolate_context(&kubeconfig, context_name)?;
+
+    // Save to <kubeconfigs_dir>/<cluster_id>.yaml
+    let config_path = kubeconfigs_dir.join(format!("{}.yaml", cluster_id));
+
+    // Serialize and write the kubeconfig
+    let yaml_content = serde_yaml::to_string(&new_config)
+        .map_err(|e| format!("Failed to serialize kubeconfig: {}", e))?;
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(&config_path)
+        .map_err(|e| format!("Failed to open kubeconfig for writing: {}", e))?;
+    file.write_all(yaml_content.as_bytes())
+        .map_err(|e| format!("Failed to write kubeconfig: {}", e))?;
+    crate::config::set_owner_only_file_permissions(&config_path)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
 
+    Ok(config_path)
+}
+
+/// A copy of `kubeconfig` reduced to one context with its cluster and user.
+/// Entries are cloned whole, so proxy, TLS server name, CA and extension settings
+/// carry over, as do the file's preferences and top-level extensions.
+fn isolate_context(kubeconfig: &Kubeconfig, context_name: &str) -> Result<Kubeconfig, String> {
     // Find the context
     let context = kubeconfig
         .contexts
@@ -135,35 +177,83 @@ pub fn extract_context(
         .find(|u| &u.name == user_name)
         .ok_or_else(|| format!("User '{}' not found", user_name))?;
 
-    // Create new isolated kubeconfig
-    let new_config = Kubeconfig {
+    Ok(Kubeconfig {
+        preferences: kubeconfig.preferences.clone(),
         clusters: vec![cluster.clone()],
         auth_infos: vec![user.clone()],
         contexts: vec![context.clone()],
         current_context: Some(context_name.to_string()),
-        ..Default::default()
-    };
+        extensions: kubeconfig.extensions.clone(),
+        kind: kubeconfig.kind.clone(),
+        api_version: kubeconfig.api_version.clone(),
+    })
+}
 
-    // Save to ~/.kore/kubeconfigs/<cluster_id>.yaml
-    let kubeconfigs_dir = crate::config::get_kubeconfigs_dir();
-    let config_path = kubeconfigs_dir.join(format!("{}.yaml", cluster_id));
+/// The saved cluster that already has `context_name` against `server_url`, if any
+pub fn is_duplicate_context(
+    manager: &ClusterManager,
+    context_name: &str,
+    server_url: &str,
+) -> Result<Option<String>, String> {
+    for cluster in manager.list_clusters()? {
+        if cluster.context_name != context_name {
+            continue;
+        }
+        if manager.cluster_server(&cluster.id)?.as_deref() == Some(server_url) {
+            return Ok(Some(cluster.id));
+        }
+    }
+    Ok(None)
+}
 
-    // Serialize and write the kubeconfig
-    let yaml_content = serde_yaml::to_string(&new_config)
-        .map_err(|e| format!("Failed to serialize kubeconfig: {}", e))?;
+/// Outcome of [`import_add_cluster`]. A context that's already saved isn't added
+/// again: `created` is false and `existing_id` names the saved cluster.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportResult {
+    pub created: bool,
+    pub cluster_id: Option<String>,
+    pub existing_id: Option<String>,
+}
 
-    let mut file = std::fs::OpenOptions::new()
-        .create(true)
-        .truncate(true)
-        .write(true)
-        .open(&config_path)
-        .map_err(|e| format!("Failed to open kubeconfig for writing: {}", e))?;
-    file.write_all(yaml_content.as_bytes())
-        .map_err(|e| format!("Failed to write kubeconfig: {}", e))?;
-    crate::config::set_owner_only_file_permissions(&config_path)
-        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+/// One context to save in [`import_add_clusters_bulk`]
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkImportEntry {
+    pub name: String,
+    pub context_name: String,
+    pub source_file: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
 
-    Ok(config_path)
+/// Extract `entry`'s context under a fresh cluster ID and save it. The extracted
+/// file is removed again if the cluster can't be saved.
+fn import_entry(
+    manager: &ClusterManager,
+    entry: BulkImportEntry,
+) -> Result<(String, PathBuf), String> {
+    let cluster_id = uuid::Uuid::new_v4().to_string();
+    let config_path = extract_context(
+        Path::new(&entry.source_file),
+        &entry.context_name,
+        &cluster_id,
+    )
+    .map_err(|e| format!("{}: {}", entry.context_name, e))?;
+
+    manager
+        .add_cluster(
+            entry.name,
+            entry.context_name.clone(),
+            config_path.clone(),
+            None,
+            None,
+            entry.tags,
+            None,
+        )
+        .map(|cluster| (cluster.id, config_path.clone()))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&config_path);
+            format!("{}: {}", entry.context_name, e)
+        })
 }
 
 // Tauri Commands
@@ -188,23 +278,102 @@ pub async fn import_add_cluster(
     icon: Option<String>,
     description: Option<String>,
     tags: Vec<String>,
+    group_name: Option<String>,
+    allow_duplicate: Option<bool>,
+    validate: Option<bool>,
     state: State<'_, ClusterManagerState>,
-) -> Result<String, String> {
+) -> Result<ImportResult, String> {
+    let source_path = PathBuf::from(source_file);
+
+    if !allow_duplicate.unwrap_or(false) {
+        let kubeconfig = Kubeconfig::read_from(&source_path)
+            .map_err(|e| format!("Failed to read kubeconfig: {}", e))?;
+        if let Some(server) = context_server(&kubeconfig, &context_name) {
+            let manager = state
+                .0
+                .lock()
+                .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            if let Some(existing_id) = is_duplicate_context(&manager, &context_name, &server)? {
+                return Ok(ImportResult {
+                    created: false,
+                    cluster_id: None,
+                    existing_id: Some(existing_id),
+                });
+            }
+        }
+    }
+
     // Generate cluster ID
     let cluster_id = uuid::Uuid::new_v4().to_string();
 
     // Extract context to isolated config file
-    let source_path = PathBuf::from(source_file);
     let config_path = extract_context(&source_path, &context_name, &cluster_id)?;
 
+    // Opt-in, as exec auth plugins can take a while even when they work
+    if validate.unwrap_or(false) {
+        if let Err(e) = verify_kubeconfig(&config_path, &context_name).await {
+            let _ = std::fs::remove_file(&config_path);
+            return Err(e);
+        }
+    }
+
     // Add to database
     let manager = state
         .0
         .lock()
         .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-    let cluster = manager.add_cluster(name, context_name, config_path, icon, description, tags)?;
+    let cluster = manager
+        .add_cluster(
+            name,
+            context_name,
+            config_path.clone(),
+            icon,
+            description,
+            tags,
+            group_name,
+        )
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&config_path);
+        })?;
+
+    Ok(ImportResult {
+        created: true,
+        cluster_id: Some(cluster.id),
+        existing_id: None,
+    })
+}
 
-    Ok(cluster.id)
+/// Save several contexts at once, in one transaction. Results line up with
+/// `entries`; one context failing doesn't stop the rest.
+#[tauri::command]
+pub async fn import_add_clusters_bulk(
+    entries: Vec<BulkImportEntry>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<Result<String, String>>, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    let mut config_paths = Vec::new();
+    let results = manager.in_transaction(|manager| {
+        entries
+            .into_iter()
+            .map(|entry| {
+                import_entry(manager, entry).map(|(cluster_id, config_path)| {
+                    config_paths.push(config_path);
+                    cluster_id
+                })
+            })
+            .collect()
+    });
+    if results.is_err() {
+        // The commit failed, so none of the clusters were saved and their extracted
+        // configs would be left behind with nothing pointing at them
+        for config_path in &config_paths {
+            let _ = std::fs::remove_file(config_path);
+        }
+    }
+    results
 }
 
 #[cfg(test)]
@@ -319,6 +488,125 @@ mod tests {
         assert!(contexts.iter().any(|c| c.context_name == "prod-context"));
     }
 
+    #[test]
+    fn test_isolate_context_keeps_connection_settings() {
+        let kubeconfig = Kubeconfig::from_yaml(
+            r#"
+apiVersion: v1
+kind: Config
+preferences:
+  colors: true
+clusters:
+- name: edge
+  cluster:
+    server: https://10.0.0.1:6443
+    proxy-url: socks5://localhost:1080
+    tls-server-name: api.edge.internal
+    certificate-authority-data: Y2EtZGF0YQ==
+- name: other
+  cluster:
+    server: https://other.example.com
+users:
+- name: edge-user
+  user:
+    token: test-token
+contexts:
+- name: edge
+  context:
+    cluster: edge
+    user: edge-user
+- name: other
+  context:
+    cluster: other
+    user: edge-user
+"#,
+        )
+        .unwrap();
+
+        let isolated = isolate_context(&kubeconfig, "edge").unwrap();
+        // Round-trip through YAML, as extract_context writes it to disk
+        let yaml = serde_yaml::to_string(&isolated).unwrap();
+        let reloaded = Kubeconfig::from_yaml(&yaml).unwrap();
+
+        assert_eq!(reloaded.clusters.len(), 1);
+        let cluster = reloaded.clusters[0].cluster.as_ref().unwrap();
+        assert_eq!(
+            cluster.proxy_url.as_deref(),
+            Some("socks5://localhost:1080")
+        );
+        assert_eq!(
+            cluster.tls_server_name.as_deref(),
+            Some("api.edge.internal")
+        );
+        assert_eq!(
+            cluster.certificate_authority_data.as_deref(),
+            Some("Y2EtZGF0YQ==")
+        );
+        assert_eq!(reloaded.preferences.unwrap().colors, Some(true));
+        assert_eq!(reloaded.kind.as_deref(), Some("Config"));
+        assert_eq!(reloaded.current_context.as_deref(), Some("edge"));
+    }
+
+    #[test]
+    fn test_import_entry_failure_leaves_no_cluster() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path =
+            create_test_kubeconfig(temp_dir.path(), "config", &[("ctx1", "cluster1", "user1")]);
+        let manager = ClusterManager::new(temp_dir.path().join("clusters.db")).unwrap();
+
+        let entry = BulkImportEntry {
+            name: "missing".to_string(),
+            context_name: "no-such-context".to_string(),
+            source_file: config_path.to_string_lossy().to_string(),
+            tags: vec![],
+        };
+        let result = manager
+            .in_transaction(|manager| import_entry(manager, entry))
+            .unwrap();
+
+        assert!(result.unwrap_err().contains("no-such-context"));
+        assert!(manager.list_clusters().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_is_duplicate_context_matches_context_and_server() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = create_test_kubeconfig(
+            temp_dir.path(),
+            "config",
+            &[("prod", "prod-cluster", "prod-user")],
+        );
+        let manager = ClusterManager::new(temp_dir.path().join("clusters.db")).unwrap();
+        let saved = manager
+            .add_cluster(
+                "prod".to_string(),
+                "prod".to_string(),
+                config_path.clone(),
+                None,
+                None,
+                vec![],
+                None,
+            )
+            .unwrap();
+
+        let kubeconfig = Kubeconfig::read_from(&config_path).unwrap();
+        let server = context_server(&kubeconfig, "prod").unwrap();
+        assert_eq!(server, "https://example.com");
+
+        assert_eq!(
+            is_duplicate_context(&manager, "prod", &server).unwrap(),
+            Some(saved.id)
+        );
+        assert_eq!(
+            is_duplicate_context(&manager, "prod", "https://other.example.com").unwrap(),
+            None
+        );
+        assert_eq!(
+            is_duplicate_context(&manager, "dev", &server).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_invalid_kubeconfig() {
         let temp_dir = TempDir::new().unwrap();
@@ -5,6 +5,8 @@ const MAX_CONTEXT_NAME_LEN: usize = 253;
 const MAX_DESCRIPTION_LEN: usize = 1000;
 const MAX_TAGS_COUNT: usize = 20;
 const MAX_TAG_LEN: usize = 32;
+const MAX_GROUP_NAME_LEN: usize = 64;
+const MAX_NAMESPACE_LEN: usize = 63;
 
 fn is_allowed_name_char(c: char) -> bool {
     c.is_ascii_alphanumeric()
@@ -85,6 +87,63 @@ pub fn validate_description(description: Option<String>) -> Result<Option<String
     Ok(Some(trimmed.to_string()))
 }
 
+pub fn validate_group_name(group_name: Option<String>) -> Result<Option<String>, String> {
+    let Some(value) = group_name else {
+        return Ok(None);
+    };
+
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    if trimmed.len() > MAX_GROUP_NAME_LEN {
+        return Err(format!(
+            "Group name must be {} characters or fewer",
+            MAX_GROUP_NAME_LEN
+        ));
+    }
+    if !trimmed.chars().all(is_allowed_name_char) {
+        return Err(
+            "Group name contains invalid characters. Allowed: letters, numbers, space, - _ . : / @ + ( ) [ ]"
+                .to_string(),
+        );
+    }
+
+    Ok(Some(trimmed.to_string()))
+}
+
+/// Namespaces are DNS labels: lowercase letters, digits and `-`, starting and
+/// ending with a letter or digit
+pub fn validate_namespace_name(namespace: Option<String>) -> Result<Option<String>, String> {
+    let Some(value) = namespace else {
+        return Ok(None);
+    };
+
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    if trimmed.len() > MAX_NAMESPACE_LEN {
+        return Err(format!(
+            "Namespace must be {} characters or fewer",
+            MAX_NAMESPACE_LEN
+        ));
+    }
+    if !trimmed
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        || trimmed.starts_with('-')
+        || trimmed.ends_with('-')
+    {
+        return Err(format!(
+            "Namespace '{}' is invalid. Use lowercase letters, numbers and -, starting and ending with a letter or number",
+            trimmed
+        ));
+    }
+
+    Ok(Some(trimmed.to_string()))
+}
+
 pub fn validate_tags(tags: Vec<String>) -> Result<Vec<String>, String> {
     if tags.len() > MAX_TAGS_COUNT {
         return Err(format!("At most {} tags are allowed", MAX_TAGS_COUNT));
@@ -147,6 +206,28 @@ mod tests {
         assert!(err.contains("control"));
     }
 
+    #[test]
+    fn group_name_is_trimmed_and_blank_is_none() {
+        assert_eq!(
+            validate_group_name(Some(" prod ".to_string())).unwrap(),
+            Some("prod".to_string())
+        );
+        assert_eq!(validate_group_name(Some("  ".to_string())).unwrap(), None);
+        assert!(validate_group_name(Some("prod\nstaging".to_string())).is_err());
+    }
+
+    #[test]
+    fn namespace_name_must_be_a_dns_label() {
+        assert_eq!(
+            validate_namespace_name(Some(" team-a ".to_string())).unwrap(),
+            Some("team-a".to_string())
+        );
+        assert_eq!(validate_namespace_name(Some("".to_string())).unwrap(), None);
+        assert!(validate_namespace_name(Some("Team-A".to_string())).is_err());
+        assert!(validate_namespace_name(Some("-team".to_string())).is_err());
+        assert!(validate_namespace_name(Some("a".repeat(64))).is_err());
+    }
+
     #[test]
     fn tags_are_trimmed_and_deduplicated() {
         let tags = validate_tags(vec!["  prod ".to_string(), "team-a".to_string()]).unwrap();
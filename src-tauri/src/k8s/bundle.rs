@@ -0,0 +1,124 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::config;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::pod::fetch_pod_events;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{Api, ApiResource, DynamicObject, LogParams};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const DEFAULT_BUNDLE_LOG_LINES: i64 = 500;
+
+/// Directory name for a bundle, e.g. `web-7d9f-20240101T120000Z`
+fn bundle_dir_name(pod_name: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    format!("{}-{}", pod_name, now.format("%Y%m%dT%H%M%SZ"))
+}
+
+fn write_bundle_file(dir: &Path, name: &str, contents: &str) -> Result<(), String> {
+    let path = dir.join(name);
+    fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", name, e))?;
+    config::set_owner_only_file_permissions(&path)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))
+}
+
+/// Write a pod's manifest, events and recent (and previous) container logs into a new
+/// directory under `dest_dir`, for sharing with teammates. Returns the bundle path.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_export_pod_bundle(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    dest_dir: String,
+    tail_lines: Option<i64>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let bundle_dir = config::validate_new_file_destination(
+        &PathBuf::from(dest_dir).join(bundle_dir_name(&pod_name, chrono::Utc::now())),
+    )?;
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = pods
+        .get(&pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod '{}': {}", pod_name, e))?;
+
+    // Export the manifest as the API server returns it, minus the managedFields noise
+    let dynamic: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), &namespace, &ApiResource::erase::<Pod>(&()));
+    let mut manifest = dynamic
+        .get(&pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod '{}': {}", pod_name, e))?;
+    manifest.metadata.managed_fields = None;
+    let manifest_yaml =
+        serde_yaml::to_string(&manifest).map_err(|e| format!("Failed to serialize pod: {}", e))?;
+
+    let events = fetch_pod_events(client, &namespace, &pod_name).await?;
+    let events_yaml =
+        serde_yaml::to_string(&events).map_err(|e| format!("Failed to serialize events: {}", e))?;
+
+    let container_names: Vec<String> = pod
+        .spec
+        .as_ref()
+        .map(|spec| {
+            spec.init_containers
+                .iter()
+                .flatten()
+                .chain(spec.containers.iter())
+                .map(|c| c.name.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    fs::create_dir(&bundle_dir).map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+    config::set_owner_only_dir_permissions(&bundle_dir)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    write_bundle_file(&bundle_dir, "pod.yaml", &manifest_yaml)?;
+    write_bundle_file(&bundle_dir, "events.yaml", &events_yaml)?;
+
+    let tail_lines = tail_lines.unwrap_or(DEFAULT_BUNDLE_LOG_LINES);
+    for container in container_names {
+        let log_params = LogParams {
+            container: Some(container.clone()),
+            tail_lines: Some(tail_lines),
+            ..Default::default()
+        };
+        match pods.logs(&pod_name, &log_params).await {
+            Ok(logs) => write_bundle_file(&bundle_dir, &format!("{}.log", container), &logs)?,
+            Err(e) => write_bundle_file(
+                &bundle_dir,
+                &format!("{}.log", container),
+                &format!("Failed to fetch logs: {}\n", e),
+            )?,
+        }
+
+        // Previous logs only exist after a restart; their absence isn't an error
+        let previous_params = LogParams {
+            previous: true,
+            ..log_params
+        };
+        if let Ok(logs) = pods.logs(&pod_name, &previous_params).await {
+            write_bundle_file(&bundle_dir, &format!("{}.previous.log", container), &logs)?;
+        }
+    }
+
+    Ok(bundle_dir.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_dir_name_includes_utc_timestamp() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        assert_eq!(bundle_dir_name("web-0", now), "web-0-20240102T030405Z");
+    }
+}
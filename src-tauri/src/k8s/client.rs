@@ -1,22 +1,37 @@
 use crate::cluster_manager::ClusterManagerState;
 use crate::config;
+use crate::error::AppError;
+use crate::settings::load_settings;
 use k8s_openapi::api::core::v1::Namespace;
 use kube::api::{Api, ListParams};
+use kube::client::{Body, ClientBuilder};
 use kube::config::Kubeconfig;
 use kube::{Client, Config};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tauri::State;
+use tower::util::MapResponseLayer;
 
-// Helper to find which file contains the context
-pub fn find_kubeconfig_path_for_context(context_name: &str) -> Option<PathBuf> {
-    // 1. Standard locations
+/// The user's own kubeconfig files: each existing file in `kubeconfig_env`, the value
+/// of `KUBECONFIG` (a `:`-separated list, `;` on Windows), then ~/.kube/config
+fn user_kubeconfig_paths(kubeconfig_env: Option<&OsStr>) -> Vec<PathBuf> {
     let mut paths = vec![];
-    if let Ok(p) = std::env::var("KUBECONFIG") {
-        paths.push(PathBuf::from(p));
+    if let Some(value) = kubeconfig_env {
+        paths.extend(std::env::split_paths(value).filter(|p| p.is_file()));
     }
     if let Some(home) = dirs::home_dir() {
         paths.push(home.join(".kube").join("config"));
     }
+    paths
+}
+
+/// Kubeconfig files to scan for contexts: the user's kubeconfigs, then the app's saved configs
+fn kubeconfig_search_paths(kubeconfig_env: Option<&OsStr>) -> Vec<PathBuf> {
+    // 1. Standard locations
+    let mut paths = user_kubeconfig_paths(kubeconfig_env);
 
     // 2. Custom app config directory
     let app_kube_dir = config::get_kubeconfigs_dir();
@@ -31,6 +46,11 @@ pub fn find_kubeconfig_path_for_context(context_name: &str) -> Option<PathBuf> {
         }
     }
 
+    paths
+}
+
+/// The first of `paths` that defines `context_name`
+fn find_context_in(paths: Vec<PathBuf>, context_name: &str) -> Option<PathBuf> {
     // Check each file
     for path in paths {
         if path.exists() {
@@ -47,104 +67,356 @@ pub fn find_kubeconfig_path_for_context(context_name: &str) -> Option<PathBuf> {
     None
 }
 
+// Helper to find which file contains the context
+pub fn find_kubeconfig_path_for_context(context_name: &str) -> Option<PathBuf> {
+    find_context_in(
+        kubeconfig_search_paths(std::env::var_os("KUBECONFIG").as_deref()),
+        context_name,
+    )
+}
+
+/// Whether `command` can be run: a path is checked directly, a bare name is
+/// looked up in each directory of `path_var`
+fn command_exists(command: &str, path_var: Option<&OsStr>) -> bool {
+    let command = Path::new(command);
+    if command.components().count() > 1 {
+        return command.is_file();
+    }
+
+    // Windows resolves `aws` to `aws.exe`
+    let extensions: &[&str] = if cfg!(windows) {
+        &["", ".exe", ".cmd", ".bat"]
+    } else {
+        &[""]
+    };
+    path_var
+        .map(|paths| {
+            std::env::split_paths(paths).any(|dir| {
+                extensions.iter().any(|ext| {
+                    let mut name = command.as_os_str().to_os_string();
+                    name.push(ext);
+                    dir.join(name).is_file()
+                })
+            })
+        })
+        .unwrap_or(false)
+}
+
+fn check_exec_plugin_in(
+    kubeconfig: &Kubeconfig,
+    context_name: &str,
+    path_var: Option<&OsStr>,
+) -> Result<(), String> {
+    let user_name = kubeconfig
+        .contexts
+        .iter()
+        .find(|c| c.name == context_name)
+        .and_then(|c| c.context.as_ref())
+        .and_then(|c| c.user.as_ref());
+    let command = kubeconfig
+        .auth_infos
+        .iter()
+        .find(|u| Some(&u.name) == user_name)
+        .and_then(|u| u.auth_info.as_ref())
+        .and_then(|auth| auth.exec.as_ref())
+        .and_then(|exec| exec.command.as_deref());
+
+    match command {
+        Some(command) if !command_exists(command, path_var) => {
+            Err(format!("Auth plugin '{}' not found on PATH", command))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Fail early when `context_name` authenticates with an exec plugin (`aws`,
+/// `gke-gcloud-auth-plugin`, `kubelogin`, ...) that isn't installed, rather than
+/// with the opaque error client creation gives
+pub fn check_exec_plugin(kubeconfig: &Kubeconfig, context_name: &str) -> Result<(), String> {
+    check_exec_plugin_in(
+        kubeconfig,
+        context_name,
+        std::env::var_os("PATH").as_deref(),
+    )
+}
+
 // Helper to create client
-pub async fn create_client_for_context(context_name: &str) -> Result<Client, String> {
-    let config_path = find_kubeconfig_path_for_context(context_name).ok_or_else(|| {
-        format!(
+pub async fn create_client_for_context(context_name: &str) -> Result<Client, AppError> {
+    let config = context_config_in(
+        kubeconfig_search_paths(std::env::var_os("KUBECONFIG").as_deref()),
+        context_name,
+    )
+    .await?;
+    Client::try_from(config).map_err(|e| AppError::from(e).context("Failed to create client"))
+}
+
+async fn context_config_in(paths: Vec<PathBuf>, context_name: &str) -> Result<Config, AppError> {
+    let config_path = find_context_in(paths, context_name).ok_or_else(|| {
+        AppError::NotFound(format!(
             "Context '{}' not found in any kubeconfig file",
             context_name
-        )
+        ))
     })?;
 
-    let kubeconfig = Kubeconfig::read_from(&config_path)
-        .map_err(|e| format!("Failed to read kubeconfig {:?}: {}", config_path, e))?;
+    let kubeconfig = Kubeconfig::read_from(&config_path).map_err(|e| {
+        AppError::Config(format!(
+            "Failed to read kubeconfig {:?}: {}",
+            config_path, e
+        ))
+    })?;
+    check_exec_plugin(&kubeconfig, context_name).map_err(AppError::Config)?;
 
     let options = kube::config::KubeConfigOptions {
         context: Some(context_name.to_string()),
         ..Default::default()
     };
 
-    let config = Config::from_custom_kubeconfig(kubeconfig, &options)
+    Config::from_custom_kubeconfig(kubeconfig, &options)
         .await
-        .map_err(|e| format!("Failed to load config: {}", e))?;
+        .map_err(|e| AppError::Config(format!("Failed to load config: {}", e)))
+}
+
+/// A client whose requests wait for a slot in the cluster's request limit
+fn create_limited_client(
+    config: Config,
+    cluster_id: &str,
+    state: &ClusterManagerState,
+) -> Result<Client, AppError> {
+    let limit = load_settings(state).max_concurrent_requests as usize;
+    let layer = state
+        .2
+        .layer(cluster_id, limit)
+        .map_err(AppError::Internal)?;
+    // A 401 means the cached client's credentials are stale (expired token, rotated
+    // certificate); drop it so the next command rebuilds it from the saved config
+    let cache = Arc::downgrade(&state.1);
+    let key = cluster_id.to_string();
+    let invalidate_on_401 = MapResponseLayer::new(move |response: http::Response<Body>| {
+        if response.status() == http::StatusCode::UNAUTHORIZED {
+            if let Some(cache) = cache.upgrade() {
+                cache.invalidate(&key);
+            }
+        }
+        response
+    });
+    // Building fails only on what the config holds (client certificate, proxy URL, ...)
+    Ok(ClientBuilder::try_from(config)
+        .map_err(|e| AppError::Config(with_tls_hint(format!("Failed to create client: {}", e))))?
+        .with_layer(&invalidate_on_401)
+        .with_layer(&layer)
+        .build())
+}
+
+/// Point certificate failures, which otherwise read as a generic connection
+/// error, at the ways to connect to a self-signed cluster
+pub fn with_tls_hint(message: String) -> String {
+    let lower = message.to_lowercase();
+    let is_cert_error = [
+        "certificate",
+        "x509",
+        "unknownissuer",
+        "tls handshake",
+        "ssl",
+    ]
+    .iter()
+    .any(|needle| lower.contains(needle));
+    if !is_cert_error {
+        return message;
+    }
+    format!(
+        "{} (certificate verification failed: add the cluster's CA to its kubeconfig, \
+         or for a self-signed cluster set insecure-skip-tls-verify or accept invalid certificates for it)",
+        message
+    )
+}
+
+/// Prefix of pseudo cluster ids that name a kubeconfig context directly, so
+/// cluster-based commands work on contexts that were never imported
+pub const CONTEXT_CLUSTER_PREFIX: &str = "ctx:";
+
+/// The context named by a `ctx:<name>` pseudo cluster id, if it is one
+pub fn context_from_cluster_id(cluster_id: &str) -> Option<&str> {
+    cluster_id
+        .strip_prefix(CONTEXT_CLUSTER_PREFIX)
+        .filter(|name| !name.is_empty())
+}
+
+/// How long a cached client is reused before its config is read again, so rotated
+/// credentials in the saved config are eventually picked up
+const CLIENT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Clients built by `create_client_for_cluster`, keyed by cluster ID. Entries must be
+/// invalidated when a cluster's config changes or the cluster is deleted.
+#[derive(Default)]
+pub struct ClientCache(Mutex<CachedClients>);
+
+#[derive(Default)]
+struct CachedClients {
+    clients: HashMap<String, (Client, Instant)>,
+    /// Bumped by every invalidation, so a client built from a config read before it
+    /// isn't cached over the invalidation
+    generation: u64,
+}
 
-    Client::try_from(config).map_err(|e| format!("Failed to create client: {}", e))
+impl ClientCache {
+    /// The cached client for a cluster, unless it's older than `CLIENT_CACHE_TTL`
+    pub fn get(&self, cluster_id: &str) -> Option<Client> {
+        self.get_at(cluster_id, Instant::now())
+    }
+
+    fn get_at(&self, cluster_id: &str, now: Instant) -> Option<Client> {
+        let mut cache = self.0.lock().ok()?;
+        match cache.clients.get(cluster_id) {
+            Some((client, created))
+                if now.saturating_duration_since(*created) < CLIENT_CACHE_TTL =>
+            {
+                Some(client.clone())
+            }
+            Some(_) => {
+                cache.clients.remove(cluster_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Read before loading a cluster's config, and passed back to `insert`
+    pub fn generation(&self) -> u64 {
+        self.0
+            .lock()
+            .map(|cache| cache.generation)
+            .unwrap_or_default()
+    }
+
+    /// Cache a client built from a config read at `generation`; it's dropped if the
+    /// cache was invalidated while it was being built
+    pub fn insert(&self, cluster_id: &str, client: Client, generation: u64) {
+        if let Ok(mut cache) = self.0.lock() {
+            if cache.generation == generation {
+                cache
+                    .clients
+                    .insert(cluster_id.to_string(), (client, Instant::now()));
+            }
+        }
+    }
+
+    /// Drop a cluster's client so the next command rebuilds it from the current config
+    pub fn invalidate(&self, cluster_id: &str) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.clients.remove(cluster_id);
+            cache.generation += 1;
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.clients.clear();
+            cache.generation += 1;
+        }
+    }
 }
 
 // NEW: Helper to create client from cluster ID
+/// Errors keep their cause: `NotFound` for an unknown cluster or context, `Config`
+/// for an unreadable or unusable kubeconfig, `Internal` for app state failures.
+#[tracing::instrument(skip(state))]
 pub async fn create_client_for_cluster(
     cluster_id: &str,
     state: &State<'_, ClusterManagerState>,
-) -> Result<Client, String> {
+) -> Result<Client, AppError> {
+    // Non-imported contexts are resolved from KUBECONFIG / ~/.kube/config only; the
+    // app's saved configs belong to imported clusters
+    if let Some(context_name) = context_from_cluster_id(cluster_id) {
+        let config = context_config_in(
+            user_kubeconfig_paths(std::env::var_os("KUBECONFIG").as_deref()),
+            context_name,
+        )
+        .await?;
+        return create_limited_client(config, cluster_id, state);
+    }
+
+    if let Some(client) = state.1.get(cluster_id) {
+        return Ok(client);
+    }
+    let cache = state.1.clone();
+    let cache_key = cluster_id.to_string();
+    let generation = cache.generation();
+
     let manager = state.0.clone();
     let cluster_id = cluster_id.to_string();
 
     // 1. Blocking I/O (DB + File Read)
-    let kubeconfig = tauri::async_runtime::spawn_blocking(move || {
+    let (kubeconfig, accept_invalid_certs) = tauri::async_runtime::spawn_blocking(move || {
         // Get config path
-        let config_path = {
+        let (config_path, accept_invalid_certs) = {
             let manager = manager
                 .lock()
-                .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+                .map_err(|e| AppError::Internal(format!("Failed to acquire lock: {}", e)))?;
             let cluster = manager
-                .get_cluster(&cluster_id)?
-                .ok_or_else(|| format!("Cluster '{}' not found", cluster_id))?;
-            PathBuf::from(&cluster.config_path)
+                .get_cluster(&cluster_id)
+                .map_err(AppError::Internal)?
+                .ok_or_else(|| AppError::NotFound(format!("Cluster '{}' not found", cluster_id)))?;
+            (
+                PathBuf::from(&cluster.config_path),
+                cluster.accept_invalid_certs,
+            )
         };
 
         if !config_path.exists() {
-            return Err(format!("Config file not found: {:?}", config_path));
+            return Err(AppError::Config(format!(
+                "Config file not found: {:?}",
+                config_path
+            )));
         }
 
-        let kubeconfig = Kubeconfig::read_from(&config_path)
-            .map_err(|e| format!("Failed to read kubeconfig {:?}: {}", config_path, e))?;
+        let kubeconfig = Kubeconfig::read_from(&config_path).map_err(|e| {
+            AppError::Config(format!(
+                "Failed to read kubeconfig {:?}: {}",
+                config_path, e
+            ))
+        })?;
 
-        Ok(kubeconfig)
+        Ok((kubeconfig, accept_invalid_certs))
     })
     .await
-    .map_err(|e| e.to_string())??;
+    .map_err(|e| AppError::Internal(e.to_string()))??;
 
     // 2. Async Config Loading
     // The extracted config should have only one context, use current_context
     let context_name = kubeconfig
         .current_context
         .as_ref()
-        .ok_or_else(|| "No current context in kubeconfig".to_string())?;
+        .ok_or_else(|| AppError::Config("No current context in kubeconfig".to_string()))?;
+    check_exec_plugin(&kubeconfig, context_name).map_err(AppError::Config)?;
 
     let options = kube::config::KubeConfigOptions {
         context: Some(context_name.clone()),
         ..Default::default()
     };
 
-    let config = Config::from_custom_kubeconfig(kubeconfig, &options)
+    let mut config = Config::from_custom_kubeconfig(kubeconfig, &options)
         .await
-        .map_err(|e| format!("Failed to load config: {}", e))?;
+        .map_err(|e| AppError::Config(with_tls_hint(format!("Failed to load config: {}", e))))?;
+    // The kubeconfig's own `insecure-skip-tls-verify` is already applied; this is the
+    // per-cluster override on top of it
+    if accept_invalid_certs {
+        config.accept_invalid_certs = true;
+    }
 
-    Client::try_from(config).map_err(|e| format!("Failed to create client: {}", e))
+    let client = create_limited_client(config, &cache_key, state)?;
+    cache.insert(&cache_key, client.clone(), generation);
+    Ok(client)
 }
 
 #[tauri::command]
 pub async fn list_contexts() -> Result<Vec<String>, String> {
-    let mut paths = vec![];
-    if let Ok(p) = std::env::var("KUBECONFIG") {
-        paths.push(PathBuf::from(p));
-    }
-    if let Some(home) = dirs::home_dir() {
-        paths.push(home.join(".kube").join("config"));
-    }
-
-    let app_kube_dir = config::get_kubeconfigs_dir();
-    if app_kube_dir.exists() {
-        if let Ok(entries) = std::fs::read_dir(app_kube_dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_file() {
-                    paths.push(path);
-                }
-            }
-        }
-    }
+    Ok(contexts_in(kubeconfig_search_paths(
+        std::env::var_os("KUBECONFIG").as_deref(),
+    )))
+}
 
+/// Sorted, deduplicated context names across `paths`; unreadable files are skipped
+fn contexts_in(paths: Vec<PathBuf>) -> Vec<String> {
     let mut contexts = Vec::new();
     for path in paths {
         if path.exists() {
@@ -156,17 +428,50 @@ pub async fn list_contexts() -> Result<Vec<String>, String> {
         }
     }
 
-    if contexts.is_empty() {
-        return Ok(vec![]);
-    }
-
     contexts.sort();
     contexts.dedup();
 
-    Ok(contexts)
+    contexts
 }
 
+/// A kubeconfig context that hasn't been imported, addressable by its pseudo cluster id
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ContextCluster {
+    pub id: String,
+    pub context_name: String,
+}
+
+/// Contexts from KUBECONFIG / ~/.kube/config that have no imported cluster, as `ctx:<name>` ids
 #[tauri::command]
+pub async fn list_context_clusters(
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<ContextCluster>, String> {
+    let imported: Vec<String> = {
+        let manager = state
+            .0
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+        manager
+            .list_clusters()?
+            .into_iter()
+            .map(|c| c.context_name)
+            .collect()
+    };
+
+    Ok(contexts_in(user_kubeconfig_paths(
+        std::env::var_os("KUBECONFIG").as_deref(),
+    ))
+    .into_iter()
+    .filter(|name| !imported.contains(name))
+    .map(|context_name| ContextCluster {
+        id: format!("{}{}", CONTEXT_CLUSTER_PREFIX, context_name),
+        context_name,
+    })
+    .collect())
+}
+
+#[tauri::command]
+#[tracing::instrument]
 pub async fn list_namespaces(context_name: String) -> Result<Vec<String>, String> {
     let client = create_client_for_context(&context_name).await?;
     let ns_api: Api<Namespace> = Api::all(client);
@@ -187,6 +492,7 @@ pub async fn list_namespaces(context_name: String) -> Result<Vec<String>, String
 }
 
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_list_namespaces(
     cluster_id: String,
     state: State<'_, ClusterManagerState>,
@@ -208,3 +514,328 @@ pub async fn cluster_list_namespaces(
 
     Ok(namespaces)
 }
+
+/// How long a health check waits for the API server before calling it unreachable
+const HEALTH_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Check that `context_name` in the kubeconfig at `config_path` can authenticate,
+/// by fetching `/version` before the config is saved as a cluster
+pub async fn verify_kubeconfig(config_path: &Path, context_name: &str) -> Result<(), String> {
+    let kubeconfig = Kubeconfig::read_from(config_path)
+        .map_err(|e| format!("Failed to read kubeconfig {:?}: {}", config_path, e))?;
+    check_exec_plugin(&kubeconfig, context_name)?;
+    let options = kube::config::KubeConfigOptions {
+        context: Some(context_name.to_string()),
+        ..Default::default()
+    };
+
+    tokio::time::timeout(HEALTH_CHECK_TIMEOUT, async {
+        let config = Config::from_custom_kubeconfig(kubeconfig, &options)
+            .await
+            .map_err(|e| format!("Failed to load config: {}", e))?;
+        let client =
+            Client::try_from(config).map_err(|e| format!("Failed to create client: {}", e))?;
+        client
+            .apiserver_version()
+            .await
+            .map_err(|e| with_tls_hint(format!("Failed to reach API server: {}", e)))
+    })
+    .await
+    .unwrap_or_else(|_| {
+        Err(format!(
+            "Timed out after {}s waiting for the API server",
+            HEALTH_CHECK_TIMEOUT.as_secs()
+        ))
+    })
+    .map(|_| ())
+}
+
+/// Whether a saved cluster's API server answers, for the status dot in the cluster picker
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ClusterHealth {
+    pub reachable: bool,
+    /// e.g. `v1.31.2`
+    pub server_version: Option<String>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Ask the cluster for its `/version`, giving up after five seconds. Failures are
+/// reported in the result rather than as an error so every cluster gets a status.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_check_health(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ClusterHealth, String> {
+    Ok(check_cluster_health(&cluster_id, &state).await)
+}
+
+pub async fn check_cluster_health(
+    cluster_id: &str,
+    state: &State<'_, ClusterManagerState>,
+) -> ClusterHealth {
+    let started = std::time::Instant::now();
+    // Client creation is inside the timeout: exec auth plugins can hang too
+    let result = tokio::time::timeout(HEALTH_CHECK_TIMEOUT, async {
+        let client = create_client_for_cluster(cluster_id, state).await?;
+        client
+            .apiserver_version()
+            .await
+            .map_err(|e| with_tls_hint(format!("Failed to reach API server: {}", e)))
+    })
+    .await
+    .unwrap_or_else(|_| {
+        Err(format!(
+            "Timed out after {}s waiting for the API server",
+            HEALTH_CHECK_TIMEOUT.as_secs()
+        ))
+    });
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(info) => ClusterHealth {
+            reachable: true,
+            server_version: Some(info.git_version),
+            latency_ms,
+            error: None,
+        },
+        Err(e) => ClusterHealth {
+            reachable: false,
+            server_version: None,
+            latency_ms,
+            error: Some(e),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn exec_kubeconfig(command: &str) -> Kubeconfig {
+        Kubeconfig::from_yaml(&format!(
+            "apiVersion: v1
+kind: Config
+current-context: eks
+clusters:
+- name: c
+  cluster:
+    server: https://eks.example.com
+users:
+- name: u
+  user:
+    exec:
+      apiVersion: client.authentication.k8s.io/v1beta1
+      command: {command}
+      args: [eks, get-token]
+contexts:
+- name: eks
+  context:
+    cluster: c
+    user: u
+"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_with_tls_hint_only_for_certificate_errors() {
+        let message = with_tls_hint(
+            "Failed to reach API server: invalid peer certificate: UnknownIssuer".to_string(),
+        );
+        assert!(message.contains("insecure-skip-tls-verify"));
+
+        let message = with_tls_hint("Failed to reach API server: connection refused".to_string());
+        assert_eq!(message, "Failed to reach API server: connection refused");
+    }
+
+    #[test]
+    fn test_check_exec_plugin_reports_missing_command() {
+        let empty = TempDir::new().unwrap();
+        let err = check_exec_plugin_in(
+            &exec_kubeconfig("aws"),
+            "eks",
+            Some(empty.path().as_os_str()),
+        )
+        .unwrap_err();
+        assert_eq!(err, "Auth plugin 'aws' not found on PATH");
+    }
+
+    #[test]
+    fn test_check_exec_plugin_finds_command_on_path() {
+        let bin = TempDir::new().unwrap();
+        let plugin = if cfg!(windows) { "aws.exe" } else { "aws" };
+        std::fs::write(bin.path().join(plugin), "").unwrap();
+
+        let path = std::env::join_paths([Path::new("/nonexistent"), bin.path()]).unwrap();
+        assert!(check_exec_plugin_in(&exec_kubeconfig("aws"), "eks", Some(&path)).is_ok());
+    }
+
+    #[test]
+    fn test_check_exec_plugin_ignores_other_auth() {
+        let kubeconfig = Kubeconfig::from_yaml(
+            "apiVersion: v1
+kind: Config
+users:
+- name: u
+  user:
+    token: t
+contexts:
+- name: plain
+  context:
+    cluster: c
+    user: u
+",
+        )
+        .unwrap();
+        assert!(check_exec_plugin_in(&kubeconfig, "plain", None).is_ok());
+    }
+
+    #[test]
+    fn test_kubeconfig_env_with_multiple_paths() {
+        let dir = TempDir::new().unwrap();
+        let write_config = |file: &str, context: &str| {
+            let path = dir.path().join(file);
+            std::fs::write(
+                &path,
+                format!(
+                    "apiVersion: v1
+kind: Config
+contexts:
+- name: {}
+  context:
+    cluster: c
+    user: u
+",
+                    context
+                ),
+            )
+            .unwrap();
+            path
+        };
+        let first = write_config("first", "first-ctx");
+        let second = write_config("second", "second-ctx");
+        let missing = dir.path().join("missing");
+
+        let kubeconfig_env = std::env::join_paths([&first, &missing, &second]).unwrap();
+        let paths = kubeconfig_search_paths(Some(kubeconfig_env.as_os_str()));
+
+        assert_eq!(&paths[..2], &[first, second]);
+        let contexts = contexts_in(paths);
+        assert!(contexts.contains(&"first-ctx".to_string()));
+        assert!(contexts.contains(&"second-ctx".to_string()));
+    }
+
+    #[test]
+    fn test_context_from_cluster_id() {
+        assert_eq!(context_from_cluster_id("ctx:kind-dev"), Some("kind-dev"));
+        assert_eq!(context_from_cluster_id("ctx:a:b"), Some("a:b"));
+        assert_eq!(context_from_cluster_id("ctx:"), None);
+        assert_eq!(context_from_cluster_id("3f2a9c1e-uuid"), None);
+        assert_eq!(context_from_cluster_id("kind-dev"), None);
+    }
+
+    #[test]
+    fn test_find_context_in_only_searches_given_paths() {
+        let dir = TempDir::new().unwrap();
+        let config = |context: &str| {
+            format!(
+                "apiVersion: v1
+kind: Config
+clusters:
+- name: c
+  cluster:
+    server: https://127.0.0.1:6443
+users:
+- name: u
+  user: {{}}
+contexts:
+- name: {}
+  context:
+    cluster: c
+    user: u
+",
+                context
+            )
+        };
+        let user = dir.path().join("user");
+        let saved = dir.path().join("saved");
+        std::fs::write(&user, config("dev")).unwrap();
+        std::fs::write(&saved, config("imported")).unwrap();
+
+        assert_eq!(
+            find_context_in(vec![user.clone(), saved.clone()], "imported"),
+            Some(saved)
+        );
+        assert_eq!(find_context_in(vec![user.clone()], "imported"), None);
+        assert_eq!(find_context_in(vec![user.clone()], "dev"), Some(user));
+    }
+
+    #[test]
+    fn test_context_config_errors_keep_their_cause() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config");
+        std::fs::write(
+            &path,
+            serde_yaml::to_string(&exec_kubeconfig("definitely-not-a-real-auth-plugin")).unwrap(),
+        )
+        .unwrap();
+
+        let missing =
+            tauri::async_runtime::block_on(context_config_in(vec![path.clone()], "other"));
+        assert_eq!(missing.unwrap_err().kind(), "NotFound");
+
+        let unusable = tauri::async_runtime::block_on(context_config_in(vec![path], "eks"));
+        assert_eq!(unusable.unwrap_err().kind(), "Config");
+    }
+
+    /// A client that's never used to send requests; building one spawns its buffer
+    /// task, so it needs a runtime
+    fn test_client() -> Client {
+        tauri::async_runtime::block_on(async {
+            Client::try_from(Config::new("http://127.0.0.1:1".parse().unwrap())).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_client_cache_expires_after_ttl() {
+        let cache = ClientCache::default();
+        cache.insert("a", test_client(), cache.generation());
+
+        assert!(cache.get("a").is_some());
+        let later = Instant::now() + CLIENT_CACHE_TTL + Duration::from_secs(1);
+        assert!(cache.get_at("a", later).is_none());
+        // The expired entry is dropped, not just skipped
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn test_client_cache_invalidate() {
+        let cache = ClientCache::default();
+        cache.insert("a", test_client(), cache.generation());
+        cache.insert("b", test_client(), cache.generation());
+
+        cache.invalidate("a");
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+
+        cache.clear();
+        assert!(cache.get("b").is_none());
+    }
+
+    #[test]
+    fn test_client_cache_drops_clients_built_before_invalidate() {
+        let cache = ClientCache::default();
+        // A build reads the generation, then the cluster's config changes before it
+        // finishes
+        let generation = cache.generation();
+        cache.invalidate("a");
+        cache.insert("a", test_client(), generation);
+        assert!(cache.get("a").is_none());
+
+        cache.insert("a", test_client(), cache.generation());
+        assert!(cache.get("a").is_some());
+    }
+}
@@ -1,6 +1,9 @@
 use chrono;
 use k8s_openapi;
 
+/// Field manager recorded on objects the app applies (YAML editor, snapshot restore)
+pub const FIELD_MANAGER: &str = "rustylens";
+
 #[derive(serde::Serialize, Clone, Debug)]
 pub struct WorkloadSummary {
     pub id: String,
@@ -13,6 +16,17 @@ pub struct WorkloadSummary {
     pub created_at: i64,
 }
 
+/// Result of a workload list command. A cluster-wide list the user isn't allowed
+/// to make comes back empty with `partial` set instead of failing the whole view.
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct WorkloadList {
+    pub items: Vec<WorkloadSummary>,
+    pub partial: bool,
+    pub partial_reason: Option<String>,
+    /// Pass back as `continue_token` to fetch the next page; `None` on the last page
+    pub continue_token: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct K8sEventInfo {
     pub event_type: String,
@@ -22,6 +36,32 @@ pub struct K8sEventInfo {
     pub first_timestamp: Option<String>,
     pub last_timestamp: Option<String>,
     pub source: String,
+    /// Namespace of the involved object
+    pub namespace: Option<String>,
+    /// UID of the involved object, for linking to it
+    pub object_uid: Option<String>,
+}
+
+impl From<k8s_openapi::api::core::v1::Event> for K8sEventInfo {
+    fn from(event: k8s_openapi::api::core::v1::Event) -> Self {
+        let source = event
+            .source
+            .as_ref()
+            .and_then(|s| s.component.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        K8sEventInfo {
+            event_type: event.type_.unwrap_or_else(|| "Normal".to_string()),
+            reason: event.reason.unwrap_or_default(),
+            message: event.message.unwrap_or_default(),
+            count: event.count.unwrap_or(1),
+            first_timestamp: event.first_timestamp.as_ref().map(|t| t.0.to_string()),
+            last_timestamp: event.last_timestamp.as_ref().map(|t| t.0.to_string()),
+            source,
+            namespace: event.involved_object.namespace.or(event.metadata.namespace),
+            object_uid: event.involved_object.uid,
+        }
+    }
 }
 
 pub fn calculate_age(
@@ -60,3 +100,241 @@ pub fn get_created_at(
     }
     0
 }
+
+/// Build a label selector string (e.g., "app=nginx,env=prod") from match labels
+pub fn build_label_selector(labels: &std::collections::BTreeMap<String, String>) -> String {
+    labels
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Whether labels satisfy a LabelSelector (matchLabels and matchExpressions are AND'd).
+/// An empty selector matches everything.
+pub fn label_selector_matches(
+    selector: &k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector,
+    labels: &std::collections::BTreeMap<String, String>,
+) -> bool {
+    let labels_match = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(k, v)| labels.get(k) == Some(v));
+
+    let expressions_match = selector.match_expressions.iter().flatten().all(|expr| {
+        let value = labels.get(&expr.key);
+        let values = expr.values.as_deref().unwrap_or_default();
+        match expr.operator.as_str() {
+            "In" => value.is_some_and(|v| values.contains(v)),
+            "NotIn" => value.map(|v| !values.contains(v)).unwrap_or(true),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    });
+
+    labels_match && expressions_match
+}
+
+/// Render a LabelSelector the way kubectl does, e.g. `app=web,tier in (a,b)`
+pub fn format_label_selector(
+    selector: &k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector,
+) -> String {
+    let mut parts: Vec<String> = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+
+    for expr in selector.match_expressions.iter().flatten() {
+        let values = expr.values.as_deref().unwrap_or_default().join(",");
+        parts.push(match expr.operator.as_str() {
+            "In" => format!("{} in ({})", expr.key, values),
+            "NotIn" => format!("{} notin ({})", expr.key, values),
+            "Exists" => expr.key.clone(),
+            "DoesNotExist" => format!("!{}", expr.key),
+            op => format!("{} {} ({})", expr.key, op, values),
+        });
+    }
+
+    if parts.is_empty() {
+        "<all>".to_string()
+    } else {
+        parts.join(",")
+    }
+}
+
+/// Whether a pod reports the `Ready` condition as `True`
+pub fn is_pod_ready(pod: &k8s_openapi::api::core::v1::Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conds| {
+            conds
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True")
+        })
+        .unwrap_or(false)
+}
+
+/// Merge patch for a `scale` subresource, rejecting negative replica counts
+pub fn scale_patch(replicas: i32) -> Result<serde_json::Value, String> {
+    if replicas < 0 {
+        return Err(format!("Replicas must be 0 or more, got {}", replicas));
+    }
+    Ok(serde_json::json!({ "spec": { "replicas": replicas } }))
+}
+
+/// Narrow list params to one page. Without a `limit` everything is listed at once.
+pub fn page_params(
+    lp: kube::api::ListParams,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+) -> kube::api::ListParams {
+    kube::api::ListParams {
+        limit,
+        continue_token: continue_token.filter(|t| !t.is_empty()),
+        ..lp
+    }
+}
+
+/// Trim an optional label or field selector. One that's given must not be blank;
+/// malformed selectors are left for the API server to reject.
+pub fn check_selector(selector: Option<String>, what: &str) -> Result<Option<String>, String> {
+    match selector {
+        Some(selector) if selector.trim().is_empty() => Err(format!("{} cannot be empty", what)),
+        Some(selector) => Ok(Some(selector.trim().to_string())),
+        None => Ok(None),
+    }
+}
+
+/// List params narrowed by an optional label selector, e.g. `app=nginx,env!=dev`
+pub fn label_selector_params(
+    label_selector: Option<String>,
+) -> Result<kube::api::ListParams, String> {
+    let lp = kube::api::ListParams::default();
+    Ok(match check_selector(label_selector, "Label selector")? {
+        Some(selector) => lp.labels(&selector),
+        None => lp,
+    })
+}
+
+/// What happens to an object's dependents when it is deleted: "Foreground",
+/// "Background" (the default) or "Orphan"
+pub fn parse_propagation_policy(
+    propagation: Option<&str>,
+) -> Result<kube::api::PropagationPolicy, String> {
+    use kube::api::PropagationPolicy;
+    match propagation.map(str::to_ascii_lowercase).as_deref() {
+        None | Some("background") => Ok(PropagationPolicy::Background),
+        Some("foreground") => Ok(PropagationPolicy::Foreground),
+        Some("orphan") => Ok(PropagationPolicy::Orphan),
+        Some(_) => Err(format!(
+            "Unknown propagation policy '{}': expected Foreground, Background or Orphan",
+            propagation.unwrap_or_default()
+        )),
+    }
+}
+
+/// The token for the page after a list response, if there is one
+pub fn next_continue_token(
+    metadata: &k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta,
+) -> Option<String> {
+    metadata.continue_.clone().filter(|t| !t.is_empty())
+}
+
+/// Whether a kube error is an API 403 (RBAC denied the request)
+pub fn is_forbidden(err: &kube::Error) -> bool {
+    matches!(err, kube::Error::Api(resp) if resp.code == 403)
+}
+
+/// Whether a kube error is an API 404 (missing object or an API group the server doesn't serve)
+pub fn is_not_found(err: &kube::Error) -> bool {
+    matches!(err, kube::Error::Api(resp) if resp.code == 404)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ListMeta;
+
+    #[test]
+    fn test_page_params() {
+        let lp = page_params(
+            kube::api::ListParams::default().labels("app=web"),
+            Some(50),
+            Some("abc".to_string()),
+        );
+        assert_eq!(lp.limit, Some(50));
+        assert_eq!(lp.continue_token.as_deref(), Some("abc"));
+        assert_eq!(lp.label_selector.as_deref(), Some("app=web"));
+
+        let unpaged = page_params(kube::api::ListParams::default(), None, Some(String::new()));
+        assert_eq!(unpaged.limit, None);
+        assert_eq!(unpaged.continue_token, None);
+    }
+
+    #[test]
+    fn test_label_selector_params() {
+        let lp = label_selector_params(Some(" app=nginx,env!=dev ".to_string())).unwrap();
+        assert_eq!(lp.label_selector.as_deref(), Some("app=nginx,env!=dev"));
+
+        assert_eq!(label_selector_params(None).unwrap().label_selector, None);
+        assert!(label_selector_params(Some("  ".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_parse_propagation_policy() {
+        use kube::api::PropagationPolicy;
+        assert!(matches!(
+            parse_propagation_policy(None),
+            Ok(PropagationPolicy::Background)
+        ));
+        assert!(matches!(
+            parse_propagation_policy(Some("Foreground")),
+            Ok(PropagationPolicy::Foreground)
+        ));
+        assert!(matches!(
+            parse_propagation_policy(Some("orphan")),
+            Ok(PropagationPolicy::Orphan)
+        ));
+        assert!(parse_propagation_policy(Some("cascade")).is_err());
+    }
+
+    #[test]
+    fn test_pages_follow_continue_token_without_duplicates() {
+        // Stand-in for the API server: a token is the offset of the next page
+        let names: Vec<String> = (0..5).map(|i| format!("pod-{}", i)).collect();
+        let list = |lp: &kube::api::ListParams| {
+            let start: usize = lp
+                .continue_token
+                .as_deref()
+                .map_or(0, |t| t.parse().unwrap());
+            let end = (start + lp.limit.unwrap() as usize).min(names.len());
+            let metadata = ListMeta {
+                continue_: (end < names.len()).then(|| end.to_string()),
+                ..Default::default()
+            };
+            (names[start..end].to_vec(), metadata)
+        };
+
+        let mut seen = Vec::new();
+        let mut token = None;
+        loop {
+            let (items, metadata) = list(&page_params(
+                kube::api::ListParams::default(),
+                Some(2),
+                token,
+            ));
+            seen.extend(items);
+            token = next_continue_token(&metadata);
+            if token.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(seen, names);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::WorkloadSummary;
+use crate::k8s::workload::list_workload_summaries;
+use std::collections::BTreeMap;
+use tauri::State;
+
+/// A resource present in both clusters whose images or status differ
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct ResourceDrift {
+    pub name: String,
+    pub images_a: Vec<String>,
+    pub images_b: Vec<String>,
+    pub status_a: String,
+    pub status_b: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Default)]
+pub struct ClusterComparison {
+    pub only_in_a: Vec<String>,
+    pub only_in_b: Vec<String>,
+    pub in_both: Vec<String>,
+    pub drift: Vec<ResourceDrift>,
+}
+
+/// Key by name within a namespace, or by `namespace/name` when comparing all namespaces
+fn key_summaries(
+    items: Vec<WorkloadSummary>,
+    all_namespaces: bool,
+) -> BTreeMap<String, WorkloadSummary> {
+    items
+        .into_iter()
+        .map(|item| {
+            let key = if all_namespaces {
+                format!("{}/{}", item.namespace, item.name)
+            } else {
+                item.name.clone()
+            };
+            (key, item)
+        })
+        .collect()
+}
+
+fn compare_summaries(
+    a: BTreeMap<String, WorkloadSummary>,
+    b: BTreeMap<String, WorkloadSummary>,
+) -> ClusterComparison {
+    let mut comparison = ClusterComparison::default();
+
+    for (key, item_a) in &a {
+        match b.get(key) {
+            Some(item_b) => {
+                comparison.in_both.push(key.clone());
+                // Replica counts surface through the summary status ("ready/desired")
+                if item_a.images != item_b.images || item_a.status != item_b.status {
+                    comparison.drift.push(ResourceDrift {
+                        name: key.clone(),
+                        images_a: item_a.images.clone(),
+                        images_b: item_b.images.clone(),
+                        status_a: item_a.status.clone(),
+                        status_b: item_b.status.clone(),
+                    });
+                }
+            }
+            None => comparison.only_in_a.push(key.clone()),
+        }
+    }
+
+    comparison.only_in_b = b.keys().filter(|k| !a.contains_key(*k)).cloned().collect();
+    comparison
+}
+
+/// Compare the inventory of one kind between two clusters
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn compare_clusters(
+    cluster_id_a: String,
+    cluster_id_b: String,
+    namespace: Option<String>,
+    kind: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ClusterComparison, String> {
+    let client_a = create_client_for_cluster(&cluster_id_a, &state).await?;
+    let client_b = create_client_for_cluster(&cluster_id_b, &state).await?;
+
+    let (list_a, list_b) = futures::join!(
+        list_workload_summaries(client_a, &kind, namespace.as_deref()),
+        list_workload_summaries(client_b, &kind, namespace.as_deref())
+    );
+    let list_a = list_a.map_err(|e| format!("Failed to list {} in cluster A: {}", kind, e))?;
+    let list_b = list_b.map_err(|e| format!("Failed to list {} in cluster B: {}", kind, e))?;
+
+    let all_namespaces = namespace.is_none();
+    Ok(compare_summaries(
+        key_summaries(list_a, all_namespaces),
+        key_summaries(list_b, all_namespaces),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(namespace: &str, name: &str, image: &str, status: &str) -> WorkloadSummary {
+        WorkloadSummary {
+            id: String::new(),
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            age: String::new(),
+            labels: Default::default(),
+            status: status.to_string(),
+            images: vec![image.to_string()],
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_compare_partitions_names() {
+        let a = key_summaries(
+            vec![
+                summary("default", "api", "api:1", "2/2"),
+                summary("default", "worker", "worker:1", "1/1"),
+            ],
+            false,
+        );
+        let b = key_summaries(
+            vec![
+                summary("default", "api", "api:1", "2/2"),
+                summary("default", "web", "web:1", "1/1"),
+            ],
+            false,
+        );
+
+        let result = compare_summaries(a, b);
+
+        assert_eq!(result.only_in_a, vec!["worker"]);
+        assert_eq!(result.only_in_b, vec!["web"]);
+        assert_eq!(result.in_both, vec!["api"]);
+        assert!(result.drift.is_empty());
+    }
+
+    #[test]
+    fn test_compare_reports_image_and_replica_drift() {
+        let a = key_summaries(vec![summary("default", "api", "api:1", "2/2")], false);
+        let b = key_summaries(vec![summary("default", "api", "api:2", "3/3")], false);
+
+        let result = compare_summaries(a, b);
+
+        assert_eq!(result.drift.len(), 1);
+        assert_eq!(result.drift[0].images_b, vec!["api:2"]);
+        assert_eq!(result.drift[0].status_a, "2/2");
+    }
+
+    #[test]
+    fn test_compare_all_namespaces_keys_by_namespace() {
+        let a = key_summaries(vec![summary("team-a", "api", "api:1", "1/1")], true);
+        let b = key_summaries(vec![summary("team-b", "api", "api:1", "1/1")], true);
+
+        let result = compare_summaries(a, b);
+
+        assert_eq!(result.only_in_a, vec!["team-a/api"]);
+        assert_eq!(result.only_in_b, vec!["team-b/api"]);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use k8s_openapi::api::core::v1::{Container, Pod};
+use kube::api::{Api, ListParams};
+use std::collections::{BTreeSet, HashMap};
+use tauri::State;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConfigKind {
+    ConfigMap,
+    Secret,
+}
+
+/// A pod that reads the ConfigMap/Secret, and how
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ConfigConsumer {
+    pub pod_name: String,
+    /// Top-level owner as `Kind/name`, e.g. `Deployment/web` rather than its ReplicaSet
+    pub workload: Option<String>,
+    /// e.g. `volume config`, `env DB_URL (container app)`, `envFrom (container app)`
+    pub references: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ConfigConsumers {
+    pub pods: Vec<ConfigConsumer>,
+    /// Distinct workloads owning the consuming pods
+    pub workloads: Vec<String>,
+}
+
+fn container_references(container: &Container, kind: ConfigKind, name: &str) -> Vec<String> {
+    let mut references = Vec::new();
+
+    for env in container.env.iter().flatten() {
+        let Some(source) = env.value_from.as_ref() else {
+            continue;
+        };
+        let matches = match kind {
+            ConfigKind::ConfigMap => source
+                .config_map_key_ref
+                .as_ref()
+                .is_some_and(|r| r.name == name),
+            ConfigKind::Secret => source
+                .secret_key_ref
+                .as_ref()
+                .is_some_and(|r| r.name == name),
+        };
+        if matches {
+            references.push(format!("env {} (container {})", env.name, container.name));
+        }
+    }
+
+    for env_from in container.env_from.iter().flatten() {
+        let matches = match kind {
+            ConfigKind::ConfigMap => env_from
+                .config_map_ref
+                .as_ref()
+                .is_some_and(|r| r.name == name),
+            ConfigKind::Secret => env_from.secret_ref.as_ref().is_some_and(|r| r.name == name),
+        };
+        if matches {
+            references.push(format!("envFrom (container {})", container.name));
+        }
+    }
+
+    references
+}
+
+/// Every place a pod spec refers to the ConfigMap/Secret: volumes (including projected
+/// ones), env and envFrom of all containers, and image pull secrets
+fn config_references(pod: &Pod, kind: ConfigKind, name: &str) -> Vec<String> {
+    let Some(spec) = pod.spec.as_ref() else {
+        return vec![];
+    };
+    let mut references = Vec::new();
+
+    for volume in spec.volumes.iter().flatten() {
+        let direct = match kind {
+            ConfigKind::ConfigMap => volume.config_map.as_ref().is_some_and(|c| c.name == name),
+            ConfigKind::Secret => volume
+                .secret
+                .as_ref()
+                .is_some_and(|s| s.secret_name.as_deref() == Some(name)),
+        };
+        let projected = volume
+            .projected
+            .as_ref()
+            .and_then(|p| p.sources.as_ref())
+            .into_iter()
+            .flatten()
+            .any(|source| match kind {
+                ConfigKind::ConfigMap => source.config_map.as_ref().is_some_and(|c| c.name == name),
+                ConfigKind::Secret => source.secret.as_ref().is_some_and(|s| s.name == name),
+            });
+        if direct || projected {
+            references.push(format!("volume {}", volume.name));
+        }
+    }
+
+    for container in spec
+        .init_containers
+        .iter()
+        .flatten()
+        .chain(spec.containers.iter())
+    {
+        references.extend(container_references(container, kind, name));
+    }
+
+    if kind == ConfigKind::Secret
+        && spec
+            .image_pull_secrets
+            .iter()
+            .flatten()
+            .any(|s| s.name == name)
+    {
+        references.push("imagePullSecrets".to_string());
+    }
+
+    references
+}
+
+/// Pods in the namespace that mount or read a ConfigMap or Secret (`kind` is
+/// "ConfigMap" or "Secret"), with the workloads they belong to
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_find_config_consumers(
+    cluster_id: String,
+    namespace: String,
+    kind: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ConfigConsumers, String> {
+    let config_kind = match kind.to_lowercase().as_str() {
+        "configmap" => ConfigKind::ConfigMap,
+        "secret" => ConfigKind::Secret,
+        _ => return Err(format!("Unsupported kind: {}", kind)),
+    };
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let list = pods
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    let replica_sets: Api<ReplicaSet> = Api::namespaced(client, &namespace);
+    // ReplicaSet name -> its Deployment, looked up once per ReplicaSet
+    let mut rs_owners: HashMap<String, Option<String>> = HashMap::new();
+
+    let mut consumers = Vec::new();
+    for pod in &list.items {
+        let references = config_references(pod, config_kind, &name);
+        if references.is_empty() {
+            continue;
+        }
+
+        let owner = pod
+            .metadata
+            .owner_references
+            .as_ref()
+            .and_then(|refs| refs.iter().find(|r| r.controller == Some(true)));
+        let workload = match owner {
+            Some(owner) if owner.kind == "ReplicaSet" => {
+                if !rs_owners.contains_key(&owner.name) {
+                    let deployment = replica_sets
+                        .get_opt(&owner.name)
+                        .await
+                        .ok()
+                        .flatten()
+                        .and_then(|rs| rs.metadata.owner_references)
+                        .and_then(|refs| refs.into_iter().find(|r| r.controller == Some(true)))
+                        .map(|r| format!("{}/{}", r.kind, r.name));
+                    rs_owners.insert(owner.name.clone(), deployment);
+                }
+                rs_owners[&owner.name]
+                    .clone()
+                    .or_else(|| Some(format!("ReplicaSet/{}", owner.name)))
+            }
+            Some(owner) => Some(format!("{}/{}", owner.kind, owner.name)),
+            None => None,
+        };
+
+        consumers.push(ConfigConsumer {
+            pod_name: pod.metadata.name.clone().unwrap_or_default(),
+            workload,
+            references,
+        });
+    }
+
+    let workloads: BTreeSet<String> = consumers
+        .iter()
+        .filter_map(|c| c.workload.clone())
+        .collect();
+
+    Ok(ConfigConsumers {
+        pods: consumers,
+        workloads: workloads.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POD: &str = r#"
+metadata:
+  name: web-1
+spec:
+  imagePullSecrets:
+  - name: registry
+  volumes:
+  - name: config
+    configMap:
+      name: app-config
+  - name: bundle
+    projected:
+      sources:
+      - secret:
+          name: tls
+  initContainers:
+  - name: migrate
+    envFrom:
+    - configMapRef:
+        name: app-config
+  containers:
+  - name: app
+    env:
+    - name: DB_PASSWORD
+      valueFrom:
+        secretKeyRef:
+          name: db
+          key: password
+    - name: MODE
+      valueFrom:
+        configMapKeyRef:
+          name: app-config
+          key: mode
+"#;
+
+    #[test]
+    fn test_config_references_configmap() {
+        let pod: Pod = serde_yaml::from_str(POD).unwrap();
+
+        assert_eq!(
+            config_references(&pod, ConfigKind::ConfigMap, "app-config"),
+            vec![
+                "volume config".to_string(),
+                "envFrom (container migrate)".to_string(),
+                "env MODE (container app)".to_string(),
+            ]
+        );
+        assert!(config_references(&pod, ConfigKind::ConfigMap, "db").is_empty());
+    }
+
+    #[test]
+    fn test_config_references_secret() {
+        let pod: Pod = serde_yaml::from_str(POD).unwrap();
+
+        assert_eq!(
+            config_references(&pod, ConfigKind::Secret, "tls"),
+            vec!["volume bundle".to_string()]
+        );
+        assert_eq!(
+            config_references(&pod, ConfigKind::Secret, "db"),
+            vec!["env DB_PASSWORD (container app)".to_string()]
+        );
+        assert_eq!(
+            config_references(&pod, ConfigKind::Secret, "registry"),
+            vec!["imagePullSecrets".to_string()]
+        );
+    }
+}
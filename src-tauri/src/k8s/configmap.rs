@@ -0,0 +1,159 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::api::Api;
+use tauri::State;
+
+/// Characters of each value returned when the caller doesn't pick a limit
+const DEFAULT_MAX_VALUE_LENGTH: usize = 16 * 1024;
+
+/// One `data` entry, cut short when it's too large to ship to the UI
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ConfigMapValue {
+    pub key: String,
+    pub value: String,
+    pub truncated: bool,
+    /// Length of the full value in bytes
+    pub size: usize,
+}
+
+/// A `binaryData` entry; only its size is returned
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ConfigMapBinaryKey {
+    pub key: String,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConfigMapDetails {
+    pub name: String,
+    pub namespace: String,
+    pub immutable: bool,
+    pub data: Vec<ConfigMapValue>,
+    pub binary_data: Vec<ConfigMapBinaryKey>,
+}
+
+fn truncate_value(key: String, value: String, max_length: usize) -> ConfigMapValue {
+    let size = value.len();
+    match value.char_indices().nth(max_length) {
+        Some((cut, _)) => ConfigMapValue {
+            key,
+            value: value[..cut].to_string(),
+            truncated: true,
+            size,
+        },
+        None => ConfigMapValue {
+            key,
+            value,
+            truncated: false,
+            size,
+        },
+    }
+}
+
+fn map_configmap_details(configmap: ConfigMap, max_value_length: usize) -> ConfigMapDetails {
+    ConfigMapDetails {
+        name: configmap.metadata.name.unwrap_or_default(),
+        namespace: configmap.metadata.namespace.unwrap_or_default(),
+        immutable: configmap.immutable.unwrap_or(false),
+        data: configmap
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| truncate_value(key, value, max_value_length))
+            .collect(),
+        binary_data: configmap
+            .binary_data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| ConfigMapBinaryKey {
+                key,
+                size: value.0.len(),
+            })
+            .collect(),
+    }
+}
+
+/// Every key of a ConfigMap with its value. Values longer than `max_value_length`
+/// characters (16Ki by default) are cut off and flagged as truncated.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_configmap_details(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    max_value_length: Option<usize>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ConfigMapDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let configmaps: Api<ConfigMap> = Api::namespaced(client, &namespace);
+
+    let configmap = configmaps
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get configmap '{}': {}", name, e))?;
+
+    Ok(map_configmap_details(
+        configmap,
+        max_value_length.unwrap_or(DEFAULT_MAX_VALUE_LENGTH),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_configmap_details_truncates_large_values() {
+        let configmap: ConfigMap = serde_yaml::from_str(
+            r#"
+metadata:
+  name: app-config
+  namespace: shop
+data:
+  mode: production
+  banner: "héllo world"
+binaryData:
+  logo.png: AAEC
+"#,
+        )
+        .unwrap();
+
+        let details = map_configmap_details(configmap, 5);
+
+        assert_eq!(details.name, "app-config");
+        assert!(!details.immutable);
+        assert_eq!(
+            details.data,
+            vec![
+                ConfigMapValue {
+                    key: "banner".to_string(),
+                    value: "héllo".to_string(),
+                    truncated: true,
+                    size: 12,
+                },
+                ConfigMapValue {
+                    key: "mode".to_string(),
+                    value: "produ".to_string(),
+                    truncated: true,
+                    size: 10,
+                },
+            ]
+        );
+        assert_eq!(
+            details.binary_data,
+            vec![ConfigMapBinaryKey {
+                key: "logo.png".to_string(),
+                size: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_truncate_value_keeps_short_values() {
+        let value = truncate_value("mode".to_string(), "debug".to_string(), 5);
+
+        assert!(!value.truncated);
+        assert_eq!(value.value, "debug");
+    }
+}
@@ -0,0 +1,236 @@
+use chrono::{DateTime, Datelike, Duration, DurationRound, NaiveDate, Timelike, Utc};
+
+/// Search steps before giving up on finding a next run. Each step skips at least a
+/// minute and usually a whole hour, day or month, so this covers centuries.
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+/// A parsed five-field cron schedule, as accepted by CronJob `spec.schedule`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CronSchedule {
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days_of_month: Vec<bool>,
+    months: Vec<bool>,
+    days_of_week: Vec<bool>,
+    /// Whether the day-of-month / day-of-week field was `*`; when both are
+    /// restricted a day matching either one runs
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+fn parse_value(value: &str, min: u32, names: &[&str]) -> Result<u32, String> {
+    if let Some(index) = names.iter().position(|n| n.eq_ignore_ascii_case(value)) {
+        return Ok(index as u32 + min);
+    }
+    value
+        .parse()
+        .map_err(|_| format!("Invalid cron value '{}'", value))
+}
+
+/// Parse one field into a match table indexed by value. `names` are the aliases
+/// for `min..` (month and weekday names).
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<Vec<bool>, String> {
+    let mut matches = vec![false; max as usize + 1];
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("Invalid cron step '{}'", step))?;
+                if step == 0 {
+                    return Err("Cron step must be greater than zero".to_string());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" || range == "?" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, names)?,
+                parse_value(end, min, names)?,
+            )
+        } else {
+            let start = parse_value(range, min, names)?;
+            // `5/15` means every 15 starting at 5
+            (start, if part.contains('/') { max } else { start })
+        };
+
+        if start < min || end > max || start > end {
+            return Err(format!("Cron field '{}' is outside {}-{}", part, min, max));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            matches[value as usize] = true;
+        }
+    }
+
+    Ok(matches)
+}
+
+impl CronSchedule {
+    pub(crate) fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(format!(
+                "Expected 5 cron fields, found {} in '{}'",
+                fields.len(),
+                expression
+            ));
+        };
+
+        let mut days_of_week = parse_field(day_of_week, 0, 7, &DAY_NAMES)?;
+        // Both 0 and 7 are Sunday
+        if days_of_week[7] {
+            days_of_week[0] = true;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, &[])?,
+            hours: parse_field(hour, 0, 23, &[])?,
+            days_of_month: parse_field(day_of_month, 1, 31, &[])?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES)?,
+            days_of_week,
+            any_day_of_month: day_of_month == "*" || day_of_month == "?",
+            any_day_of_week: day_of_week == "*" || day_of_week == "?",
+        })
+    }
+
+    fn day_matches(&self, time: &DateTime<Utc>) -> bool {
+        let dom = self.days_of_month[time.day() as usize];
+        let dow = self.days_of_week[time.weekday().num_days_from_sunday() as usize];
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        }
+    }
+
+    /// The first scheduled time strictly after `after`, or `None` if the schedule
+    /// never fires (e.g. February 30th)
+    pub(crate) fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut time = after.duration_trunc(Duration::minutes(1)).ok()? + Duration::minutes(1);
+
+        for _ in 0..MAX_SEARCH_STEPS {
+            if !self.months[time.month() as usize] {
+                // Jump to midnight on the first of next month
+                let (year, month) = if time.month() == 12 {
+                    (time.year() + 1, 1)
+                } else {
+                    (time.year(), time.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc();
+            } else if !self.day_matches(&time) {
+                time = (time + Duration::days(1)).with_hour(0)?.with_minute(0)?;
+            } else if !self.hours[time.hour() as usize] {
+                time = (time + Duration::hours(1)).with_minute(0)?;
+            } else if !self.minutes[time.minute() as usize] {
+                time += Duration::minutes(1);
+            } else {
+                return Some(time);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn next(expression: &str, after: &str) -> Option<DateTime<Utc>> {
+        CronSchedule::parse(expression)
+            .unwrap()
+            .next_after(at(after))
+    }
+
+    #[test]
+    fn test_next_after_steps_and_ranges() {
+        assert_eq!(
+            next("*/15 * * * *", "2026-10-16T10:07:30Z"),
+            Some(at("2026-10-16T10:15:00Z"))
+        );
+        // Strictly after: a run exactly at `after` is not returned again
+        assert_eq!(
+            next("*/15 * * * *", "2026-10-16T10:15:00Z"),
+            Some(at("2026-10-16T10:30:00Z"))
+        );
+        assert_eq!(
+            next("30 9-17/4 * * *", "2026-10-16T17:31:00Z"),
+            Some(at("2026-10-17T09:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_next_after_names_and_macros() {
+        // 2026-10-16 is a Friday
+        assert_eq!(
+            next("0 8 * * MON-FRI", "2026-10-16T09:00:00Z"),
+            Some(at("2026-10-19T08:00:00Z"))
+        );
+        assert_eq!(
+            next("0 0 1 JAN *", "2026-10-16T00:00:00Z"),
+            Some(at("2027-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            next("@weekly", "2026-10-16T00:00:00Z"),
+            Some(at("2026-10-18T00:00:00Z"))
+        );
+        assert_eq!(
+            next("0 12 * * 7", "2026-10-16T00:00:00Z"),
+            Some(at("2026-10-18T12:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_restricted_day_fields_match_either() {
+        // The 20th, or any Monday
+        assert_eq!(
+            next("0 0 20 * 1", "2026-10-16T00:00:00Z"),
+            Some(at("2026-10-19T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_next_after_rare_and_impossible_dates() {
+        assert_eq!(
+            next("0 0 29 2 *", "2026-10-16T00:00:00Z"),
+            Some(at("2028-02-29T00:00:00Z"))
+        );
+        assert_eq!(next("0 0 30 2 *", "2026-10-16T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_expressions() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * FOO *").is_err());
+    }
+}
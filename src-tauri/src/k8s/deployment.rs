@@ -1,9 +1,9 @@
 use crate::cluster_manager::ClusterManagerState;
 use crate::k8s::client::create_client_for_cluster;
-use crate::k8s::common::{calculate_age, K8sEventInfo};
+use crate::k8s::common::{calculate_age, scale_patch, K8sEventInfo};
 use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
-use k8s_openapi::api::core::v1::{Event, Pod};
-use kube::api::{Api, ListParams};
+use k8s_openapi::api::core::v1::{Event, Pod, PodTemplateSpec};
+use kube::api::{Api, ListParams, Patch, PatchParams};
 use std::collections::HashMap;
 use tauri::State;
 
@@ -39,6 +39,7 @@ pub struct DeploymentCondition {
 
 /// Get detailed information about a specific deployment
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_deployment_details(
     cluster_id: String,
     namespace: String,
@@ -198,6 +199,7 @@ pub fn map_pod_to_deployment_pod_info(pod: &Pod) -> DeploymentPodInfo {
 
 /// Get all pods matching a deployment's selector labels
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_deployment_pods(
     cluster_id: String,
     namespace: String,
@@ -324,6 +326,7 @@ fn map_replicaset_to_info(rs: &ReplicaSet) -> ReplicaSetInfo {
 
 /// Fetches ReplicaSets (revision history) for a specific deployment
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_deployment_replicasets(
     cluster_id: String,
     namespace: String,
@@ -355,16 +358,7 @@ pub async fn cluster_get_deployment_replicasets(
     let mut rs_infos: Vec<ReplicaSetInfo> = rs_list
         .items
         .iter()
-        .filter(|rs| {
-            rs.metadata
-                .owner_references
-                .as_ref()
-                .map(|refs| {
-                    refs.iter()
-                        .any(|owner| owner.kind == "Deployment" && owner.uid == deployment_uid)
-                })
-                .unwrap_or(false)
-        })
+        .filter(|rs| is_owned_by(rs, &deployment_uid))
         .map(map_replicaset_to_info)
         .collect();
 
@@ -398,23 +392,7 @@ pub fn filter_deployment_events(
 
             name_matches && kind_matches && uid_matches
         })
-        .map(|event| {
-            let source = event
-                .source
-                .as_ref()
-                .and_then(|s| s.component.clone())
-                .unwrap_or_else(|| "unknown".to_string());
-
-            K8sEventInfo {
-                event_type: event.type_.unwrap_or_else(|| "Normal".to_string()),
-                reason: event.reason.unwrap_or_default(),
-                message: event.message.unwrap_or_default(),
-                count: event.count.unwrap_or(1),
-                first_timestamp: event.first_timestamp.as_ref().map(|t| t.0.to_string()),
-                last_timestamp: event.last_timestamp.as_ref().map(|t| t.0.to_string()),
-                source,
-            }
-        })
+        .map(K8sEventInfo::from)
         .collect();
 
     // Sort by last_timestamp descending (most recent first)
@@ -425,6 +403,7 @@ pub fn filter_deployment_events(
 
 /// Fetches events related to a specific deployment
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_deployment_events(
     cluster_id: String,
     namespace: String,
@@ -457,6 +436,121 @@ pub async fn cluster_get_deployment_events(
     Ok(event_infos)
 }
 
+/// Set the replica count through the `scale` subresource and return the new desired count
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_scale_deployment(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    replicas: i32,
+    state: State<'_, ClusterManagerState>,
+) -> Result<i32, String> {
+    let patch = scale_patch(replicas)?;
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let deployments: Api<Deployment> = Api::namespaced(client, &namespace);
+
+    let scale = deployments
+        .patch_scale(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| format!("Failed to scale deployment '{}': {}", name, e))?;
+
+    // The API omits `replicas` when it is 0
+    Ok(scale.spec.and_then(|s| s.replicas).unwrap_or(0))
+}
+
+// --- Deployment rollback ---
+
+fn is_owned_by(rs: &ReplicaSet, deployment_uid: &str) -> bool {
+    rs.metadata
+        .owner_references
+        .as_ref()
+        .map(|refs| {
+            refs.iter()
+                .any(|owner| owner.kind == "Deployment" && owner.uid == deployment_uid)
+        })
+        .unwrap_or(false)
+}
+
+/// The pod template of the deployment's ReplicaSet at `revision`, without the
+/// `pod-template-hash` label the controller adds to each ReplicaSet's copy
+fn revision_template(
+    replicasets: &[ReplicaSet],
+    deployment_uid: &str,
+    revision: &str,
+) -> Option<PodTemplateSpec> {
+    let rs = replicasets
+        .iter()
+        .find(|rs| is_owned_by(rs, deployment_uid) && extract_revision(rs) == revision)?;
+    let mut template = rs.spec.as_ref()?.template.clone()?;
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove("pod-template-hash");
+    }
+    Some(template)
+}
+
+/// Roll a deployment back to the pod template of an earlier revision, like
+/// `kubectl rollout undo --to-revision`. Returns the deployment's new generation,
+/// which `status.observedGeneration` reaches once the controller picks up the change.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_rollback_deployment(
+    cluster_id: String,
+    namespace: String,
+    deployment_name: String,
+    revision: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<i64, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let deployments_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    let deployment = deployments_api
+        .get(&deployment_name)
+        .await
+        .map_err(|e| format!("Failed to get deployment '{}': {}", deployment_name, e))?;
+    let deployment_uid = deployment
+        .metadata
+        .uid
+        .ok_or_else(|| "Deployment has no UID".to_string())?;
+
+    let replicasets_api: Api<ReplicaSet> = Api::namespaced(client, &namespace);
+    let rs_list = replicasets_api
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list replicasets: {}", e))?;
+
+    let template =
+        revision_template(&rs_list.items, &deployment_uid, &revision).ok_or_else(|| {
+            format!(
+                "No ReplicaSet of deployment '{}' has revision {}",
+                deployment_name, revision
+            )
+        })?;
+
+    // `$patch: replace` swaps the whole template instead of merging labels and
+    // annotations from the current one into it
+    let mut template_value = serde_json::to_value(&template)
+        .map_err(|e| format!("Failed to serialize pod template: {}", e))?;
+    template_value["$patch"] = serde_json::json!("replace");
+    let patch = serde_json::json!({ "spec": { "template": template_value } });
+
+    let updated = deployments_api
+        .patch(
+            &deployment_name,
+            &PatchParams::default(),
+            &Patch::Strategic(&patch),
+        )
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to roll back deployment '{}': {}",
+                deployment_name, e
+            )
+        })?;
+
+    Ok(updated.metadata.generation.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -511,6 +605,8 @@ mod tests {
             first_timestamp: Some("2024-01-01T00:00:00Z".to_string()),
             last_timestamp: Some("2024-01-01T01:00:00Z".to_string()),
             source: "deployment-controller".to_string(),
+            namespace: Some("default".to_string()),
+            object_uid: None,
         };
 
         assert_eq!(event_info.event_type, "Warning");
@@ -636,6 +732,7 @@ mod tests {
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].message, "Scaled up");
+        assert_eq!(result[0].object_uid.as_deref(), Some("uid-123"));
     }
 
     #[test]
@@ -764,6 +861,8 @@ mod tests {
             first_timestamp: Some("2024-01-01T00:00:00Z".to_string()),
             last_timestamp: Some("2024-01-01T01:00:00Z".to_string()),
             source: "deployment-controller".to_string(),
+            namespace: Some("default".to_string()),
+            object_uid: None,
         };
 
         let json = serde_json::to_string(&event_info).expect("Serialization should work");
@@ -1444,4 +1543,80 @@ mod tests {
         assert!(!pod_info.node.is_empty());
         assert!(!pod_info.pod_ip.is_empty());
     }
+
+    // --- scale tests ---
+
+    #[test]
+    fn test_scale_patch_allows_zero() {
+        assert_eq!(
+            scale_patch(0).unwrap(),
+            serde_json::json!({ "spec": { "replicas": 0 } })
+        );
+        assert_eq!(scale_patch(3).unwrap()["spec"]["replicas"], 3);
+    }
+
+    #[test]
+    fn test_scale_patch_rejects_negative() {
+        let err = scale_patch(-1).unwrap_err();
+        assert!(err.contains("0 or more"));
+    }
+
+    // --- rollback tests ---
+
+    fn replicaset(name: &str, revision: &str, owner_uid: &str) -> ReplicaSet {
+        serde_yaml::from_str(&format!(
+            r#"
+metadata:
+  name: {name}
+  annotations:
+    deployment.kubernetes.io/revision: "{revision}"
+  ownerReferences:
+  - apiVersion: apps/v1
+    kind: Deployment
+    name: web
+    uid: {owner_uid}
+spec:
+  selector:
+    matchLabels:
+      app: web
+  template:
+    metadata:
+      labels:
+        app: web
+        pod-template-hash: abc123
+    spec:
+      containers:
+      - name: app
+        image: web:{revision}
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_revision_template_strips_pod_template_hash() {
+        let replicasets = vec![
+            replicaset("web-1", "1", "uid-1"),
+            replicaset("web-2", "2", "uid-1"),
+            replicaset("other-1", "1", "uid-2"),
+        ];
+
+        let template = revision_template(&replicasets, "uid-1", "1").unwrap();
+
+        let labels = template.metadata.unwrap().labels.unwrap();
+        assert_eq!(labels.get("app").map(String::as_str), Some("web"));
+        assert!(!labels.contains_key("pod-template-hash"));
+        assert_eq!(
+            template.spec.unwrap().containers[0].image.as_deref(),
+            Some("web:1")
+        );
+    }
+
+    #[test]
+    fn test_revision_template_requires_matching_revision_and_owner() {
+        let replicasets = vec![replicaset("other-3", "3", "uid-2")];
+
+        assert!(revision_template(&replicasets, "uid-1", "3").is_none());
+        assert!(revision_template(&replicasets, "uid-2", "4").is_none());
+    }
 }
@@ -0,0 +1,380 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use kube::api::{Api, ApiResource, DynamicObject, GroupVersionKind, ListParams};
+use std::collections::HashSet;
+use tauri::State;
+
+/// A deprecated group/version for a kind and what replaces it
+struct DeprecatedApi {
+    kind: &'static str,
+    deprecated_version: &'static str,
+    replacement: &'static str,
+    plural: &'static str,
+    /// Kubernetes minor version (1.x) that stops serving the deprecated version
+    removed_in_minor: u32,
+}
+
+const fn deprecated(
+    kind: &'static str,
+    deprecated_version: &'static str,
+    replacement: &'static str,
+    plural: &'static str,
+    removed_in_minor: u32,
+) -> DeprecatedApi {
+    DeprecatedApi {
+        kind,
+        deprecated_version,
+        replacement,
+        plural,
+        removed_in_minor,
+    }
+}
+
+/// Removals from the upstream deprecation guide that still show up in real clusters
+const DEPRECATED_APIS: &[DeprecatedApi] = &[
+    deprecated(
+        "Ingress",
+        "extensions/v1beta1",
+        "networking.k8s.io/v1",
+        "ingresses",
+        22,
+    ),
+    deprecated(
+        "Ingress",
+        "networking.k8s.io/v1beta1",
+        "networking.k8s.io/v1",
+        "ingresses",
+        22,
+    ),
+    deprecated(
+        "IngressClass",
+        "networking.k8s.io/v1beta1",
+        "networking.k8s.io/v1",
+        "ingressclasses",
+        22,
+    ),
+    deprecated(
+        "ClusterRole",
+        "rbac.authorization.k8s.io/v1beta1",
+        "rbac.authorization.k8s.io/v1",
+        "clusterroles",
+        22,
+    ),
+    deprecated(
+        "ClusterRoleBinding",
+        "rbac.authorization.k8s.io/v1beta1",
+        "rbac.authorization.k8s.io/v1",
+        "clusterrolebindings",
+        22,
+    ),
+    deprecated(
+        "Role",
+        "rbac.authorization.k8s.io/v1beta1",
+        "rbac.authorization.k8s.io/v1",
+        "roles",
+        22,
+    ),
+    deprecated(
+        "RoleBinding",
+        "rbac.authorization.k8s.io/v1beta1",
+        "rbac.authorization.k8s.io/v1",
+        "rolebindings",
+        22,
+    ),
+    deprecated(
+        "CustomResourceDefinition",
+        "apiextensions.k8s.io/v1beta1",
+        "apiextensions.k8s.io/v1",
+        "customresourcedefinitions",
+        22,
+    ),
+    deprecated(
+        "MutatingWebhookConfiguration",
+        "admissionregistration.k8s.io/v1beta1",
+        "admissionregistration.k8s.io/v1",
+        "mutatingwebhookconfigurations",
+        22,
+    ),
+    deprecated(
+        "ValidatingWebhookConfiguration",
+        "admissionregistration.k8s.io/v1beta1",
+        "admissionregistration.k8s.io/v1",
+        "validatingwebhookconfigurations",
+        22,
+    ),
+    deprecated(
+        "PriorityClass",
+        "scheduling.k8s.io/v1beta1",
+        "scheduling.k8s.io/v1",
+        "priorityclasses",
+        22,
+    ),
+    deprecated(
+        "StorageClass",
+        "storage.k8s.io/v1beta1",
+        "storage.k8s.io/v1",
+        "storageclasses",
+        22,
+    ),
+    deprecated("CronJob", "batch/v1beta1", "batch/v1", "cronjobs", 25),
+    deprecated(
+        "PodDisruptionBudget",
+        "policy/v1beta1",
+        "policy/v1",
+        "poddisruptionbudgets",
+        25,
+    ),
+    deprecated(
+        "EndpointSlice",
+        "discovery.k8s.io/v1beta1",
+        "discovery.k8s.io/v1",
+        "endpointslices",
+        25,
+    ),
+    deprecated(
+        "HorizontalPodAutoscaler",
+        "autoscaling/v2beta1",
+        "autoscaling/v2",
+        "horizontalpodautoscalers",
+        25,
+    ),
+    deprecated(
+        "HorizontalPodAutoscaler",
+        "autoscaling/v2beta2",
+        "autoscaling/v2",
+        "horizontalpodautoscalers",
+        26,
+    ),
+    deprecated(
+        "CSIStorageCapacity",
+        "storage.k8s.io/v1beta1",
+        "storage.k8s.io/v1",
+        "csistoragecapacities",
+        27,
+    ),
+    deprecated(
+        "FlowSchema",
+        "flowcontrol.apiserver.k8s.io/v1beta2",
+        "flowcontrol.apiserver.k8s.io/v1",
+        "flowschemas",
+        29,
+    ),
+    deprecated(
+        "FlowSchema",
+        "flowcontrol.apiserver.k8s.io/v1beta3",
+        "flowcontrol.apiserver.k8s.io/v1",
+        "flowschemas",
+        32,
+    ),
+];
+
+/// An object last written through a deprecated API version
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct DeprecatedApiUsage {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub deprecated_version: String,
+    pub replacement: String,
+    /// e.g. "1.25"
+    pub removed_in: String,
+    /// Whether the cluster's version has already stopped serving the deprecated version
+    pub removed: bool,
+}
+
+fn split_api_version(api_version: &str) -> (&str, &str) {
+    match api_version.split_once('/') {
+        Some((group, version)) => (group, version),
+        None => ("", api_version),
+    }
+}
+
+/// Minor version from discovery, which some providers suffix (e.g. "27+")
+fn parse_minor(minor: &str) -> Option<u32> {
+    minor
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// API versions an object has been written with, from its field managers and
+/// the kubectl last-applied annotation
+fn written_api_versions(obj: &DynamicObject) -> HashSet<String> {
+    let mut versions: HashSet<String> = obj
+        .metadata
+        .managed_fields
+        .iter()
+        .flatten()
+        .filter_map(|entry| entry.api_version.clone())
+        .collect();
+
+    if let Some(last_applied) = obj
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get("kubectl.kubernetes.io/last-applied-configuration"))
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+    {
+        if let Some(api_version) = last_applied.get("apiVersion").and_then(|v| v.as_str()) {
+            versions.insert(api_version.to_string());
+        }
+    }
+
+    versions
+}
+
+fn find_deprecated_usage(
+    obj: &DynamicObject,
+    apis: &[&DeprecatedApi],
+    cluster_minor: Option<u32>,
+) -> Vec<DeprecatedApiUsage> {
+    let written = written_api_versions(obj);
+    apis.iter()
+        .filter(|api| written.contains(api.deprecated_version))
+        .map(|api| DeprecatedApiUsage {
+            kind: api.kind.to_string(),
+            name: obj.metadata.name.clone().unwrap_or_default(),
+            namespace: obj.metadata.namespace.clone(),
+            deprecated_version: api.deprecated_version.to_string(),
+            replacement: api.replacement.to_string(),
+            removed_in: format!("1.{}", api.removed_in_minor),
+            removed: cluster_minor.is_some_and(|minor| minor >= api.removed_in_minor),
+        })
+        .collect()
+}
+
+/// Objects whose manifests still use deprecated API versions. Each kind is listed
+/// through its replacement version (when the cluster serves it) and flagged when a
+/// field manager or `kubectl apply` last wrote it through a deprecated version.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_check_deprecated_apis(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<DeprecatedApiUsage>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let cluster_minor = client
+        .apiserver_version()
+        .await
+        .ok()
+        .and_then(|info| parse_minor(&info.minor));
+
+    let groups = client
+        .list_api_groups()
+        .await
+        .map_err(|e| format!("Failed to discover API groups: {}", e))?;
+    let served: HashSet<String> = groups
+        .groups
+        .iter()
+        .flat_map(|g| g.versions.iter().map(|v| v.group_version.clone()))
+        .collect();
+
+    // One list per replacement resource, checked against all of its deprecated versions
+    let mut targets: Vec<(&str, &str, &str)> = DEPRECATED_APIS
+        .iter()
+        .map(|api| (api.replacement, api.kind, api.plural))
+        .collect();
+    targets.dedup();
+
+    let mut usages = Vec::new();
+    for (replacement, kind, plural) in targets {
+        if !served.contains(replacement) {
+            continue;
+        }
+
+        let (group, version) = split_api_version(replacement);
+        let resource =
+            ApiResource::from_gvk_with_plural(&GroupVersionKind::gvk(group, version, kind), plural);
+        let api: Api<DynamicObject> = Api::all_with(client.clone(), &resource);
+        let list = match api.list(&ListParams::default()).await {
+            Ok(list) => list,
+            // Missing RBAC on one kind shouldn't hide findings for the rest
+            Err(e) => {
+                println!("Skipping {} in deprecation check: {}", kind, e);
+                continue;
+            }
+        };
+
+        let apis: Vec<&DeprecatedApi> = DEPRECATED_APIS
+            .iter()
+            .filter(|api| api.kind == kind && api.replacement == replacement)
+            .collect();
+        for obj in &list.items {
+            usages.extend(find_deprecated_usage(obj, &apis, cluster_minor));
+        }
+    }
+
+    Ok(usages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minor_strips_provider_suffix() {
+        assert_eq!(parse_minor("27+"), Some(27));
+        assert_eq!(parse_minor("31"), Some(31));
+        assert_eq!(parse_minor(""), None);
+    }
+
+    #[test]
+    fn test_find_deprecated_usage_from_last_applied() {
+        let obj: DynamicObject = serde_yaml::from_str(
+            r#"
+apiVersion: networking.k8s.io/v1
+kind: Ingress
+metadata:
+  name: web
+  namespace: default
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: '{"apiVersion":"networking.k8s.io/v1beta1","kind":"Ingress"}'
+"#,
+        )
+        .unwrap();
+        let apis: Vec<&DeprecatedApi> = DEPRECATED_APIS
+            .iter()
+            .filter(|api| api.kind == "Ingress")
+            .collect();
+
+        let usages = find_deprecated_usage(&obj, &apis, Some(21));
+
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].deprecated_version, "networking.k8s.io/v1beta1");
+        assert_eq!(usages[0].replacement, "networking.k8s.io/v1");
+        assert_eq!(usages[0].removed_in, "1.22");
+        assert!(!usages[0].removed);
+    }
+
+    #[test]
+    fn test_find_deprecated_usage_from_managed_fields() {
+        let obj: DynamicObject = serde_yaml::from_str(
+            r#"
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: nightly
+  managedFields:
+  - apiVersion: batch/v1beta1
+    manager: helm
+    operation: Update
+  - apiVersion: batch/v1
+    manager: kube-controller-manager
+    operation: Update
+"#,
+        )
+        .unwrap();
+        let apis: Vec<&DeprecatedApi> = DEPRECATED_APIS
+            .iter()
+            .filter(|api| api.kind == "CronJob")
+            .collect();
+
+        let usages = find_deprecated_usage(&obj, &apis, Some(31));
+
+        assert_eq!(usages.len(), 1);
+        assert!(usages[0].removed);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{build_label_selector, label_selector_matches};
+use crate::settings::load_settings;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{ContainerStateTerminated, Pod};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::{Api, ListParams, LogParams};
+use kube::Client;
+use tauri::State;
+
+/// Previous-container logs of the most recent crash among a workload's pods
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct LatestFailureLogs {
+    pub pod_name: String,
+    pub container_name: String,
+    pub finished_at: String,
+    pub exit_code: i32,
+    pub reason: Option<String>,
+    pub restart_count: i32,
+    pub logs: String,
+}
+
+/// The pod selector of a workload, by kind name (e.g. "Deployment")
+async fn workload_selector(
+    client: Client,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> Result<LabelSelector, String> {
+    let selector = match kind.to_lowercase().as_str() {
+        "deployment" => Api::<Deployment>::namespaced(client, namespace)
+            .get(name)
+            .await
+            .map(|w| w.spec.map(|s| s.selector)),
+        "statefulset" => Api::<StatefulSet>::namespaced(client, namespace)
+            .get(name)
+            .await
+            .map(|w| w.spec.map(|s| s.selector)),
+        "daemonset" => Api::<DaemonSet>::namespaced(client, namespace)
+            .get(name)
+            .await
+            .map(|w| w.spec.map(|s| s.selector)),
+        "replicaset" => Api::<ReplicaSet>::namespaced(client, namespace)
+            .get(name)
+            .await
+            .map(|w| w.spec.map(|s| s.selector)),
+        "job" => Api::<Job>::namespaced(client, namespace)
+            .get(name)
+            .await
+            .map(|w| w.spec.and_then(|s| s.selector)),
+        _ => return Err(format!("Unsupported workload kind: {}", kind)),
+    };
+
+    selector
+        .map_err(|e| format!("Failed to get {} '{}': {}", kind, name, e))?
+        .ok_or_else(|| format!("{} '{}' has no pod selector", kind, name))
+}
+
+/// The container termination that finished last across all pods, as
+/// (pod name, container name, restart count, termination)
+fn latest_termination(pods: &[Pod]) -> Option<(String, String, i32, ContainerStateTerminated)> {
+    pods.iter()
+        .flat_map(|pod| {
+            let pod_name = pod.metadata.name.clone().unwrap_or_default();
+            let status = pod.status.as_ref();
+            status
+                .and_then(|s| s.init_container_statuses.as_ref())
+                .into_iter()
+                .flatten()
+                .chain(
+                    status
+                        .and_then(|s| s.container_statuses.as_ref())
+                        .into_iter()
+                        .flatten(),
+                )
+                .filter_map(move |cs| {
+                    let terminated = cs.last_state.as_ref()?.terminated.clone()?;
+                    terminated.finished_at.as_ref()?;
+                    Some((
+                        pod_name.clone(),
+                        cs.name.clone(),
+                        cs.restart_count,
+                        terminated,
+                    ))
+                })
+        })
+        .max_by(|a, b| a.3.finished_at.cmp(&b.3.finished_at))
+}
+
+/// Find the workload replica whose container crashed most recently and return the
+/// logs of that dead container. Returns `None` when no container has terminated.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_latest_failure_logs(
+    cluster_id: String,
+    namespace: String,
+    kind: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Option<LatestFailureLogs>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let selector = workload_selector(client.clone(), &namespace, &kind, &name).await?;
+
+    // matchLabels narrows the list server-side; matchExpressions are checked here
+    let pods_api: Api<Pod> = Api::namespaced(client, &namespace);
+    let lp = ListParams::default().labels(&build_label_selector(
+        &selector.match_labels.clone().unwrap_or_default(),
+    ));
+    let pods: Vec<Pod> = pods_api
+        .list(&lp)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?
+        .items
+        .into_iter()
+        .filter(|p| {
+            label_selector_matches(
+                &selector,
+                p.metadata.labels.as_ref().unwrap_or(&Default::default()),
+            )
+        })
+        .collect();
+
+    let Some((pod_name, container_name, restart_count, terminated)) = latest_termination(&pods)
+    else {
+        return Ok(None);
+    };
+
+    let settings = load_settings(&state);
+    let log_params = LogParams {
+        previous: true,
+        container: Some(container_name.clone()),
+        tail_lines: Some(settings.log_tail_lines),
+        timestamps: settings.default_log_timestamps,
+        ..Default::default()
+    };
+    let logs = pods_api
+        .logs(&pod_name, &log_params)
+        .await
+        .map_err(|e| format!("Failed to get previous logs for '{}': {}", pod_name, e))?;
+
+    Ok(Some(LatestFailureLogs {
+        pod_name,
+        container_name,
+        finished_at: terminated
+            .finished_at
+            .map(|t| t.0.to_string())
+            .unwrap_or_default(),
+        exit_code: terminated.exit_code,
+        reason: terminated.reason,
+        restart_count,
+        logs,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(yaml: &str) -> Pod {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_latest_termination_picks_most_recent() {
+        let pods = vec![
+            pod(r#"
+metadata:
+  name: web-a
+status:
+  containerStatuses:
+  - name: app
+    image: app
+    imageID: ""
+    ready: true
+    restartCount: 2
+    lastState:
+      terminated:
+        exitCode: 1
+        finishedAt: "2024-01-01T10:00:00Z"
+"#),
+            pod(r#"
+metadata:
+  name: web-b
+status:
+  containerStatuses:
+  - name: app
+    image: app
+    imageID: ""
+    ready: false
+    restartCount: 5
+    lastState:
+      terminated:
+        exitCode: 137
+        reason: OOMKilled
+        finishedAt: "2024-01-01T12:00:00Z"
+  - name: sidecar
+    image: sidecar
+    imageID: ""
+    ready: true
+    restartCount: 0
+"#),
+        ];
+
+        let (pod_name, container, restarts, terminated) = latest_termination(&pods).unwrap();
+
+        assert_eq!(pod_name, "web-b");
+        assert_eq!(container, "app");
+        assert_eq!(restarts, 5);
+        assert_eq!(terminated.exit_code, 137);
+        assert_eq!(terminated.reason.as_deref(), Some("OOMKilled"));
+    }
+
+    #[test]
+    fn test_latest_termination_none_without_crashes() {
+        let pods = vec![pod(r#"
+metadata:
+  name: web-a
+status:
+  containerStatuses:
+  - name: app
+    image: app
+    imageID: ""
+    ready: true
+    restartCount: 0
+"#)];
+
+        assert!(latest_termination(&pods).is_none());
+    }
+}
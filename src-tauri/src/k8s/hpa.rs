@@ -0,0 +1,396 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::is_not_found;
+use k8s_openapi::api::autoscaling::{v1, v2};
+use kube::api::Api;
+use std::collections::HashMap;
+use tauri::State;
+
+/// Detailed information about a HorizontalPodAutoscaler
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HpaDetails {
+    pub name: String,
+    pub namespace: String,
+    pub uid: String,
+    pub created_at: String,
+    pub labels: HashMap<String, String>,
+    pub annotations: HashMap<String, String>,
+    /// API version the object was read through ("autoscaling/v2" or "autoscaling/v1")
+    pub api_version: String,
+    pub scale_target_kind: String,
+    pub scale_target_name: String,
+    pub min_replicas: i32,
+    pub max_replicas: i32,
+    pub current_replicas: i32,
+    pub desired_replicas: i32,
+    pub metrics: Vec<HpaMetric>,
+    pub conditions: Vec<HpaCondition>,
+}
+
+/// A single scaling metric with its target and last observed value
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct HpaMetric {
+    /// Resource, ContainerResource, Pods, Object or External
+    pub metric_type: String,
+    pub name: String,
+    pub target: String,
+    pub current: Option<String>,
+}
+
+/// Condition of a HorizontalPodAutoscaler
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HpaCondition {
+    pub condition_type: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    pub last_transition_time: Option<String>,
+}
+
+fn format_metric_target(target: &v2::MetricTarget) -> String {
+    match target.type_.as_str() {
+        "Utilization" => target
+            .average_utilization
+            .map(|u| format!("{}%", u))
+            .unwrap_or_else(|| "-".to_string()),
+        "AverageValue" => target
+            .average_value
+            .as_ref()
+            .map(|q| format!("{} (avg)", q.0))
+            .unwrap_or_else(|| "-".to_string()),
+        _ => target
+            .value
+            .as_ref()
+            .map(|q| q.0.clone())
+            .unwrap_or_else(|| "-".to_string()),
+    }
+}
+
+fn format_metric_value(value: &v2::MetricValueStatus) -> Option<String> {
+    if let Some(u) = value.average_utilization {
+        Some(format!("{}%", u))
+    } else if let Some(q) = value.average_value.as_ref() {
+        Some(format!("{} (avg)", q.0))
+    } else {
+        value.value.as_ref().map(|q| q.0.clone())
+    }
+}
+
+/// Identify a metric spec as (type, name) along with its target
+fn describe_metric_spec(spec: &v2::MetricSpec) -> Option<(String, String)> {
+    match spec.type_.as_str() {
+        "Resource" => spec
+            .resource
+            .as_ref()
+            .map(|r| (r.name.clone(), format_metric_target(&r.target))),
+        "ContainerResource" => spec.container_resource.as_ref().map(|r| {
+            (
+                format!("{}/{}", r.container, r.name),
+                format_metric_target(&r.target),
+            )
+        }),
+        "Pods" => spec
+            .pods
+            .as_ref()
+            .map(|p| (p.metric.name.clone(), format_metric_target(&p.target))),
+        "Object" => spec.object.as_ref().map(|o| {
+            (
+                format!(
+                    "{}/{} {}",
+                    o.described_object.kind, o.described_object.name, o.metric.name
+                ),
+                format_metric_target(&o.target),
+            )
+        }),
+        "External" => spec
+            .external
+            .as_ref()
+            .map(|e| (e.metric.name.clone(), format_metric_target(&e.target))),
+        _ => None,
+    }
+}
+
+/// Identify a metric status as (type, name) along with its current value
+fn describe_metric_status(status: &v2::MetricStatus) -> Option<(String, Option<String>)> {
+    match status.type_.as_str() {
+        "Resource" => status
+            .resource
+            .as_ref()
+            .map(|r| (r.name.clone(), format_metric_value(&r.current))),
+        "ContainerResource" => status.container_resource.as_ref().map(|r| {
+            (
+                format!("{}/{}", r.container, r.name),
+                format_metric_value(&r.current),
+            )
+        }),
+        "Pods" => status
+            .pods
+            .as_ref()
+            .map(|p| (p.metric.name.clone(), format_metric_value(&p.current))),
+        "Object" => status.object.as_ref().map(|o| {
+            (
+                format!(
+                    "{}/{} {}",
+                    o.described_object.kind, o.described_object.name, o.metric.name
+                ),
+                format_metric_value(&o.current),
+            )
+        }),
+        "External" => status
+            .external
+            .as_ref()
+            .map(|e| (e.metric.name.clone(), format_metric_value(&e.current))),
+        _ => None,
+    }
+}
+
+/// Pair each configured metric with its current value (matched by type and name)
+pub(crate) fn map_hpa_v2_metrics(
+    specs: &[v2::MetricSpec],
+    statuses: &[v2::MetricStatus],
+) -> Vec<HpaMetric> {
+    let current: HashMap<(String, String), Option<String>> = statuses
+        .iter()
+        .filter_map(|s| {
+            describe_metric_status(s).map(|(name, value)| ((s.type_.clone(), name), value))
+        })
+        .collect();
+
+    specs
+        .iter()
+        .filter_map(|spec| {
+            let (name, target) = describe_metric_spec(spec)?;
+            let current = current
+                .get(&(spec.type_.clone(), name.clone()))
+                .cloned()
+                .flatten();
+            Some(HpaMetric {
+                metric_type: spec.type_.clone(),
+                name,
+                target,
+                current,
+            })
+        })
+        .collect()
+}
+
+/// Metrics of an autoscaling/v1 HPA, which can only express CPU utilization
+fn map_hpa_v1_metrics(hpa: &v1::HorizontalPodAutoscaler) -> Vec<HpaMetric> {
+    let target = hpa
+        .spec
+        .as_ref()
+        .and_then(|s| s.target_cpu_utilization_percentage);
+    let current = hpa
+        .status
+        .as_ref()
+        .and_then(|s| s.current_cpu_utilization_percentage);
+
+    target
+        .map(|t| {
+            vec![HpaMetric {
+                metric_type: "Resource".to_string(),
+                name: "cpu".to_string(),
+                target: format!("{}%", t),
+                current: current.map(|c| format!("{}%", c)),
+            }]
+        })
+        .unwrap_or_default()
+}
+
+fn map_hpa_v2_to_details(hpa: v2::HorizontalPodAutoscaler) -> HpaDetails {
+    let meta = hpa.metadata;
+    let spec = hpa.spec.unwrap_or_default();
+    let status = hpa.status.unwrap_or_default();
+
+    let metrics = map_hpa_v2_metrics(
+        spec.metrics.as_deref().unwrap_or_default(),
+        status.current_metrics.as_deref().unwrap_or_default(),
+    );
+
+    let conditions = status
+        .conditions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| HpaCondition {
+            condition_type: c.type_,
+            status: c.status,
+            reason: c.reason,
+            message: c.message,
+            last_transition_time: c.last_transition_time.map(|t| t.0.to_string()),
+        })
+        .collect();
+
+    HpaDetails {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        uid: meta.uid.unwrap_or_default(),
+        created_at: meta
+            .creation_timestamp
+            .map(|t| t.0.to_string())
+            .unwrap_or_default(),
+        labels: meta.labels.unwrap_or_default().into_iter().collect(),
+        annotations: meta.annotations.unwrap_or_default().into_iter().collect(),
+        api_version: "autoscaling/v2".to_string(),
+        scale_target_kind: spec.scale_target_ref.kind,
+        scale_target_name: spec.scale_target_ref.name,
+        min_replicas: spec.min_replicas.unwrap_or(1),
+        max_replicas: spec.max_replicas,
+        current_replicas: status.current_replicas.unwrap_or(0),
+        desired_replicas: status.desired_replicas,
+        metrics,
+        conditions,
+    }
+}
+
+fn map_hpa_v1_to_details(hpa: v1::HorizontalPodAutoscaler) -> HpaDetails {
+    let metrics = map_hpa_v1_metrics(&hpa);
+    let meta = hpa.metadata;
+    let spec = hpa.spec.unwrap_or_default();
+    let status = hpa.status.unwrap_or_default();
+
+    HpaDetails {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        uid: meta.uid.unwrap_or_default(),
+        created_at: meta
+            .creation_timestamp
+            .map(|t| t.0.to_string())
+            .unwrap_or_default(),
+        labels: meta.labels.unwrap_or_default().into_iter().collect(),
+        annotations: meta.annotations.unwrap_or_default().into_iter().collect(),
+        api_version: "autoscaling/v1".to_string(),
+        scale_target_kind: spec.scale_target_ref.kind,
+        scale_target_name: spec.scale_target_ref.name,
+        min_replicas: spec.min_replicas.unwrap_or(1),
+        max_replicas: spec.max_replicas,
+        current_replicas: status.current_replicas,
+        desired_replicas: status.desired_replicas,
+        metrics,
+        conditions: vec![],
+    }
+}
+
+/// Get detailed information about an HPA, including all v2 metric types.
+/// Falls back to autoscaling/v1 on clusters that don't serve v2.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_hpa_details(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<HpaDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let v2_api: Api<v2::HorizontalPodAutoscaler> = Api::namespaced(client.clone(), &namespace);
+    match v2_api.get(&name).await {
+        Ok(hpa) => return Ok(map_hpa_v2_to_details(hpa)),
+        Err(e) if !is_not_found(&e) => {
+            return Err(format!("Failed to get HPA '{}': {}", name, e));
+        }
+        // A 404 is either a missing HPA or a server without autoscaling/v2; v1 tells them apart
+        Err(_) => {}
+    }
+
+    let v1_api: Api<v1::HorizontalPodAutoscaler> = Api::namespaced(client, &namespace);
+    let hpa = v1_api
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get HPA '{}': {}", name, e))?;
+
+    Ok(map_hpa_v1_to_details(hpa))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    fn resource_spec(name: &str, utilization: i32) -> v2::MetricSpec {
+        v2::MetricSpec {
+            type_: "Resource".to_string(),
+            resource: Some(v2::ResourceMetricSource {
+                name: name.to_string(),
+                target: v2::MetricTarget {
+                    type_: "Utilization".to_string(),
+                    average_utilization: Some(utilization),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn resource_status(name: &str, utilization: i32) -> v2::MetricStatus {
+        v2::MetricStatus {
+            type_: "Resource".to_string(),
+            resource: Some(v2::ResourceMetricStatus {
+                name: name.to_string(),
+                current: v2::MetricValueStatus {
+                    average_utilization: Some(utilization),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_v2_metrics_match_current_by_name() {
+        let specs = vec![resource_spec("cpu", 70), resource_spec("memory", 80)];
+        let statuses = vec![resource_status("memory", 65), resource_status("cpu", 40)];
+
+        let metrics = map_hpa_v2_metrics(&specs, &statuses);
+
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics[0].name, "cpu");
+        assert_eq!(metrics[0].target, "70%");
+        assert_eq!(metrics[0].current.as_deref(), Some("40%"));
+        assert_eq!(metrics[1].name, "memory");
+        assert_eq!(metrics[1].current.as_deref(), Some("65%"));
+    }
+
+    #[test]
+    fn test_v2_external_metric_without_status() {
+        let specs = vec![v2::MetricSpec {
+            type_: "External".to_string(),
+            external: Some(v2::ExternalMetricSource {
+                metric: v2::MetricIdentifier {
+                    name: "queue_depth".to_string(),
+                    ..Default::default()
+                },
+                target: v2::MetricTarget {
+                    type_: "AverageValue".to_string(),
+                    average_value: Some(Quantity("30".to_string())),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        }];
+
+        let metrics = map_hpa_v2_metrics(&specs, &[]);
+
+        assert_eq!(metrics[0].metric_type, "External");
+        assert_eq!(metrics[0].name, "queue_depth");
+        assert_eq!(metrics[0].target, "30 (avg)");
+        assert_eq!(metrics[0].current, None);
+    }
+
+    #[test]
+    fn test_v1_metrics_only_cpu() {
+        let hpa = v1::HorizontalPodAutoscaler {
+            spec: Some(v1::HorizontalPodAutoscalerSpec {
+                max_replicas: 5,
+                target_cpu_utilization_percentage: Some(50),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let metrics = map_hpa_v1_metrics(&hpa);
+
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].target, "50%");
+        assert_eq!(metrics[0].current, None);
+    }
+}
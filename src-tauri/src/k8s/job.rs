@@ -0,0 +1,421 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::build_label_selector;
+use crate::k8s::watcher::{finish_watch, watch_key, WatchRegistration, WatchSpec, WatcherState};
+use crate::settings::load_settings;
+use futures::StreamExt;
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{ListParams, LogParams};
+use kube::runtime::watcher;
+use kube::{Api, Client};
+use tauri::{Emitter, State, Window};
+
+/// Pod counts of a running Job, emitted as `job_progress_<stream_id>`
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct JobProgress {
+    pub name: String,
+    pub namespace: String,
+    pub active: i32,
+    pub succeeded: i32,
+    pub failed: i32,
+    pub completions: Option<i32>,
+    pub backoff_limit: i32,
+}
+
+/// How a Job ended, emitted once as `job_complete_<stream_id>`
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct JobResult {
+    pub name: String,
+    pub namespace: String,
+    pub succeeded: bool,
+    /// The terminal condition type: "Complete" or "Failed"
+    pub condition: String,
+    /// e.g. "BackoffLimitExceeded" or "DeadlineExceeded"
+    pub reason: Option<String>,
+    pub message: Option<String>,
+    /// The pod that failed last, when the Job failed
+    pub failed_pod: Option<String>,
+    /// Logs of the failed container in `failed_pod`
+    pub logs: Option<String>,
+}
+
+fn job_progress(job: &Job) -> JobProgress {
+    let status = job.status.clone().unwrap_or_default();
+    let spec = job.spec.as_ref();
+
+    JobProgress {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        namespace: job.metadata.namespace.clone().unwrap_or_default(),
+        active: status.active.unwrap_or(0),
+        succeeded: status.succeeded.unwrap_or(0),
+        failed: status.failed.unwrap_or(0),
+        completions: spec.and_then(|s| s.completions),
+        // The API server defaults an unset backoffLimit to 6
+        backoff_limit: spec.and_then(|s| s.backoff_limit).unwrap_or(6),
+    }
+}
+
+/// The Job's terminal condition, if it has finished
+fn job_outcome(job: &Job) -> Option<JobResult> {
+    let condition = job
+        .status
+        .as_ref()?
+        .conditions
+        .as_ref()?
+        .iter()
+        .find(|c| (c.type_ == "Complete" || c.type_ == "Failed") && c.status == "True")?;
+
+    Some(JobResult {
+        name: job.metadata.name.clone().unwrap_or_default(),
+        namespace: job.metadata.namespace.clone().unwrap_or_default(),
+        succeeded: condition.type_ == "Complete",
+        condition: condition.type_.clone(),
+        reason: condition.reason.clone(),
+        message: condition.message.clone(),
+        failed_pod: None,
+        logs: None,
+    })
+}
+
+/// A container whose non-zero exit is the Job's latest failure
+#[derive(Debug, PartialEq)]
+struct FailedContainer {
+    pod: String,
+    container: String,
+    /// The exit belongs to a container that has since restarted
+    previous: bool,
+}
+
+/// The container whose non-zero exit happened last
+fn last_failed_container(pods: &[Pod]) -> Option<FailedContainer> {
+    pods.iter()
+        .flat_map(|pod| {
+            let pod_name = pod.metadata.name.clone().unwrap_or_default();
+            pod.status
+                .as_ref()
+                .and_then(|s| s.container_statuses.as_ref())
+                .into_iter()
+                .flatten()
+                .filter_map(move |cs| {
+                    // Never-restarting pods keep the exit in `state`, OnFailure ones in `last_state`
+                    let current = cs
+                        .state
+                        .as_ref()
+                        .and_then(|s| s.terminated.as_ref())
+                        .map(|t| (t, false));
+                    let (terminated, previous) = current
+                        .or_else(|| {
+                            cs.last_state
+                                .as_ref()
+                                .and_then(|s| s.terminated.as_ref())
+                                .map(|t| (t, true))
+                        })
+                        .filter(|(t, _)| t.exit_code != 0)?;
+                    Some((
+                        terminated.finished_at.clone(),
+                        FailedContainer {
+                            pod: pod_name.clone(),
+                            container: cs.name.clone(),
+                            previous,
+                        },
+                    ))
+                })
+        })
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, failed)| failed)
+}
+
+/// Record the Job's last failed pod on `result`, with the logs of its failed container
+async fn add_failed_pod_logs(
+    result: &mut JobResult,
+    client: Client,
+    job: &Job,
+    tail_lines: i64,
+) -> Result<(), String> {
+    let namespace = job.metadata.namespace.as_deref().unwrap_or_default();
+    let Some(match_labels) = job
+        .spec
+        .as_ref()
+        .and_then(|s| s.selector.as_ref())
+        .and_then(|s| s.match_labels.clone())
+    else {
+        return Ok(());
+    };
+
+    let pods: Api<Pod> = Api::namespaced(client, namespace);
+    let list = pods
+        .list(&ListParams::default().labels(&build_label_selector(&match_labels)))
+        .await
+        .map_err(|e| format!("Failed to list job pods: {}", e))?;
+
+    let Some(failed) = last_failed_container(&list.items) else {
+        return Ok(());
+    };
+    result.failed_pod = Some(failed.pod.clone());
+
+    let log_params = LogParams {
+        container: Some(failed.container),
+        previous: failed.previous,
+        tail_lines: Some(tail_lines),
+        ..Default::default()
+    };
+    let logs = pods
+        .logs(&failed.pod, &log_params)
+        .await
+        .map_err(|e| format!("Failed to get logs for '{}': {}", failed.pod, e))?;
+    result.logs = Some(logs);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn cluster_watch_job(
+    cluster_id: String,
+    namespace: String,
+    job_name: String,
+    stream_id: String,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), String> {
+    start_job_watch(
+        cluster_id,
+        namespace,
+        job_name,
+        stream_id,
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+/// Start (or restart) watching a Job, emitting `job_progress_<stream_id>` whenever its
+/// pod counts change. Once the Job completes or exhausts its backoff limit,
+/// `job_complete_<stream_id>` carries the outcome and the watch ends.
+pub(crate) async fn start_job_watch(
+    cluster_id: String,
+    namespace: String,
+    job_name: String,
+    stream_id: String,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+    let tail_lines = load_settings(state).log_tail_lines;
+
+    let config = watcher::Config::default().fields(&format!("metadata.name={}", job_name));
+    let key = watch_key(window.label(), &format!("job_watch:{}", stream_id));
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::Job {
+            cluster_id,
+            namespace,
+            job_name,
+            stream_id: stream_id.clone(),
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let progress_event = format!("job_progress_{}", stream_id);
+        let complete_event = format!("job_complete_{}", stream_id);
+        let mut stream = watcher(api, config).boxed();
+        let mut last_emitted: Option<JobProgress> = None;
+
+        while let Some(event) = stream.next().await {
+            let job = match event {
+                Ok(watcher::Event::Apply(job)) | Ok(watcher::Event::InitApply(job)) => job,
+                Ok(watcher::Event::Delete(_)) => break,
+                Ok(_) => continue,
+                Err(e) => {
+                    println!("Job watch error: {}", e);
+                    continue;
+                }
+            };
+
+            let progress = job_progress(&job);
+            if last_emitted.as_ref() != Some(&progress) {
+                if let Err(e) = window.emit(&progress_event, progress.clone()) {
+                    println!("Failed to emit job progress: {}", e);
+                    break;
+                }
+                last_emitted = Some(progress);
+            }
+
+            if let Some(mut result) = job_outcome(&job) {
+                if !result.succeeded {
+                    if let Err(e) =
+                        add_failed_pod_logs(&mut result, client.clone(), &job, tail_lines).await
+                    {
+                        println!("{}", e);
+                    }
+                }
+                if let Err(e) = window.emit(&complete_event, result) {
+                    println!("Failed to emit job result: {}", e);
+                }
+                break;
+            }
+        }
+
+        // A finished or deleted Job (or a closed window) has nothing left to reconnect to
+        finish_watch(&handles, &registrations, &key_clone, true);
+    });
+
+    // Store new handle
+    watcher_state.insert(key, handle, registration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(yaml: &str) -> Job {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_job_progress_defaults_backoff_limit() {
+        let running = job(r#"
+metadata:
+  name: migrate
+  namespace: default
+spec:
+  completions: 3
+  template: {}
+status:
+  active: 1
+  succeeded: 2
+"#);
+
+        let progress = job_progress(&running);
+
+        assert_eq!(progress.active, 1);
+        assert_eq!(progress.succeeded, 2);
+        assert_eq!(progress.failed, 0);
+        assert_eq!(progress.completions, Some(3));
+        assert_eq!(progress.backoff_limit, 6);
+        assert!(job_outcome(&running).is_none());
+    }
+
+    #[test]
+    fn test_job_outcome_failed() {
+        let failed = job(r#"
+metadata:
+  name: migrate
+  namespace: default
+spec:
+  backoffLimit: 2
+  template: {}
+status:
+  failed: 3
+  conditions:
+  - type: FailureTarget
+    status: "True"
+  - type: Failed
+    status: "True"
+    reason: BackoffLimitExceeded
+    message: Job has reached the specified backoff limit
+"#);
+
+        let result = job_outcome(&failed).unwrap();
+
+        assert!(!result.succeeded);
+        assert_eq!(result.condition, "Failed");
+        assert_eq!(result.reason.as_deref(), Some("BackoffLimitExceeded"));
+    }
+
+    #[test]
+    fn test_last_failed_container() {
+        let pods: Vec<Pod> = vec![
+            serde_yaml::from_str(
+                r#"
+metadata:
+  name: migrate-a
+status:
+  containerStatuses:
+  - name: main
+    image: migrate
+    imageID: ""
+    ready: false
+    restartCount: 0
+    state:
+      terminated:
+        exitCode: 1
+        finishedAt: "2024-01-01T10:00:00Z"
+"#,
+            )
+            .unwrap(),
+            serde_yaml::from_str(
+                r#"
+metadata:
+  name: migrate-b
+status:
+  containerStatuses:
+  - name: main
+    image: migrate
+    imageID: ""
+    ready: false
+    restartCount: 0
+    state:
+      terminated:
+        exitCode: 2
+        finishedAt: "2024-01-01T11:00:00Z"
+"#,
+            )
+            .unwrap(),
+        ];
+
+        assert_eq!(
+            last_failed_container(&pods),
+            Some(FailedContainer {
+                pod: "migrate-b".to_string(),
+                container: "main".to_string(),
+                previous: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_last_failed_container_after_restart() {
+        let pod: Pod = serde_yaml::from_str(
+            r#"
+metadata:
+  name: migrate-a
+status:
+  containerStatuses:
+  - name: main
+    image: migrate
+    imageID: ""
+    ready: true
+    restartCount: 1
+    state:
+      running:
+        startedAt: "2024-01-01T10:01:00Z"
+    lastState:
+      terminated:
+        exitCode: 1
+        finishedAt: "2024-01-01T10:00:00Z"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            last_failed_container(&[pod]),
+            Some(FailedContainer {
+                pod: "migrate-a".to_string(),
+                container: "main".to_string(),
+                previous: true,
+            })
+        );
+    }
+}
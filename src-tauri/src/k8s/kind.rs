@@ -0,0 +1,179 @@
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscaler;
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Endpoints, LimitRange, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota,
+    Secret, Service, ServiceAccount,
+};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::api::rbac::v1::{ClusterRole, Role, RoleBinding};
+use k8s_openapi::api::storage::v1::StorageClass;
+use kube::api::ApiResource;
+
+/// Built-in kinds that commands pick by name at runtime (YAML view, snapshots,
+/// cluster comparison), so each of them resolves names the same way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Pod,
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Job,
+    CronJob,
+    ConfigMap,
+    Secret,
+    ResourceQuota,
+    LimitRange,
+    HorizontalPodAutoscaler,
+    PodDisruptionBudget,
+    Service,
+    Endpoints,
+    EndpointSlice,
+    Ingress,
+    NetworkPolicy,
+    PersistentVolumeClaim,
+    ServiceAccount,
+    Role,
+    RoleBinding,
+    PersistentVolume,
+    StorageClass,
+    ClusterRole,
+}
+
+impl ResourceKind {
+    const ALL: [ResourceKind; 25] = [
+        ResourceKind::Pod,
+        ResourceKind::Deployment,
+        ResourceKind::StatefulSet,
+        ResourceKind::DaemonSet,
+        ResourceKind::ReplicaSet,
+        ResourceKind::Job,
+        ResourceKind::CronJob,
+        ResourceKind::ConfigMap,
+        ResourceKind::Secret,
+        ResourceKind::ResourceQuota,
+        ResourceKind::LimitRange,
+        ResourceKind::HorizontalPodAutoscaler,
+        ResourceKind::PodDisruptionBudget,
+        ResourceKind::Service,
+        ResourceKind::Endpoints,
+        ResourceKind::EndpointSlice,
+        ResourceKind::Ingress,
+        ResourceKind::NetworkPolicy,
+        ResourceKind::PersistentVolumeClaim,
+        ResourceKind::ServiceAccount,
+        ResourceKind::Role,
+        ResourceKind::RoleBinding,
+        ResourceKind::PersistentVolume,
+        ResourceKind::StorageClass,
+        ResourceKind::ClusterRole,
+    ];
+
+    /// The kind named `kind`, case-insensitively (e.g. "Deployment" or "deployment")
+    pub fn parse(kind: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|k| k.api_resource().kind.eq_ignore_ascii_case(kind))
+    }
+
+    pub fn api_resource(self) -> ApiResource {
+        match self {
+            ResourceKind::Pod => ApiResource::erase::<Pod>(&()),
+            ResourceKind::Deployment => ApiResource::erase::<Deployment>(&()),
+            ResourceKind::StatefulSet => ApiResource::erase::<StatefulSet>(&()),
+            ResourceKind::DaemonSet => ApiResource::erase::<DaemonSet>(&()),
+            ResourceKind::ReplicaSet => ApiResource::erase::<ReplicaSet>(&()),
+            ResourceKind::Job => ApiResource::erase::<Job>(&()),
+            ResourceKind::CronJob => ApiResource::erase::<CronJob>(&()),
+            ResourceKind::ConfigMap => ApiResource::erase::<ConfigMap>(&()),
+            ResourceKind::Secret => ApiResource::erase::<Secret>(&()),
+            ResourceKind::ResourceQuota => ApiResource::erase::<ResourceQuota>(&()),
+            ResourceKind::LimitRange => ApiResource::erase::<LimitRange>(&()),
+            ResourceKind::HorizontalPodAutoscaler => {
+                ApiResource::erase::<HorizontalPodAutoscaler>(&())
+            }
+            ResourceKind::PodDisruptionBudget => ApiResource::erase::<PodDisruptionBudget>(&()),
+            ResourceKind::Service => ApiResource::erase::<Service>(&()),
+            ResourceKind::Endpoints => ApiResource::erase::<Endpoints>(&()),
+            ResourceKind::EndpointSlice => ApiResource::erase::<EndpointSlice>(&()),
+            ResourceKind::Ingress => ApiResource::erase::<Ingress>(&()),
+            ResourceKind::NetworkPolicy => ApiResource::erase::<NetworkPolicy>(&()),
+            ResourceKind::PersistentVolumeClaim => ApiResource::erase::<PersistentVolumeClaim>(&()),
+            ResourceKind::ServiceAccount => ApiResource::erase::<ServiceAccount>(&()),
+            ResourceKind::Role => ApiResource::erase::<Role>(&()),
+            ResourceKind::RoleBinding => ApiResource::erase::<RoleBinding>(&()),
+            ResourceKind::PersistentVolume => ApiResource::erase::<PersistentVolume>(&()),
+            ResourceKind::StorageClass => ApiResource::erase::<StorageClass>(&()),
+            ResourceKind::ClusterRole => ApiResource::erase::<ClusterRole>(&()),
+        }
+    }
+
+    pub fn namespaced(self) -> bool {
+        !matches!(
+            self,
+            ResourceKind::PersistentVolume | ResourceKind::StorageClass | ResourceKind::ClusterRole
+        )
+    }
+
+    /// Where the kind goes when a namespace snapshot is applied, so that config and
+    /// identities exist before the workloads referencing them. `None` for kinds a
+    /// snapshot doesn't hold.
+    pub fn snapshot_order(self) -> Option<u8> {
+        match self {
+            ResourceKind::ServiceAccount | ResourceKind::Role => Some(0),
+            ResourceKind::RoleBinding
+            | ResourceKind::ConfigMap
+            | ResourceKind::Secret
+            | ResourceKind::PersistentVolumeClaim => Some(1),
+            ResourceKind::Service | ResourceKind::NetworkPolicy => Some(2),
+            ResourceKind::Deployment
+            | ResourceKind::StatefulSet
+            | ResourceKind::DaemonSet
+            | ResourceKind::Job
+            | ResourceKind::CronJob => Some(3),
+            ResourceKind::Ingress
+            | ResourceKind::HorizontalPodAutoscaler
+            | ResourceKind::PodDisruptionBudget => Some(4),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_is_case_insensitive() {
+        assert_eq!(
+            ResourceKind::parse("deployment"),
+            Some(ResourceKind::Deployment)
+        );
+        assert_eq!(
+            ResourceKind::parse("HorizontalPodAutoscaler"),
+            Some(ResourceKind::HorizontalPodAutoscaler)
+        );
+        assert_eq!(ResourceKind::parse("Widget"), None);
+    }
+
+    #[test]
+    fn test_scope_and_resource() {
+        let deployment = ResourceKind::parse("Deployment").unwrap();
+        assert!(deployment.namespaced());
+        assert_eq!(deployment.api_resource().api_version, "apps/v1");
+
+        let storage_class = ResourceKind::parse("storageclass").unwrap();
+        assert!(!storage_class.namespaced());
+        assert_eq!(storage_class.api_resource().plural, "storageclasses");
+    }
+
+    #[test]
+    fn test_every_kind_parses_from_its_own_name() {
+        for kind in ResourceKind::ALL {
+            assert_eq!(ResourceKind::parse(&kind.api_resource().kind), Some(kind));
+        }
+    }
+}
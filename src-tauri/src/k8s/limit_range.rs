@@ -0,0 +1,131 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::core::v1::{LimitRange, LimitRangeItem};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::Api;
+use std::collections::{BTreeMap, HashMap};
+use tauri::State;
+
+/// Detailed information about a Kubernetes LimitRange
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LimitRangeDetails {
+    pub name: String,
+    pub namespace: String,
+    pub uid: String,
+    pub created_at: String,
+    pub labels: HashMap<String, String>,
+    pub annotations: HashMap<String, String>,
+    pub limits: Vec<LimitRangeLimit>,
+}
+
+/// Defaults and constraints applied to one object type (Container, Pod or PersistentVolumeClaim).
+/// Each map is keyed by resource name (e.g. "cpu", "memory").
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct LimitRangeLimit {
+    pub limit_type: String,
+    pub default: BTreeMap<String, String>,
+    pub default_request: BTreeMap<String, String>,
+    pub min: BTreeMap<String, String>,
+    pub max: BTreeMap<String, String>,
+    pub max_limit_request_ratio: BTreeMap<String, String>,
+}
+
+fn quantity_map(map: Option<BTreeMap<String, Quantity>>) -> BTreeMap<String, String> {
+    map.unwrap_or_default()
+        .into_iter()
+        .map(|(resource, q)| (resource, q.0))
+        .collect()
+}
+
+fn map_limit_range_item(item: LimitRangeItem) -> LimitRangeLimit {
+    LimitRangeLimit {
+        limit_type: item.type_,
+        default: quantity_map(item.default),
+        default_request: quantity_map(item.default_request),
+        min: quantity_map(item.min),
+        max: quantity_map(item.max),
+        max_limit_request_ratio: quantity_map(item.max_limit_request_ratio),
+    }
+}
+
+/// Get the defaults and min/max constraints a LimitRange injects into a namespace
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_limit_range_details(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<LimitRangeDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let limit_ranges: Api<LimitRange> = Api::namespaced(client, &namespace);
+
+    let limit_range = limit_ranges
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get limit range '{}': {}", name, e))?;
+
+    let meta = limit_range.metadata;
+    let limits = limit_range
+        .spec
+        .map(|s| s.limits.into_iter().map(map_limit_range_item).collect())
+        .unwrap_or_default();
+
+    let created_at = meta
+        .creation_timestamp
+        .map(|t| t.0.to_string())
+        .unwrap_or_default();
+
+    Ok(LimitRangeDetails {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        uid: meta.uid.unwrap_or_default(),
+        created_at,
+        labels: meta.labels.unwrap_or_default().into_iter().collect(),
+        annotations: meta.annotations.unwrap_or_default().into_iter().collect(),
+        limits,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_limit_range_item() {
+        let item = LimitRangeItem {
+            type_: "Container".to_string(),
+            default: Some(BTreeMap::from([(
+                "memory".to_string(),
+                Quantity("512Mi".to_string()),
+            )])),
+            default_request: Some(BTreeMap::from([(
+                "cpu".to_string(),
+                Quantity("100m".to_string()),
+            )])),
+            max_limit_request_ratio: Some(BTreeMap::from([(
+                "cpu".to_string(),
+                Quantity("4".to_string()),
+            )])),
+            ..Default::default()
+        };
+
+        let limit = map_limit_range_item(item);
+
+        assert_eq!(limit.limit_type, "Container");
+        assert_eq!(
+            limit.default.get("memory").map(String::as_str),
+            Some("512Mi")
+        );
+        assert_eq!(
+            limit.default_request.get("cpu").map(String::as_str),
+            Some("100m")
+        );
+        assert_eq!(
+            limit.max_limit_request_ratio.get("cpu").map(String::as_str),
+            Some("4")
+        );
+        assert!(limit.min.is_empty());
+        assert!(limit.max.is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Semaphore;
+use tower::limit::GlobalConcurrencyLimitLayer;
+
+/// Per-cluster cap on in-flight API requests, so a view firing many requests at once
+/// doesn't overwhelm a small control plane. Keyed by cluster id, with the limit each
+/// semaphore was created for. Every client built for a cluster shares its semaphore,
+/// and a request holds a slot until its response headers arrive, so watches and log
+/// streams don't keep one for their lifetime.
+#[derive(Default)]
+pub struct RequestLimiterState(Mutex<HashMap<String, (usize, Arc<Semaphore>)>>);
+
+impl RequestLimiterState {
+    fn semaphore(&self, cluster_id: &str, limit: usize) -> Result<Arc<Semaphore>, String> {
+        let mut semaphores = self
+            .0
+            .lock()
+            .map_err(|e| format!("Request limiter lock poisoned: {}", e))?;
+        let entry = semaphores
+            .entry(cluster_id.to_string())
+            .or_insert_with(|| (limit, Arc::new(Semaphore::new(limit))));
+        // A changed setting applies to clients built afterwards; requests on older
+        // clients drain the old semaphore
+        if entry.0 != limit {
+            *entry = (limit, Arc::new(Semaphore::new(limit)));
+        }
+        Ok(entry.1.clone())
+    }
+
+    /// Layer for a cluster's client that makes each request wait for a slot
+    pub fn layer(
+        &self,
+        cluster_id: &str,
+        limit: usize,
+    ) -> Result<GlobalConcurrencyLimitLayer, String> {
+        Ok(GlobalConcurrencyLimitLayer::with_semaphore(
+            self.semaphore(cluster_id, limit)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_semaphore_per_cluster_and_limit() {
+        let limiter = RequestLimiterState::default();
+
+        let a = limiter.semaphore("a", 2).unwrap();
+        let permit = a.clone().try_acquire_owned().unwrap();
+        assert_eq!(limiter.semaphore("a", 2).unwrap().available_permits(), 1);
+        assert_eq!(limiter.semaphore("b", 2).unwrap().available_permits(), 2);
+
+        let resized = limiter.semaphore("a", 4).unwrap();
+        assert_eq!(resized.available_permits(), 4);
+        assert!(!Arc::ptr_eq(&a, &resized));
+
+        drop(permit);
+        assert_eq!(a.available_permits(), 2);
+    }
+}
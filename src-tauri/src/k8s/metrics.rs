@@ -1,10 +1,18 @@
 use crate::cluster_manager::ClusterManagerState;
 use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{calculate_age, is_not_found};
+use crate::k8s::watcher::{finish_watch, watch_key, WatchRegistration, WatchSpec, WatcherState};
+use futures::StreamExt;
 use k8s_openapi::api::core::v1::{Event, Node, Pod};
-use kube::api::Api;
-use tauri::State;
+use k8s_openapi::jiff::Timestamp;
+use kube::api::{Api, ApiResource, DynamicObject, GroupVersionKind, ListParams};
+use kube::runtime::watcher;
+use kube::Client;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, State, Window};
 
-#[derive(serde::Serialize, Default, Debug)]
+#[derive(serde::Serialize, Default, Debug, Clone)]
 pub struct ResourceStats {
     pub capacity: f64,
     pub allocatable: f64,
@@ -13,31 +21,69 @@ pub struct ResourceStats {
     pub usage: f64,
 }
 
-#[derive(serde::Serialize, Default, Debug)]
+#[derive(serde::Serialize, Default, Debug, Clone)]
 pub struct ClusterMetrics {
     pub cpu: ResourceStats,
     pub memory: ResourceStats,
     pub pods: ResourceStats,
+    /// Whether `cpu.usage`/`memory.usage` come from metrics-server
+    pub metrics_available: bool,
+    /// Why usage is missing when `metrics_available` is false
+    pub metrics_warning: Option<String>,
 }
 
-#[derive(serde::Serialize, Debug)]
+/// An event as listed by `cluster_get_events` and streamed by `cluster_start_event_watch`.
+/// Despite the name it can be a `Normal` event too; see `type_`.
+#[derive(serde::Serialize, Debug, Clone)]
 pub struct WarningEvent {
     pub message: String,
+    /// `kind/name` of the involved object
     pub object: String,
     pub type_: String,
     pub age: String,
     pub count: i32,
+    pub reason: String,
+    /// Namespace of the involved object; `None` for cluster-scoped objects
+    pub namespace: Option<String>,
+    /// UID of the involved object, for linking to it
+    pub object_uid: Option<String>,
 }
 
-fn parse_cpu(q: &str) -> f64 {
+/// Payload of `event_stream`
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct StreamedEvent {
+    pub cluster_id: String,
+    pub event: WarningEvent,
+}
+
+/// Events collapsed by (involved object, reason), like `kubectl get events` series
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct GroupedEvent {
+    pub object: String,
+    pub namespace: String,
+    pub reason: String,
+    pub type_: String,
+    /// Message of the most recent occurrence
+    pub message: String,
+    pub count: i32,
+    pub last_seen: Option<String>,
+    pub age: String,
+}
+
+pub(crate) fn parse_cpu(q: &str) -> f64 {
     if q.ends_with('m') {
         q.trim_end_matches('m').parse::<f64>().unwrap_or(0.0) / 1000.0
+    } else if let Some(val) = q.strip_suffix('u') {
+        // metrics-server reports usage in micro/nanocores
+        val.parse::<f64>().unwrap_or(0.0) / 1_000_000.0
+    } else if let Some(val) = q.strip_suffix('n') {
+        val.parse::<f64>().unwrap_or(0.0) / 1_000_000_000.0
     } else {
         q.parse::<f64>().unwrap_or(0.0)
     }
 }
 
-fn parse_memory(q: &str) -> f64 {
+pub(crate) fn parse_memory(q: &str) -> f64 {
     let q = q.trim();
     if let Some(val) = q.strip_suffix("Ki") {
         val.parse::<f64>().unwrap_or(0.0) * 1024.0
@@ -54,29 +100,54 @@ fn parse_memory(q: &str) -> f64 {
     }
 }
 
+/// Parse a count quantity such as a node's `pods` capacity (`110`). Decimal (`1k`)
+/// and binary (`1Ki`) suffixes are expanded; anything unparseable counts as zero.
+pub(crate) fn parse_count(q: &str) -> f64 {
+    let q = q.trim();
+    let decimal = [("k", 1e3), ("M", 1e6), ("G", 1e9), ("T", 1e12)];
+    match decimal
+        .iter()
+        .find_map(|(suffix, factor)| q.strip_suffix(suffix).map(|val| (val, factor)))
+    {
+        Some((val, factor)) => val.parse::<f64>().unwrap_or(0.0) * factor,
+        None => parse_memory(q),
+    }
+}
+
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_metrics(
     cluster_id: String,
     state: State<'_, ClusterManagerState>,
 ) -> Result<ClusterMetrics, String> {
     let client = create_client_for_cluster(&cluster_id, &state).await?;
+    collect_cluster_metrics(client).await
+}
 
+/// Sum node capacity/allocatable, pod requests/limits, and node usage across the cluster
+async fn collect_cluster_metrics(client: Client) -> Result<ClusterMetrics, String> {
     let nodes: Api<Node> = Api::all(client.clone());
     let pods: Api<Pod> = Api::all(client.clone());
 
-    let node_list = nodes
-        .list(&Default::default())
-        .await
-        .map_err(|e| e.to_string())?;
-    let pod_list = pods
-        .list(&Default::default())
-        .await
-        .map_err(|e| e.to_string())?;
+    let lp = ListParams::default();
+    let (node_list, pod_list, node_metrics) =
+        futures::join!(nodes.list(&lp), pods.list(&lp), fetch_node_metrics(client));
 
+    let mut metrics = sum_cluster_metrics(
+        node_list.map_err(|e| e.to_string())?.items,
+        pod_list.map_err(|e| e.to_string())?.items,
+    );
+    apply_node_usage(&mut metrics, node_metrics);
+
+    Ok(metrics)
+}
+
+/// Sum node capacity/allocatable and the requests/limits of running pods
+fn sum_cluster_metrics(nodes: Vec<Node>, pods: Vec<Pod>) -> ClusterMetrics {
     let mut metrics = ClusterMetrics::default();
 
     // Node Capacity & Allocatable
-    for node in node_list.items {
+    for node in nodes {
         if let Some(status) = node.status {
             if let Some(cap) = status.capacity {
                 if let Some(cpu) = cap.get("cpu") {
@@ -86,7 +157,7 @@ pub async fn cluster_get_metrics(
                     metrics.memory.capacity += parse_memory(&mem.0);
                 }
                 if let Some(p) = cap.get("pods") {
-                    metrics.pods.capacity += parse_cpu(&p.0);
+                    metrics.pods.capacity += parse_count(&p.0);
                 }
             }
             if let Some(alloc) = status.allocatable {
@@ -97,14 +168,14 @@ pub async fn cluster_get_metrics(
                     metrics.memory.allocatable += parse_memory(&mem.0);
                 }
                 if let Some(p) = alloc.get("pods") {
-                    metrics.pods.allocatable += parse_cpu(&p.0);
+                    metrics.pods.allocatable += parse_count(&p.0);
                 }
             }
         }
     }
 
     // Pod Requests & Limits
-    for pod in pod_list.items {
+    for pod in pods {
         // Skip finished pods
         if let Some(status) = &pod.status {
             if let Some(phase) = &status.phase {
@@ -142,10 +213,28 @@ pub async fn cluster_get_metrics(
         }
     }
 
-    Ok(metrics)
+    metrics
+}
+
+/// Fill in actual usage from node metrics. This is best-effort; requests/limits
+/// are still worth showing without it.
+fn apply_node_usage(
+    metrics: &mut ClusterMetrics,
+    node_metrics: Result<HashMap<String, MetricsEntry>, String>,
+) {
+    match node_metrics {
+        Ok(node_metrics) => {
+            let total = total_usage(node_metrics.values());
+            metrics.cpu.usage = total.cpu;
+            metrics.memory.usage = total.memory;
+            metrics.metrics_available = true;
+        }
+        Err(e) => metrics.metrics_warning = Some(e),
+    }
 }
 
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_events(
     cluster_id: String,
     state: State<'_, ClusterManagerState>,
@@ -156,49 +245,914 @@ pub async fn cluster_get_events(
     let lp = kube::api::ListParams::default();
     let event_list = events.list(&lp).await.map_err(|e| e.to_string())?;
 
-    let mut warnings = Vec::new();
-    let now = chrono::Utc::now();
+    Ok(recent_warnings(event_list.items))
+}
+
+/// The 50 most recent `Warning` events
+fn recent_warnings(events: Vec<Event>) -> Vec<WarningEvent> {
+    let warning_type = ["Warning".to_string()];
+    let mut warnings: Vec<WarningEvent> = events
+        .into_iter()
+        .filter(|e| event_type_matches(&warning_type, e))
+        .map(map_event)
+        .collect();
+
+    // Limit to 50 most recent warnings
+    warnings.reverse();
+    warnings.truncate(50);
+
+    warnings
+}
+
+/// Everything the dashboard shows, from one round of API calls
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct DashboardData {
+    pub metrics: ClusterMetrics,
+    pub warnings: Vec<WarningEvent>,
+}
+
+/// `cluster_get_metrics` and `cluster_get_events` in one call: nodes, pods, events
+/// and node usage are fetched concurrently, and pods are listed only once
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_dashboard(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<DashboardData, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let nodes: Api<Node> = Api::all(client.clone());
+    let pods: Api<Pod> = Api::all(client.clone());
+    let events: Api<Event> = Api::all(client.clone());
 
-    for e in event_list.items {
-        if e.type_.as_deref() == Some("Warning") {
-            let age = if let Some(last_ts) = &e.last_timestamp {
-                let last_ts_str = last_ts.0.to_string();
-                let last_ts_parsed = chrono::DateTime::parse_from_rfc3339(&last_ts_str)
-                    .unwrap()
-                    .with_timezone(&chrono::Utc);
-                let duration = now.signed_duration_since(last_ts_parsed);
-                if duration.num_days() > 0 {
-                    format!("{}d", duration.num_days())
-                } else if duration.num_hours() > 0 {
-                    format!("{}h", duration.num_hours())
-                } else if duration.num_minutes() > 0 {
-                    format!("{}m", duration.num_minutes())
-                } else {
-                    format!("{}s", duration.num_seconds())
+    let lp = ListParams::default();
+    let (node_list, pod_list, event_list, node_metrics) = futures::join!(
+        nodes.list(&lp),
+        pods.list(&lp),
+        events.list(&lp),
+        fetch_node_metrics(client)
+    );
+
+    let mut metrics = sum_cluster_metrics(
+        node_list.map_err(|e| e.to_string())?.items,
+        pod_list.map_err(|e| e.to_string())?.items,
+    );
+    apply_node_usage(&mut metrics, node_metrics);
+
+    Ok(DashboardData {
+        metrics,
+        warnings: recent_warnings(event_list.map_err(|e| e.to_string())?.items),
+    })
+}
+
+/// (kind, namespace, name, reason) of an event series
+type EventGroupKey = (String, String, String, String);
+
+/// Most recent time an event was observed, across the legacy and events.k8s.io fields
+fn event_last_seen(e: &Event) -> Option<Timestamp> {
+    e.series
+        .as_ref()
+        .and_then(|s| s.last_observed_time.as_ref().map(|t| t.0))
+        .or_else(|| e.last_timestamp.as_ref().map(|t| t.0))
+        .or_else(|| e.event_time.as_ref().map(|t| t.0))
+        .or_else(|| e.first_timestamp.as_ref().map(|t| t.0))
+        .or_else(|| e.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+fn event_count(e: &Event) -> i32 {
+    e.series
+        .as_ref()
+        .and_then(|s| s.count)
+        .or(e.count)
+        .unwrap_or(1)
+}
+
+fn map_event(e: Event) -> WarningEvent {
+    let last_seen = event_last_seen(&e).map(k8s_openapi::apimachinery::pkg::apis::meta::v1::Time);
+
+    WarningEvent {
+        age: calculate_age(last_seen.as_ref()),
+        count: event_count(&e),
+        message: e.message.unwrap_or_default(),
+        object: format!(
+            "{}/{}",
+            e.involved_object.kind.unwrap_or_default(),
+            e.involved_object.name.unwrap_or_default()
+        ),
+        type_: e.type_.unwrap_or_default(),
+        reason: e.reason.unwrap_or_default(),
+        namespace: e.involved_object.namespace.or(e.metadata.namespace),
+        object_uid: e.involved_object.uid,
+    }
+}
+
+/// Whether an event's type is one of `types` (e.g. `["Warning"]`); an empty
+/// filter matches every event
+fn event_type_matches(types: &[String], e: &Event) -> bool {
+    types.is_empty()
+        || e.type_
+            .as_deref()
+            .is_some_and(|t| types.iter().any(|want| want.eq_ignore_ascii_case(t)))
+}
+
+/// Aggregate events by (involved object, reason), summing counts and keeping the latest
+/// occurrence's message. Sorted by most recent first.
+fn group_events(events: Vec<Event>) -> Vec<GroupedEvent> {
+    let mut groups: HashMap<EventGroupKey, (GroupedEvent, Option<Timestamp>)> = HashMap::new();
+
+    for e in events {
+        let last_seen = event_last_seen(&e);
+        let count = event_count(&e);
+        let involved = &e.involved_object;
+        let kind = involved.kind.clone().unwrap_or_default();
+        let name = involved.name.clone().unwrap_or_default();
+        let namespace = involved
+            .namespace
+            .clone()
+            .or_else(|| e.metadata.namespace.clone())
+            .unwrap_or_default();
+        let reason = e.reason.clone().unwrap_or_default();
+        let key = (
+            kind.clone(),
+            namespace.clone(),
+            name.clone(),
+            reason.clone(),
+        );
+
+        match groups.get_mut(&key) {
+            Some((group, latest)) => {
+                group.count += count;
+                if last_seen > *latest {
+                    *latest = last_seen;
+                    group.message = e.message.unwrap_or_default();
+                    group.type_ = e.type_.unwrap_or_default();
+                }
+            }
+            None => {
+                groups.insert(
+                    key,
+                    (
+                        GroupedEvent {
+                            object: format!("{}/{}", kind, name),
+                            namespace,
+                            reason,
+                            type_: e.type_.unwrap_or_default(),
+                            message: e.message.unwrap_or_default(),
+                            count,
+                            last_seen: None,
+                            age: String::new(),
+                        },
+                        last_seen,
+                    ),
+                );
+            }
+        }
+    }
+
+    let mut grouped: Vec<(GroupedEvent, Option<Timestamp>)> = groups.into_values().collect();
+    grouped.sort_by(|a, b| b.1.cmp(&a.1));
+
+    grouped
+        .into_iter()
+        .map(|(mut group, latest)| {
+            let time = latest.map(k8s_openapi::apimachinery::pkg::apis::meta::v1::Time);
+            group.last_seen = latest.map(|t| t.to_string());
+            group.age = calculate_age(time.as_ref());
+            group
+        })
+        .collect()
+}
+
+/// Events deduplicated by (involved object, reason), most recent first
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_events_grouped(
+    cluster_id: String,
+    namespace: Option<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<GroupedEvent>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let events: Api<Event> = match namespace {
+        Some(ns) => Api::namespaced(client, &ns),
+        None => Api::all(client),
+    };
+
+    let event_list = events
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list events: {}", e))?;
+
+    Ok(group_events(event_list.items))
+}
+
+/// Stream events as they happen, emitting each as `event_stream`. Existing events
+/// are sent first. `types` limits the stream to e.g. `["Warning"]`, and `namespace`
+/// to one namespace instead of the whole cluster.
+#[tauri::command]
+pub async fn cluster_start_event_watch(
+    cluster_id: String,
+    namespace: Option<String>,
+    types: Option<Vec<String>>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), String> {
+    start_event_watch(
+        cluster_id,
+        namespace,
+        types.unwrap_or_default(),
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+/// Key of an event stream: streams with different `types` run side by side, while
+/// the same filter in any order or case restarts the existing one
+fn event_watch_name(cluster_id: &str, namespace: Option<&str>, types: &[String]) -> String {
+    let mut types: Vec<String> = types.iter().map(|t| t.to_lowercase()).collect();
+    types.sort();
+    types.dedup();
+    format!(
+        "event_watch:{}:{}:{}",
+        cluster_id,
+        namespace.unwrap_or("all"),
+        if types.is_empty() {
+            "all".to_string()
+        } else {
+            types.join(",")
+        }
+    )
+}
+
+/// Start (or restart) an event stream for a window
+pub(crate) async fn start_event_watch(
+    cluster_id: String,
+    namespace: Option<String>,
+    types: Vec<String>,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let key = watch_key(
+        window.label(),
+        &event_watch_name(&cluster_id, namespace.as_deref(), &types),
+    );
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let events: Api<Event> = match namespace.as_deref() {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::Events {
+            cluster_id: cluster_id.clone(),
+            namespace,
+            types: types.clone(),
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut stream = watcher(events, watcher::Config::default()).boxed();
+        let mut window_closed = false;
+
+        while let Some(result) = stream.next().await {
+            let event = match result {
+                Ok(watcher::Event::Apply(e)) | Ok(watcher::Event::InitApply(e)) => e,
+                Ok(_) => continue,
+                Err(e) => {
+                    println!("Event watch error: {}", e);
+                    continue;
                 }
-            } else {
-                "-".to_string()
             };
+            if !event_type_matches(&types, &event) {
+                continue;
+            }
 
-            warnings.push(WarningEvent {
-                message: e.message.unwrap_or_default(),
-                object: format!(
-                    "{}/{}",
-                    e.involved_object.kind.unwrap_or_default(),
-                    e.involved_object.name.unwrap_or_default()
-                ),
-                type_: e.type_.unwrap_or_default(),
-                age,
-                count: e.count.unwrap_or(1),
+            let payload = StreamedEvent {
+                cluster_id: cluster_id.clone(),
+                event: map_event(event),
+            };
+            if let Err(e) = window.emit("event_stream", payload) {
+                println!("Failed to emit event: {}", e);
+                window_closed = true;
+                break;
+            }
+        }
+
+        // Cleanup
+        finish_watch(&handles, &registrations, &key_clone, window_closed);
+    });
+
+    // Store new handle
+    watcher_state.insert(key, handle, registration)
+}
+
+// --- Usage from metrics.k8s.io ---
+
+/// Samples older than this are reported but flagged as stale
+const METRICS_STALE_AFTER_SECS: i64 = 300;
+
+/// Actual resource usage reported by metrics-server
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct Usage {
+    pub cpu: f64,
+    pub memory: f64,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NodeUsage {
+    pub name: String,
+    pub usage: Option<Usage>,
+    pub metrics_stale: bool,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PodUsage {
+    pub namespace: String,
+    pub name: String,
+    pub usage: Option<Usage>,
+    pub metrics_stale: bool,
+}
+
+/// Per-node usage keyed by node name
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct NodeUsageReport {
+    /// False when metrics-server isn't installed or failed; `nodes` is then empty
+    pub metrics_available: bool,
+    /// Why metrics are unavailable
+    pub metrics_warning: Option<String>,
+    pub nodes: HashMap<String, NodeUsage>,
+}
+
+/// Per-pod usage keyed by `namespace/name`
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct PodUsageReport {
+    /// False when metrics-server isn't installed or failed; `pods` is then empty
+    pub metrics_available: bool,
+    /// Why metrics are unavailable
+    pub metrics_warning: Option<String>,
+    pub pods: HashMap<String, PodUsage>,
+}
+
+/// A usage sample parsed from a NodeMetrics/PodMetrics object
+#[derive(Debug, Clone, Copy)]
+struct MetricsEntry {
+    usage: Option<Usage>,
+    stale: bool,
+}
+
+impl MetricsEntry {
+    /// Resolve an object's usage; a missing entry means metrics-server has nothing for it
+    fn resolve(entry: Option<&MetricsEntry>) -> (Option<Usage>, bool) {
+        match entry {
+            Some(entry) => (entry.usage, entry.stale || entry.usage.is_none()),
+            None => (None, true),
+        }
+    }
+}
+
+fn metrics_api_resource(kind: &str, plural: &str) -> ApiResource {
+    ApiResource::from_gvk_with_plural(
+        &GroupVersionKind::gvk("metrics.k8s.io", "v1beta1", kind),
+        plural,
+    )
+}
+
+fn parse_usage(usage: &serde_json::Value) -> Option<Usage> {
+    let cpu = usage.get("cpu")?.as_str()?;
+    let memory = usage.get("memory")?.as_str()?;
+    Some(Usage {
+        cpu: parse_cpu(cpu),
+        memory: parse_memory(memory),
+    })
+}
+
+fn is_sample_stale(data: &serde_json::Value, now: chrono::DateTime<chrono::Utc>) -> bool {
+    data.get("timestamp")
+        .and_then(|t| t.as_str())
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| now.signed_duration_since(t).num_seconds() > METRICS_STALE_AFTER_SECS)
+        .unwrap_or(false)
+}
+
+/// Parse a NodeMetrics object
+fn parse_node_metrics(obj: &DynamicObject, now: chrono::DateTime<chrono::Utc>) -> MetricsEntry {
+    MetricsEntry {
+        usage: obj.data.get("usage").and_then(parse_usage),
+        stale: is_sample_stale(&obj.data, now),
+    }
+}
+
+/// Parse a PodMetrics object, summing its containers.
+/// Any container without parseable usage makes the whole pod's usage unknown.
+fn parse_pod_metrics(obj: &DynamicObject, now: chrono::DateTime<chrono::Utc>) -> MetricsEntry {
+    let usage = obj
+        .data
+        .get("containers")
+        .and_then(|c| c.as_array())
+        .and_then(|containers| {
+            containers.iter().try_fold(
+                Usage {
+                    cpu: 0.0,
+                    memory: 0.0,
+                },
+                |total, container| {
+                    let usage = container.get("usage").and_then(parse_usage)?;
+                    Some(Usage {
+                        cpu: total.cpu + usage.cpu,
+                        memory: total.memory + usage.memory,
+                    })
+                },
+            )
+        });
+
+    MetricsEntry {
+        usage,
+        stale: is_sample_stale(&obj.data, now),
+    }
+}
+
+/// Sum the usage of every entry that has one
+fn total_usage<'a>(entries: impl Iterator<Item = &'a MetricsEntry>) -> Usage {
+    entries.filter_map(|entry| entry.usage).fold(
+        Usage {
+            cpu: 0.0,
+            memory: 0.0,
+        },
+        |total, usage| Usage {
+            cpu: total.cpu + usage.cpu,
+            memory: total.memory + usage.memory,
+        },
+    )
+}
+
+/// Why metrics.k8s.io couldn't be listed. Any failure (not installed, or an
+/// unhealthy metrics-server answering 503) only means usage is missing.
+fn metrics_unavailable(e: kube::Error, what: &str) -> String {
+    if is_not_found(&e) {
+        "metrics-server is not installed".to_string()
+    } else {
+        format!("Failed to list {}: {}", what, e)
+    }
+}
+
+/// List NodeMetrics keyed by node name; the error says why metrics are unavailable
+async fn fetch_node_metrics(client: Client) -> Result<HashMap<String, MetricsEntry>, String> {
+    let api: Api<DynamicObject> =
+        Api::all_with(client, &metrics_api_resource("NodeMetrics", "nodes"));
+    let now = chrono::Utc::now();
+
+    match api.list(&ListParams::default()).await {
+        Ok(list) => Ok(list
+            .items
+            .iter()
+            .filter_map(|obj| {
+                let name = obj.metadata.name.clone()?;
+                Some((name, parse_node_metrics(obj, now)))
+            })
+            .collect()),
+        Err(e) => Err(metrics_unavailable(e, "node metrics")),
+    }
+}
+
+/// List PodMetrics keyed by (namespace, name); the error says why metrics are unavailable
+async fn fetch_pod_metrics(
+    client: Client,
+    namespace: Option<&str>,
+) -> Result<HashMap<(String, String), MetricsEntry>, String> {
+    let ar = metrics_api_resource("PodMetrics", "pods");
+    let api: Api<DynamicObject> = match namespace {
+        Some(ns) => Api::namespaced_with(client, ns, &ar),
+        None => Api::all_with(client, &ar),
+    };
+    let now = chrono::Utc::now();
+
+    match api.list(&ListParams::default()).await {
+        Ok(list) => Ok(list
+            .items
+            .iter()
+            .filter_map(|obj| {
+                let key = (obj.metadata.namespace.clone()?, obj.metadata.name.clone()?);
+                Some((key, parse_pod_metrics(obj, now)))
+            })
+            .collect()),
+        Err(e) => Err(metrics_unavailable(e, "pod metrics")),
+    }
+}
+
+/// Per-node usage. Nodes without (fresh) metrics are still listed, flagged `metrics_stale`.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_node_usage(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<NodeUsageReport, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let metrics = match fetch_node_metrics(client.clone()).await {
+        Ok(metrics) => metrics,
+        Err(warning) => {
+            return Ok(NodeUsageReport {
+                metrics_available: false,
+                metrics_warning: Some(warning),
+                nodes: HashMap::new(),
+            })
+        }
+    };
+
+    let nodes: Api<Node> = Api::all(client.clone());
+    let node_list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list nodes: {}", e))?;
+
+    let nodes = node_list
+        .items
+        .into_iter()
+        .map(|node| {
+            let name = node.metadata.name.unwrap_or_default();
+            let (usage, metrics_stale) = MetricsEntry::resolve(metrics.get(&name));
+            let node_usage = NodeUsage {
+                name: name.clone(),
+                usage,
+                metrics_stale,
+            };
+            (name, node_usage)
+        })
+        .collect();
+
+    Ok(NodeUsageReport {
+        metrics_available: true,
+        metrics_warning: None,
+        nodes,
+    })
+}
+
+/// Per-pod usage. Pods without (fresh) metrics are still listed, flagged `metrics_stale`.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_pod_usage(
+    cluster_id: String,
+    namespace: Option<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<PodUsageReport, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let metrics = match fetch_pod_metrics(client.clone(), namespace.as_deref()).await {
+        Ok(metrics) => metrics,
+        Err(warning) => {
+            return Ok(PodUsageReport {
+                metrics_available: false,
+                metrics_warning: Some(warning),
+                pods: HashMap::new(),
+            })
+        }
+    };
+
+    let pods: Api<Pod> = match namespace.as_deref() {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+    let pod_list = pods
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    let pods = pod_list
+        .items
+        .into_iter()
+        .map(|pod| {
+            let key = (
+                pod.metadata.namespace.unwrap_or_default(),
+                pod.metadata.name.unwrap_or_default(),
+            );
+            let (usage, metrics_stale) = MetricsEntry::resolve(metrics.get(&key));
+            let pod_usage = PodUsage {
+                namespace: key.0.clone(),
+                name: key.1.clone(),
+                usage,
+                metrics_stale,
+            };
+            (format!("{}/{}", key.0, key.1), pod_usage)
+        })
+        .collect();
+
+    Ok(PodUsageReport {
+        metrics_available: true,
+        metrics_warning: None,
+        pods,
+    })
+}
+
+/// How many pods `cluster_top_pods` returns by default
+const DEFAULT_TOP_PODS: usize = 10;
+
+/// A pod's total usage across its containers
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct TopPod {
+    pub namespace: String,
+    pub name: String,
+    pub cpu_cores: f64,
+    pub memory_bytes: f64,
+}
+
+/// The busiest pods in the cluster
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct TopPodsReport {
+    /// False when metrics-server isn't installed or failed; `pods` is then empty
+    pub metrics_available: bool,
+    /// Why metrics are unavailable
+    pub metrics_warning: Option<String>,
+    pub pods: Vec<TopPod>,
+}
+
+/// What `cluster_top_pods` ranks pods by
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TopPodsSort {
+    Cpu,
+    Memory,
+}
+
+impl TopPodsSort {
+    /// Parse `sort_by` ("cpu" or "memory", any case)
+    fn parse(sort_by: &str) -> Result<Self, String> {
+        match sort_by.to_lowercase().as_str() {
+            "cpu" => Ok(Self::Cpu),
+            "memory" => Ok(Self::Memory),
+            _ => Err(format!(
+                "Unsupported sort: {}. Use 'cpu' or 'memory'",
+                sort_by
+            )),
+        }
+    }
+
+    fn key(self, pod: &TopPod) -> f64 {
+        match self {
+            Self::Cpu => pod.cpu_cores,
+            Self::Memory => pod.memory_bytes,
+        }
+    }
+}
+
+/// The `limit` pods using the most CPU or memory, skipping pods without usage
+fn top_pods(
+    metrics: HashMap<(String, String), MetricsEntry>,
+    sort: TopPodsSort,
+    limit: usize,
+) -> Vec<TopPod> {
+    let mut pods: Vec<TopPod> = metrics
+        .into_iter()
+        .filter_map(|((namespace, name), entry)| {
+            let usage = entry.usage?;
+            Some(TopPod {
+                namespace,
+                name,
+                cpu_cores: usage.cpu,
+                memory_bytes: usage.memory,
+            })
+        })
+        .collect();
+    pods.sort_by(|a, b| {
+        sort.key(b)
+            .total_cmp(&sort.key(a))
+            .then_with(|| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)))
+    });
+    pods.truncate(limit);
+
+    pods
+}
+
+/// The top `limit` (default 10) pods by CPU or memory usage, for the dashboard's
+/// hot pods panel
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_top_pods(
+    cluster_id: String,
+    sort_by: String,
+    limit: Option<usize>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<TopPodsReport, String> {
+    let limit = limit.unwrap_or(DEFAULT_TOP_PODS);
+    // Reject a bad `sort_by` before going to the cluster
+    let sort = TopPodsSort::parse(&sort_by)?;
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let metrics = match fetch_pod_metrics(client, None).await {
+        Ok(metrics) => metrics,
+        Err(warning) => {
+            return Ok(TopPodsReport {
+                metrics_available: false,
+                metrics_warning: Some(warning),
+                pods: Vec::new(),
+            })
+        }
+    };
+
+    Ok(TopPodsReport {
+        metrics_available: true,
+        metrics_warning: None,
+        pods: top_pods(metrics, sort, limit),
+    })
+}
+
+// --- Metrics history ---
+
+const DEFAULT_HISTORY_LEN: usize = 300;
+const MAX_HISTORY_LEN: usize = 3600;
+const MAX_HISTORY_STREAMS: usize = 16;
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 10;
+
+/// A single metrics sample recorded by a metrics poll stream
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct MetricsSample {
+    pub timestamp: i64,
+    pub metrics: ClusterMetrics,
+}
+
+struct MetricsHistory {
+    capacity: usize,
+    samples: VecDeque<MetricsSample>,
+    /// Value of `MetricsHistoryBuffers::updates` at this stream's latest sample
+    last_updated: u64,
+}
+
+/// Bounded ring buffers of recent samples, one per metrics poll `stream_id`
+#[derive(Default)]
+pub struct MetricsHistoryBuffers {
+    streams: HashMap<String, MetricsHistory>,
+    /// Counts recorded samples, so eviction order doesn't depend on clock resolution
+    updates: u64,
+}
+
+impl MetricsHistoryBuffers {
+    /// Append a sample, dropping the oldest sample once the buffer is full and
+    /// the least recently updated stream once too many streams are tracked
+    pub fn record(&mut self, stream_id: &str, capacity: usize, sample: MetricsSample) {
+        let capacity = capacity.clamp(1, MAX_HISTORY_LEN);
+
+        if !self.streams.contains_key(stream_id) && self.streams.len() >= MAX_HISTORY_STREAMS {
+            let stalest = self
+                .streams
+                .iter()
+                .min_by_key(|(_, history)| history.last_updated)
+                .map(|(id, _)| id.clone());
+            if let Some(id) = stalest {
+                self.streams.remove(&id);
+            }
+        }
+
+        let history = self
+            .streams
+            .entry(stream_id.to_string())
+            .or_insert_with(|| MetricsHistory {
+                capacity,
+                samples: VecDeque::with_capacity(capacity),
+                last_updated: 0,
             });
+
+        history.capacity = capacity;
+        history.samples.push_back(sample);
+        while history.samples.len() > history.capacity {
+            history.samples.pop_front();
         }
+        self.updates += 1;
+        history.last_updated = self.updates;
     }
 
-    // Limit to 50 most recent warnings
-    warnings.reverse();
-    warnings.truncate(50);
+    /// Samples for a stream, oldest first
+    pub fn snapshot(&self, stream_id: &str) -> Vec<MetricsSample> {
+        self.streams
+            .get(stream_id)
+            .map(|history| history.samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+pub struct MetricsHistoryState(pub Arc<Mutex<MetricsHistoryBuffers>>);
+
+impl Default for MetricsHistoryState {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(MetricsHistoryBuffers::default())))
+    }
+}
+
+/// Periodically sample cluster metrics, emitting `metrics_sample_<stream_id>`
+/// and retaining the last `history_len` samples for backfill
+#[tauri::command]
+pub async fn cluster_start_metrics_poll(
+    cluster_id: String,
+    stream_id: String,
+    interval_secs: Option<u64>,
+    history_len: Option<usize>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+    history_state: State<'_, MetricsHistoryState>,
+) -> Result<(), String> {
+    start_metrics_poll(
+        cluster_id,
+        stream_id,
+        interval_secs,
+        history_len,
+        window,
+        &state,
+        &watcher_state,
+        &history_state,
+    )
+    .await
+}
+
+/// Start (or restart) a metrics poll stream for a window
+pub(crate) async fn start_metrics_poll(
+    cluster_id: String,
+    stream_id: String,
+    interval_secs: Option<u64>,
+    history_len: Option<usize>,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+    history_state: &State<'_, MetricsHistoryState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+
+    let interval =
+        std::time::Duration::from_secs(interval_secs.unwrap_or(DEFAULT_POLL_INTERVAL_SECS).max(1));
+    let key = watch_key(window.label(), &format!("metrics_poll:{}", stream_id));
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::MetricsPoll {
+            cluster_id,
+            stream_id: stream_id.clone(),
+            interval_secs,
+            history_len,
+        },
+    };
+
+    let history_len = history_len.unwrap_or(DEFAULT_HISTORY_LEN);
+    let history = history_state.inner().0.clone();
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let event_name = format!("metrics_sample_{}", stream_id);
+        loop {
+            match collect_cluster_metrics(client.clone()).await {
+                Ok(metrics) => {
+                    let sample = MetricsSample {
+                        timestamp: chrono::Utc::now().timestamp(),
+                        metrics,
+                    };
+
+                    if let Ok(mut buffers) = history.lock() {
+                        buffers.record(&stream_id, history_len, sample.clone());
+                    } else {
+                        eprintln!("Warning: metrics history lock poisoned");
+                    }
+
+                    if let Err(e) = window.emit(&event_name, sample) {
+                        println!("Failed to emit metrics sample: {}", e);
+                        break;
+                    }
+                }
+                Err(e) => {
+                    println!("Metrics poll error: {}", e);
+                }
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+
+        // The loop only exits once the window is gone
+        finish_watch(&handles, &registrations, &key_clone, true);
+    });
 
-    Ok(warnings)
+    // Store new handle
+    watcher_state.insert(key, handle, registration)
+}
+
+/// Recent samples recorded for a metrics poll stream, oldest first
+#[tauri::command]
+pub fn cluster_get_metrics_history(
+    stream_id: String,
+    history_state: State<'_, MetricsHistoryState>,
+) -> Result<Vec<MetricsSample>, String> {
+    let buffers = history_state
+        .0
+        .lock()
+        .map_err(|e| format!("Metrics history lock poisoned: {}", e))?;
+    Ok(buffers.snapshot(&stream_id))
 }
 
 #[cfg(test)]
@@ -221,6 +1175,12 @@ mod tests {
         assert_eq!(parse_cpu("0.5"), 0.5);
     }
 
+    #[test]
+    fn test_parse_cpu_nano_and_micro_cores() {
+        assert_eq!(parse_cpu("250000000n"), 0.25);
+        assert_eq!(parse_cpu("500000u"), 0.5);
+    }
+
     #[test]
     fn test_parse_cpu_invalid() {
         assert_eq!(parse_cpu("invalid"), 0.0);
@@ -269,4 +1229,383 @@ mod tests {
         let result = parse_memory("1000m");
         assert_eq!(result, 1.0);
     }
+
+    #[test]
+    fn test_parse_count_plain_integer() {
+        assert_eq!(parse_count("110"), 110.0);
+        assert_eq!(parse_count(" 250 "), 250.0);
+    }
+
+    #[test]
+    fn test_parse_count_suffixes() {
+        assert_eq!(parse_count("1k"), 1000.0);
+        assert_eq!(parse_count("2M"), 2_000_000.0);
+        assert_eq!(parse_count("1Ki"), 1024.0);
+    }
+
+    #[test]
+    fn test_parse_count_invalid() {
+        assert_eq!(parse_count("lots"), 0.0);
+        assert_eq!(parse_count(""), 0.0);
+    }
+
+    #[test]
+    fn test_sum_cluster_metrics_skips_finished_pods() {
+        let node: Node = serde_yaml::from_str(
+            "
+status:
+  capacity: {cpu: '4', memory: 8Gi, pods: '110'}
+  allocatable: {cpu: 3500m, memory: 7Gi, pods: '100'}
+",
+        )
+        .unwrap();
+        let pod = |phase: &str| -> Pod {
+            serde_yaml::from_str(&format!(
+                "
+spec:
+  containers:
+  - name: app
+    resources:
+      requests: {{cpu: 250m, memory: 256Mi}}
+      limits: {{cpu: '1', memory: 512Mi}}
+status:
+  phase: {phase}
+"
+            ))
+            .unwrap()
+        };
+
+        let metrics = sum_cluster_metrics(vec![node], vec![pod("Running"), pod("Succeeded")]);
+
+        assert_eq!(metrics.pods.capacity, 110.0);
+        assert_eq!(metrics.pods.allocatable, 100.0);
+        assert_eq!(metrics.pods.usage, 1.0);
+        assert_eq!(metrics.cpu.allocatable, 3.5);
+        assert_eq!(metrics.cpu.requests, 0.25);
+        assert_eq!(metrics.memory.limits, 512.0 * 1024.0 * 1024.0);
+        assert!(!metrics.metrics_available);
+    }
+
+    // --- Metrics history tests ---
+
+    fn sample(timestamp: i64) -> MetricsSample {
+        MetricsSample {
+            timestamp,
+            metrics: ClusterMetrics::default(),
+        }
+    }
+
+    #[test]
+    fn test_metrics_history_drops_oldest_when_full() {
+        let mut buffers = MetricsHistoryBuffers::default();
+        for ts in 0..5 {
+            buffers.record("dash", 3, sample(ts));
+        }
+
+        let timestamps: Vec<i64> = buffers
+            .snapshot("dash")
+            .iter()
+            .map(|s| s.timestamp)
+            .collect();
+        assert_eq!(timestamps, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_metrics_history_caps_stream_count() {
+        let mut buffers = MetricsHistoryBuffers::default();
+        for i in 0..=MAX_HISTORY_STREAMS {
+            buffers.record(&format!("stream-{}", i), 10, sample(i as i64));
+        }
+
+        assert_eq!(buffers.streams.len(), MAX_HISTORY_STREAMS);
+        // The first (least recently updated) stream is evicted
+        assert!(buffers.snapshot("stream-0").is_empty());
+        assert_eq!(
+            buffers
+                .snapshot(&format!("stream-{}", MAX_HISTORY_STREAMS))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_metrics_history_evicts_least_recently_updated() {
+        let mut buffers = MetricsHistoryBuffers::default();
+        for i in 0..MAX_HISTORY_STREAMS {
+            buffers.record(&format!("stream-{}", i), 10, sample(i as i64));
+        }
+        // A fresh sample keeps stream-0, so stream-1 is now the stalest
+        buffers.record("stream-0", 10, sample(100));
+        buffers.record("new", 10, sample(101));
+
+        assert_eq!(buffers.snapshot("stream-0").len(), 2);
+        assert!(buffers.snapshot("stream-1").is_empty());
+        assert_eq!(buffers.snapshot("new").len(), 1);
+    }
+
+    #[test]
+    fn test_metrics_history_unknown_stream_is_empty() {
+        let buffers = MetricsHistoryBuffers::default();
+        assert!(buffers.snapshot("missing").is_empty());
+    }
+
+    // --- Usage tests ---
+
+    fn pod_metrics(data: serde_json::Value) -> DynamicObject {
+        let mut obj = DynamicObject::new("web", &metrics_api_resource("PodMetrics", "pods"));
+        obj.data = data;
+        obj
+    }
+
+    #[test]
+    fn test_parse_pod_metrics_sums_containers() {
+        let now = chrono::Utc::now();
+        let obj = pod_metrics(serde_json::json!({
+            "timestamp": now.to_rfc3339(),
+            "containers": [
+                { "name": "app", "usage": { "cpu": "100m", "memory": "64Mi" } },
+                { "name": "sidecar", "usage": { "cpu": "50000000n", "memory": "16Mi" } }
+            ]
+        }));
+
+        let entry = parse_pod_metrics(&obj, now);
+        let usage = entry.usage.unwrap();
+        assert!((usage.cpu - 0.15).abs() < 1e-9);
+        assert_eq!(usage.memory, 80.0 * 1024.0 * 1024.0);
+        assert!(!entry.stale);
+    }
+
+    #[test]
+    fn test_parse_pod_metrics_partial_container_is_unknown() {
+        let now = chrono::Utc::now();
+        let obj = pod_metrics(serde_json::json!({
+            "containers": [
+                { "name": "app", "usage": { "cpu": "100m", "memory": "64Mi" } },
+                { "name": "sidecar" }
+            ]
+        }));
+
+        let (usage, stale) = MetricsEntry::resolve(Some(&parse_pod_metrics(&obj, now)));
+        assert_eq!(usage, None);
+        assert!(stale);
+    }
+
+    #[test]
+    fn test_old_metrics_sample_is_stale() {
+        let now = chrono::Utc::now();
+        let old = now - chrono::Duration::seconds(METRICS_STALE_AFTER_SECS + 60);
+        let obj = pod_metrics(serde_json::json!({
+            "timestamp": old.to_rfc3339(),
+            "containers": [{ "name": "app", "usage": { "cpu": "1", "memory": "1Gi" } }]
+        }));
+
+        let (usage, stale) = MetricsEntry::resolve(Some(&parse_pod_metrics(&obj, now)));
+        assert!(usage.is_some());
+        assert!(stale);
+    }
+
+    #[test]
+    fn test_total_usage_skips_unknown() {
+        let entries = [
+            MetricsEntry {
+                usage: Some(Usage {
+                    cpu: 0.5,
+                    memory: 1024.0,
+                }),
+                stale: false,
+            },
+            MetricsEntry {
+                usage: None,
+                stale: true,
+            },
+            MetricsEntry {
+                usage: Some(Usage {
+                    cpu: 1.5,
+                    memory: 2048.0,
+                }),
+                stale: false,
+            },
+        ];
+
+        assert_eq!(
+            total_usage(entries.iter()),
+            Usage {
+                cpu: 2.0,
+                memory: 3072.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_metrics_entry_is_stale() {
+        let (usage, stale) = MetricsEntry::resolve(None);
+        assert_eq!(usage, None);
+        assert!(stale);
+    }
+
+    #[test]
+    fn test_top_pods_sorts_and_limits() {
+        let entry = |cpu: f64, memory: f64| MetricsEntry {
+            usage: Some(Usage { cpu, memory }),
+            stale: false,
+        };
+        let metrics = || {
+            HashMap::from([
+                (
+                    ("default".to_string(), "web".to_string()),
+                    entry(0.5, 100.0),
+                ),
+                (("default".to_string(), "api".to_string()), entry(2.0, 50.0)),
+                (("jobs".to_string(), "batch".to_string()), entry(1.0, 300.0)),
+                (
+                    ("jobs".to_string(), "pending".to_string()),
+                    MetricsEntry {
+                        usage: None,
+                        stale: true,
+                    },
+                ),
+            ])
+        };
+        let names = |pods: Vec<TopPod>| pods.into_iter().map(|p| p.name).collect::<Vec<_>>();
+
+        assert_eq!(
+            names(top_pods(metrics(), TopPodsSort::Cpu, 10)),
+            vec!["api", "batch", "web"]
+        );
+        assert_eq!(
+            names(top_pods(metrics(), TopPodsSort::Memory, 2)),
+            vec!["batch", "web"]
+        );
+    }
+
+    #[test]
+    fn test_top_pods_sort_parse() {
+        assert_eq!(TopPodsSort::parse("cpu"), Ok(TopPodsSort::Cpu));
+        assert_eq!(TopPodsSort::parse("Memory"), Ok(TopPodsSort::Memory));
+        assert!(TopPodsSort::parse("disk").is_err());
+    }
+
+    // --- Grouped events tests ---
+
+    fn mock_event(name: &str, reason: &str, message: &str, count: i32, ts: &str) -> Event {
+        use k8s_openapi::api::core::v1::ObjectReference;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+        Event {
+            involved_object: ObjectReference {
+                kind: Some("Pod".to_string()),
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            reason: Some(reason.to_string()),
+            message: Some(message.to_string()),
+            type_: Some("Warning".to_string()),
+            count: Some(count),
+            last_timestamp: Some(Time(ts.parse().unwrap())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_group_events_sums_counts_and_keeps_latest_message() {
+        let events = vec![
+            mock_event("web", "BackOff", "old", 3, "2024-01-01T00:00:00Z"),
+            mock_event("web", "BackOff", "new", 2, "2024-01-01T00:05:00Z"),
+            mock_event("api", "BackOff", "other", 1, "2024-01-01T00:01:00Z"),
+        ];
+
+        let grouped = group_events(events);
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].object, "Pod/web");
+        assert_eq!(grouped[0].count, 5);
+        assert_eq!(grouped[0].message, "new");
+        assert_eq!(grouped[1].object, "Pod/api");
+    }
+
+    #[test]
+    fn test_event_type_filter() {
+        let mut normal = mock_event("web", "Pulled", "pulled image", 1, "2024-01-01T00:00:00Z");
+        normal.type_ = Some("Normal".to_string());
+        let warning = mock_event("web", "BackOff", "back-off", 1, "2024-01-01T00:00:00Z");
+
+        let only_warnings = ["warning".to_string()];
+        assert!(!event_type_matches(&only_warnings, &normal));
+        assert!(event_type_matches(&only_warnings, &warning));
+        assert!(event_type_matches(&[], &normal));
+
+        let mapped = map_event(normal);
+        assert_eq!(mapped.object, "Pod/web");
+        assert_eq!(mapped.type_, "Normal");
+        assert_eq!(mapped.message, "pulled image");
+        assert_eq!(mapped.reason, "Pulled");
+        assert_eq!(mapped.namespace.as_deref(), Some("default"));
+    }
+
+    #[test]
+    fn test_event_watch_name_includes_types() {
+        assert_eq!(event_watch_name("c1", None, &[]), "event_watch:c1:all:all");
+        assert_eq!(
+            event_watch_name("c1", Some("default"), &["Warning".to_string()]),
+            "event_watch:c1:default:warning"
+        );
+        assert_eq!(
+            event_watch_name("c1", None, &["warning".to_string(), "Normal".to_string()]),
+            event_watch_name("c1", None, &["Normal".to_string(), "Warning".to_string()])
+        );
+        assert_ne!(
+            event_watch_name("c1", None, &["Warning".to_string()]),
+            event_watch_name("c1", None, &[])
+        );
+    }
+
+    #[test]
+    fn test_group_events_separates_reasons() {
+        let events = vec![
+            mock_event("web", "BackOff", "a", 1, "2024-01-01T00:00:00Z"),
+            mock_event("web", "Unhealthy", "b", 1, "2024-01-01T00:00:00Z"),
+        ];
+
+        assert_eq!(group_events(events).len(), 2);
+    }
+
+    fn api_error(code: u16, reason: &str) -> kube::Error {
+        kube::Error::Api(
+            serde_json::from_value(serde_json::json!({
+                "status": "Failure",
+                "message": reason,
+                "reason": reason,
+                "code": code,
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_metrics_unavailable_reason() {
+        assert_eq!(
+            metrics_unavailable(api_error(404, "NotFound"), "node metrics"),
+            "metrics-server is not installed"
+        );
+        assert!(
+            metrics_unavailable(api_error(503, "ServiceUnavailable"), "node metrics")
+                .starts_with("Failed to list node metrics")
+        );
+    }
+
+    #[test]
+    fn test_apply_node_usage_degrades_on_error() {
+        let mut metrics = ClusterMetrics::default();
+        metrics.cpu.requests = 1.5;
+        apply_node_usage(&mut metrics, Err("Failed to list node metrics".to_string()));
+
+        assert!(!metrics.metrics_available);
+        assert_eq!(
+            metrics.metrics_warning.as_deref(),
+            Some("Failed to list node metrics")
+        );
+        assert_eq!(metrics.cpu.requests, 1.5);
+        assert_eq!(metrics.cpu.usage, 0.0);
+    }
 }
@@ -1,16 +1,65 @@
+pub mod bundle;
 pub mod client;
 pub mod common;
+pub mod compare;
+pub mod config_consumers;
+pub mod configmap;
+pub mod cron;
 pub mod deployment;
+pub mod deprecation;
+pub mod failure_logs;
+pub mod hpa;
+pub mod job;
+pub mod kind;
+pub mod limit_range;
+pub mod limiter;
 pub mod metrics;
+pub mod namespace;
+pub mod network_policy;
+pub mod node;
 pub mod pod;
+pub mod pvc;
+pub mod raw;
+pub mod rbac;
+pub mod resource_quota;
+pub mod scheduling;
+pub mod secret;
+pub mod service;
+pub mod snapshot;
 pub mod statefulset;
+pub mod stats;
 pub mod watcher;
+pub mod webhook;
 pub mod workload;
 
+pub use bundle::*;
 pub use client::*;
+pub use compare::*;
+pub use config_consumers::*;
+pub use configmap::*;
 pub use deployment::*;
+pub use deprecation::*;
+pub use failure_logs::*;
+pub use hpa::*;
+pub use job::*;
+pub use kind::*;
+pub use limit_range::*;
+pub use limiter::*;
 pub use metrics::*;
+pub use namespace::*;
+pub use network_policy::*;
+pub use node::*;
 pub use pod::*;
+pub use pvc::*;
+pub use raw::*;
+pub use rbac::*;
+pub use resource_quota::*;
+pub use scheduling::*;
+pub use secret::*;
+pub use service::*;
+pub use snapshot::*;
 pub use statefulset::*;
+pub use stats::*;
 pub use watcher::*;
+pub use webhook::*;
 pub use workload::*;
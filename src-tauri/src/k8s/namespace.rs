@@ -0,0 +1,218 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{calculate_age, get_created_at};
+use k8s_openapi::api::core::v1::Namespace;
+use kube::api::{Api, DeleteParams, ListParams, ObjectMeta, PostParams};
+use std::collections::{BTreeMap, HashMap};
+use tauri::State;
+
+/// A namespace with its lifecycle status, for spotting ones stuck in `Terminating`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct NamespaceInfo {
+    pub name: String,
+    /// `status.phase`: `Active` or `Terminating`
+    pub status: String,
+    pub age: String,
+    pub created_at: i64,
+    pub labels: BTreeMap<String, String>,
+}
+
+fn map_namespace_to_info(ns: Namespace) -> NamespaceInfo {
+    let meta = ns.metadata;
+
+    NamespaceInfo {
+        name: meta.name.unwrap_or_default(),
+        status: ns
+            .status
+            .and_then(|s| s.phase)
+            .unwrap_or_else(|| "Unknown".to_string()),
+        age: calculate_age(meta.creation_timestamp.as_ref()),
+        created_at: get_created_at(meta.creation_timestamp.as_ref()),
+        labels: meta.labels.unwrap_or_default(),
+    }
+}
+
+/// List namespaces with status, age and labels; `cluster_list_namespaces` still
+/// returns just the names
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_list_namespace_details(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<NamespaceInfo>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let ns_api: Api<Namespace> = Api::all(client);
+
+    let list = ns_api
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list namespaces: {}", e))?;
+
+    Ok(list.items.into_iter().map(map_namespace_to_info).collect())
+}
+
+/// Namespaces the cluster depends on; deleting them needs `force`
+const PROTECTED_NAMESPACES: [&str; 3] = ["kube-system", "kube-public", "default"];
+
+fn check_deletable(name: &str, force: bool) -> Result<(), String> {
+    if PROTECTED_NAMESPACES.contains(&name) && !force {
+        return Err(format!(
+            "Namespace '{}' is protected; pass force to delete it",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Create a namespace with the given labels and return its name
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_create_namespace(
+    cluster_id: String,
+    name: String,
+    labels: HashMap<String, String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let ns_api: Api<Namespace> = Api::all(client);
+
+    let ns = Namespace {
+        metadata: ObjectMeta {
+            name: Some(name.clone()),
+            labels: (!labels.is_empty()).then(|| labels.into_iter().collect()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let created = ns_api
+        .create(&PostParams::default(), &ns)
+        .await
+        .map_err(|e| format!("Failed to create namespace '{}': {}", name, e))?;
+
+    Ok(created.metadata.name.unwrap_or(name))
+}
+
+/// Delete a namespace and everything in it. `kube-system`, `kube-public` and
+/// `default` are refused unless `force` is set.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_delete_namespace(
+    cluster_id: String,
+    name: String,
+    force: Option<bool>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), String> {
+    check_deletable(&name, force.unwrap_or(false))?;
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let ns_api: Api<Namespace> = Api::all(client);
+
+    ns_api
+        .delete(&name, &DeleteParams::default())
+        .await
+        .map_err(|e| format!("Failed to delete namespace '{}': {}", name, e))?;
+
+    Ok(())
+}
+
+/// Destructive namespace operations must be confirmed by typing the namespace name
+fn check_confirm_token(name: &str, confirm_token: &str) -> Result<(), String> {
+    if confirm_token != name {
+        return Err(format!(
+            "Confirmation token does not match namespace '{}'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Clear a Terminating namespace's `spec.finalizers` through the `/finalize` subresource.
+/// This bypasses the cleanup those finalizers guard. Returns the resulting phase.
+#[tauri::command]
+#[tracing::instrument(skip(state, confirm_token))]
+pub async fn cluster_force_finalize_namespace(
+    cluster_id: String,
+    name: String,
+    confirm_token: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    check_confirm_token(&name, &confirm_token)?;
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let ns_api: Api<Namespace> = Api::all(client);
+
+    let mut ns = ns_api
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get namespace '{}': {}", name, e))?;
+
+    let phase = ns.status.as_ref().and_then(|s| s.phase.clone());
+    if phase.as_deref() != Some("Terminating") {
+        return Err(format!(
+            "Namespace '{}' is not Terminating (phase: {})",
+            name,
+            phase.as_deref().unwrap_or("Unknown")
+        ));
+    }
+
+    if let Some(spec) = ns.spec.as_mut() {
+        spec.finalizers = Some(vec![]);
+    }
+    let body =
+        serde_json::to_vec(&ns).map_err(|e| format!("Failed to serialize namespace: {}", e))?;
+
+    let updated = ns_api
+        .replace_subresource("finalize", &name, &PostParams::default(), body)
+        .await
+        .map_err(|e| format!("Failed to finalize namespace '{}': {}", name, e))?;
+
+    Ok(updated
+        .status
+        .and_then(|s| s.phase)
+        .unwrap_or_else(|| "Unknown".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::NamespaceStatus;
+
+    #[test]
+    fn test_map_namespace_to_info() {
+        let ns = Namespace {
+            metadata: ObjectMeta {
+                name: Some("team-a".to_string()),
+                labels: Some(BTreeMap::from([("env".to_string(), "prod".to_string())])),
+                ..Default::default()
+            },
+            status: Some(NamespaceStatus {
+                phase: Some("Terminating".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let info = map_namespace_to_info(ns);
+
+        assert_eq!(info.name, "team-a");
+        assert_eq!(info.status, "Terminating");
+        assert_eq!(info.age, "-");
+        assert_eq!(info.labels.get("env").map(String::as_str), Some("prod"));
+    }
+
+    #[test]
+    fn test_check_deletable_protects_system_namespaces() {
+        assert!(check_deletable("team-a", false).is_ok());
+        assert!(check_deletable("kube-system", false).is_err());
+        assert!(check_deletable("default", false).is_err());
+        assert!(check_deletable("kube-public", true).is_ok());
+    }
+
+    #[test]
+    fn test_check_confirm_token() {
+        assert!(check_confirm_token("team-a", "team-a").is_ok());
+        assert!(check_confirm_token("team-a", "team-b").is_err());
+        assert!(check_confirm_token("team-a", "").is_err());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{format_label_selector, label_selector_matches};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{Api, ListParams};
+use std::collections::BTreeMap;
+use tauri::State;
+
+/// One ingress or egress rule: traffic from/to any of `peers` on any of `ports`.
+/// Empty `peers` means every peer, empty `ports` means every port.
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct NetworkPolicyRuleInfo {
+    pub peers: Vec<String>,
+    pub ports: Vec<String>,
+}
+
+/// A NetworkPolicy whose podSelector matches the pod
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct AppliedNetworkPolicy {
+    pub name: String,
+    pub pod_selector: String,
+    pub policy_types: Vec<String>,
+    pub ingress: Vec<NetworkPolicyRuleInfo>,
+    pub egress: Vec<NetworkPolicyRuleInfo>,
+}
+
+/// The NetworkPolicies that apply to a pod and the traffic they allow in total.
+/// Traffic in a direction is denied unless some rule allows it once any policy
+/// isolates the pod in that direction; without isolation, everything is allowed.
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct PodNetworkPolicies {
+    pub pod_name: String,
+    pub namespace: String,
+    pub pod_labels: BTreeMap<String, String>,
+    pub ingress_isolated: bool,
+    pub egress_isolated: bool,
+    pub policies: Vec<AppliedNetworkPolicy>,
+    /// Union of the ingress rules of every selecting policy
+    pub ingress_rules: Vec<NetworkPolicyRuleInfo>,
+    /// Union of the egress rules of every selecting policy
+    pub egress_rules: Vec<NetworkPolicyRuleInfo>,
+}
+
+fn format_peer(peer: &NetworkPolicyPeer) -> String {
+    if let Some(block) = &peer.ip_block {
+        let except = block.except.as_deref().unwrap_or_default();
+        return if except.is_empty() {
+            format!("ipBlock {}", block.cidr)
+        } else {
+            format!("ipBlock {} except {}", block.cidr, except.join(", "))
+        };
+    }
+
+    let pods = peer
+        .pod_selector
+        .as_ref()
+        .map(|s| format!("pods {}", format_label_selector(s)));
+    let namespaces = peer
+        .namespace_selector
+        .as_ref()
+        .map(|s| format!("namespaces {}", format_label_selector(s)));
+
+    match (pods, namespaces) {
+        (Some(pods), Some(namespaces)) => format!("{} in {}", pods, namespaces),
+        (Some(pods), None) => format!("{} in this namespace", pods),
+        (None, Some(namespaces)) => format!("all pods in {}", namespaces),
+        (None, None) => "all".to_string(),
+    }
+}
+
+fn format_port(port: &NetworkPolicyPort) -> String {
+    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+    let number = match &port.port {
+        Some(IntOrString::Int(n)) => n.to_string(),
+        Some(IntOrString::String(name)) => name.clone(),
+        None => return format!("{}/all", protocol),
+    };
+    match port.end_port {
+        Some(end) => format!("{}/{}-{}", protocol, number, end),
+        None => format!("{}/{}", protocol, number),
+    }
+}
+
+fn rule_info(
+    peers: Option<&Vec<NetworkPolicyPeer>>,
+    ports: Option<&Vec<NetworkPolicyPort>>,
+) -> NetworkPolicyRuleInfo {
+    NetworkPolicyRuleInfo {
+        peers: peers.into_iter().flatten().map(format_peer).collect(),
+        ports: ports.into_iter().flatten().map(format_port).collect(),
+    }
+}
+
+/// Map a policy that selects the pod. Policies without explicit policyTypes always
+/// cover ingress, and cover egress only when they have egress rules.
+fn applied_policy(policy: &NetworkPolicy) -> AppliedNetworkPolicy {
+    let spec = policy.spec.clone().unwrap_or_default();
+
+    let policy_types = spec.policy_types.clone().unwrap_or_else(|| {
+        let mut types = vec!["Ingress".to_string()];
+        if spec.egress.is_some() {
+            types.push("Egress".to_string());
+        }
+        types
+    });
+
+    AppliedNetworkPolicy {
+        name: policy.metadata.name.clone().unwrap_or_default(),
+        pod_selector: format_label_selector(&spec.pod_selector.clone().unwrap_or_default()),
+        policy_types,
+        ingress: spec
+            .ingress
+            .iter()
+            .flatten()
+            .map(|r| rule_info(r.from.as_ref(), r.ports.as_ref()))
+            .collect(),
+        egress: spec
+            .egress
+            .iter()
+            .flatten()
+            .map(|r| rule_info(r.to.as_ref(), r.ports.as_ref()))
+            .collect(),
+    }
+}
+
+fn evaluate_policies(
+    pod_name: String,
+    namespace: String,
+    pod_labels: BTreeMap<String, String>,
+    policies: &[NetworkPolicy],
+) -> PodNetworkPolicies {
+    let applied: Vec<AppliedNetworkPolicy> = policies
+        .iter()
+        .filter(|p| {
+            let selector = p
+                .spec
+                .as_ref()
+                .and_then(|s| s.pod_selector.clone())
+                .unwrap_or_default();
+            label_selector_matches(&selector, &pod_labels)
+        })
+        .map(applied_policy)
+        .collect();
+
+    let isolates = |direction: &str| {
+        applied
+            .iter()
+            .any(|p| p.policy_types.iter().any(|t| t == direction))
+    };
+
+    PodNetworkPolicies {
+        ingress_isolated: isolates("Ingress"),
+        egress_isolated: isolates("Egress"),
+        ingress_rules: applied.iter().flat_map(|p| p.ingress.clone()).collect(),
+        egress_rules: applied.iter().flat_map(|p| p.egress.clone()).collect(),
+        policies: applied,
+        pod_name,
+        namespace,
+        pod_labels,
+    }
+}
+
+/// NetworkPolicies in the pod's namespace whose podSelector matches it, with their
+/// combined ingress and egress rules
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_pod_network_policies(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<PodNetworkPolicies, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = pods
+        .get(&pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod '{}': {}", pod_name, e))?;
+
+    let policies_api: Api<NetworkPolicy> = Api::namespaced(client, &namespace);
+    let policies = policies_api
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list network policies: {}", e))?;
+
+    Ok(evaluate_policies(
+        pod_name,
+        namespace,
+        pod.metadata.labels.unwrap_or_default(),
+        &policies.items,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(yaml: &str) -> NetworkPolicy {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn labels(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_policies_selects_matching_pods() {
+        let policies = vec![
+            policy(
+                r#"
+metadata:
+  name: allow-frontend
+spec:
+  podSelector:
+    matchLabels:
+      app: api
+  ingress:
+  - from:
+    - podSelector:
+        matchLabels:
+          app: web
+    ports:
+    - protocol: TCP
+      port: 8080
+"#,
+            ),
+            policy(
+                r#"
+metadata:
+  name: db-only
+spec:
+  podSelector:
+    matchExpressions:
+    - key: app
+      operator: In
+      values: [db]
+  policyTypes: [Ingress, Egress]
+"#,
+            ),
+        ];
+
+        let result = evaluate_policies(
+            "api-0".to_string(),
+            "default".to_string(),
+            labels(&[("app", "api")]),
+            &policies,
+        );
+
+        assert_eq!(result.policies.len(), 1);
+        assert_eq!(result.policies[0].name, "allow-frontend");
+        assert!(result.ingress_isolated);
+        assert!(!result.egress_isolated);
+        assert_eq!(
+            result.ingress_rules,
+            vec![NetworkPolicyRuleInfo {
+                peers: vec!["pods app=web in this namespace".to_string()],
+                ports: vec!["TCP/8080".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_empty_selector_denies_all_egress() {
+        let policies = vec![policy(
+            r#"
+metadata:
+  name: default-deny-egress
+spec:
+  podSelector: {}
+  policyTypes: [Egress]
+"#,
+        )];
+
+        let result = evaluate_policies(
+            "anything".to_string(),
+            "default".to_string(),
+            labels(&[("app", "x")]),
+            &policies,
+        );
+
+        assert!(result.egress_isolated);
+        assert!(!result.ingress_isolated);
+        assert!(result.egress_rules.is_empty());
+        assert_eq!(result.policies[0].pod_selector, "<all>");
+    }
+
+    #[test]
+    fn test_format_peer_ip_block() {
+        let peer: NetworkPolicyPeer =
+            serde_yaml::from_str("ipBlock:\n  cidr: 10.0.0.0/8\n  except: [10.1.0.0/16]\n")
+                .unwrap();
+
+        assert_eq!(format_peer(&peer), "ipBlock 10.0.0.0/8 except 10.1.0.0/16");
+    }
+}
@@ -0,0 +1,549 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::calculate_age;
+use crate::k8s::common::is_not_found;
+use crate::settings::load_settings;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::api::{Api, EvictParams, ListParams, Patch, PatchParams};
+use kube::Client;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, State, Window};
+
+const NODE_ROLE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
+/// Set by the kubelet on the API copy of a static pod
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 300;
+const EVICTION_RETRY_INITIAL: Duration = Duration::from_secs(1);
+const EVICTION_RETRY_MAX: Duration = Duration::from_secs(16);
+
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct NodeSummary {
+    pub name: String,
+    /// Like `kubectl get nodes`: "Ready", "NotReady" or "Unknown", with
+    /// ",SchedulingDisabled" appended for cordoned nodes
+    pub status: String,
+    pub roles: Vec<String>,
+    pub kubelet_version: String,
+    pub os_image: String,
+    pub cpu_capacity: String,
+    pub memory_capacity: String,
+    pub age: String,
+    /// As `key=value:effect`, or `key:effect` for taints without a value
+    pub taints: Vec<String>,
+    pub internal_ip: Option<String>,
+}
+
+fn node_status(node: &Node) -> String {
+    let ready = node
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|c| c.type_ == "Ready"));
+    let mut status = match ready.map(|c| c.status.as_str()) {
+        Some("True") => "Ready".to_string(),
+        Some("False") => "NotReady".to_string(),
+        _ => "Unknown".to_string(),
+    };
+
+    if node.spec.as_ref().and_then(|s| s.unschedulable) == Some(true) {
+        status.push_str(",SchedulingDisabled");
+    }
+    status
+}
+
+fn node_roles(node: &Node) -> Vec<String> {
+    let mut roles: Vec<String> = node
+        .metadata
+        .labels
+        .iter()
+        .flatten()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(NODE_ROLE_LABEL_PREFIX)
+                .filter(|role| !role.is_empty())
+                .map(str::to_string)
+                // Older clusters label the role as `kubernetes.io/role=<role>`
+                .or_else(|| (key == "kubernetes.io/role").then(|| value.clone()))
+        })
+        .collect();
+    roles.sort();
+    roles.dedup();
+    roles
+}
+
+fn node_taints(node: &Node) -> Vec<String> {
+    node.spec
+        .as_ref()
+        .and_then(|s| s.taints.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|taint| match taint.value.as_deref() {
+            Some(value) if !value.is_empty() => {
+                format!("{}={}:{}", taint.key, value, taint.effect)
+            }
+            _ => format!("{}:{}", taint.key, taint.effect),
+        })
+        .collect()
+}
+
+fn summarize_node(node: &Node) -> NodeSummary {
+    let status = node.status.as_ref();
+    let node_info = status.and_then(|s| s.node_info.as_ref());
+    let capacity = |resource: &str| {
+        status
+            .and_then(|s| s.capacity.as_ref())
+            .and_then(|c| c.get(resource))
+            .map(|q| q.0.clone())
+            .unwrap_or_default()
+    };
+
+    NodeSummary {
+        name: node.metadata.name.clone().unwrap_or_default(),
+        status: node_status(node),
+        roles: node_roles(node),
+        kubelet_version: node_info
+            .map(|i| i.kubelet_version.clone())
+            .unwrap_or_default(),
+        os_image: node_info.map(|i| i.os_image.clone()).unwrap_or_default(),
+        cpu_capacity: capacity("cpu"),
+        memory_capacity: capacity("memory"),
+        age: calculate_age(node.metadata.creation_timestamp.as_ref()),
+        taints: node_taints(node),
+        internal_ip: status
+            .and_then(|s| s.addresses.as_ref())
+            .and_then(|addresses| addresses.iter().find(|a| a.type_ == "InternalIP"))
+            .map(|a| a.address.clone()),
+    }
+}
+
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_list_nodes(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<NodeSummary>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let nodes: Api<Node> = Api::all(client);
+
+    let list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list nodes: {}", e))?;
+
+    Ok(list.items.iter().map(summarize_node).collect())
+}
+
+async fn set_unschedulable(
+    client: Client,
+    node_name: &str,
+    unschedulable: bool,
+) -> Result<(), String> {
+    let nodes: Api<Node> = Api::all(client);
+    let patch = serde_json::json!({ "spec": { "unschedulable": unschedulable } });
+    nodes
+        .patch(node_name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| format!("Failed to update node '{}': {}", node_name, e))?;
+    Ok(())
+}
+
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_cordon_node(
+    cluster_id: String,
+    node_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    set_unschedulable(client, &node_name, true).await
+}
+
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_uncordon_node(
+    cluster_id: String,
+    node_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    set_unschedulable(client, &node_name, false).await
+}
+
+/// Emitted as `drain_progress_<node>` after each pod is evicted
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct DrainProgress {
+    pub pod_name: String,
+    pub namespace: String,
+    pub evicted: usize,
+    pub total: usize,
+}
+
+/// Event names only allow alphanumerics and `-/:_`, but node names are DNS subdomains
+fn drain_event_name(node_name: &str) -> String {
+    format!("drain_progress_{}", node_name.replace('.', "_"))
+}
+
+/// Pods a drain should evict: everything except DaemonSet pods (the controller
+/// ignores unschedulable and would recreate them) and mirror pods (owned by the kubelet).
+/// Like `kubectl drain`, running pods without a controller are refused unless `force`
+/// (nothing recreates them) and pods with emptyDir volumes unless `delete_emptydir_data`
+/// (their data is lost); the error lists every pod that blocks the drain.
+fn drain_candidates(
+    pods: Vec<Pod>,
+    force: bool,
+    delete_emptydir_data: bool,
+) -> Result<Vec<Pod>, String> {
+    let mut blocked = Vec::new();
+    let candidates: Vec<Pod> = pods
+        .into_iter()
+        .filter(|pod| {
+            let daemonset_owned = pod
+                .metadata
+                .owner_references
+                .iter()
+                .flatten()
+                .any(|r| r.controller == Some(true) && r.kind == "DaemonSet");
+            let mirror = pod
+                .metadata
+                .annotations
+                .as_ref()
+                .is_some_and(|a| a.contains_key(MIRROR_POD_ANNOTATION));
+            !daemonset_owned && !mirror
+        })
+        .collect();
+
+    for pod in &candidates {
+        let name = format!(
+            "{}/{}",
+            pod.metadata.namespace.as_deref().unwrap_or_default(),
+            pod.metadata.name.as_deref().unwrap_or_default()
+        );
+        let finished = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.as_deref())
+            .is_some_and(|phase| phase == "Succeeded" || phase == "Failed");
+        let has_controller = pod
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .any(|r| r.controller == Some(true));
+        if !force && !finished && !has_controller {
+            blocked.push(format!(
+                "{} is not managed by a controller (use force)",
+                name
+            ));
+        }
+        let has_empty_dir = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.volumes.as_ref())
+            .is_some_and(|v| v.iter().any(|v| v.empty_dir.is_some()));
+        if !delete_emptydir_data && has_empty_dir {
+            blocked.push(format!(
+                "{} uses emptyDir volumes (use delete_emptydir_data)",
+                name
+            ));
+        }
+    }
+
+    if blocked.is_empty() {
+        Ok(candidates)
+    } else {
+        Err(format!("Cannot drain node: {}", blocked.join("; ")))
+    }
+}
+
+/// Evict a pod, retrying with backoff while a PodDisruptionBudget blocks it (429)
+async fn evict_with_retry(
+    pods: &Api<Pod>,
+    pod_name: &str,
+    params: &EvictParams,
+    deadline: Instant,
+) -> Result<(), String> {
+    let mut delay = EVICTION_RETRY_INITIAL;
+    loop {
+        match pods.evict(pod_name, params).await {
+            Ok(_) => return Ok(()),
+            // Already gone
+            Err(e) if is_not_found(&e) => return Ok(()),
+            Err(kube::Error::Api(resp)) if resp.code == 429 => {
+                if Instant::now() + delay > deadline {
+                    return Err(format!(
+                        "Timed out evicting pod '{}': {}",
+                        pod_name, resp.message
+                    ));
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(EVICTION_RETRY_MAX);
+            }
+            Err(e) => return Err(format!("Failed to evict pod '{}': {}", pod_name, e)),
+        }
+    }
+}
+
+/// Cordon a node and evict its pods, emitting `drain_progress_<node>` (dots in the
+/// node name replaced by `_`) after each eviction. Evictions blocked by a
+/// PodDisruptionBudget are retried until `timeout_seconds` (default 5 minutes).
+/// Unmanaged and emptyDir pods need `force` / `delete_emptydir_data`; when one is
+/// refused the node is left untouched. Returns how many pods were evicted.
+#[tauri::command]
+#[tracing::instrument(skip(state, window))]
+pub async fn cluster_drain_node(
+    cluster_id: String,
+    node_name: String,
+    grace_period_seconds: Option<u32>,
+    timeout_seconds: Option<u64>,
+    force: Option<bool>,
+    delete_emptydir_data: Option<bool>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+) -> Result<usize, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let all_pods: Api<Pod> = Api::all(client.clone());
+    let list = all_pods
+        .list(&ListParams::default().fields(&format!("spec.nodeName={}", node_name)))
+        .await
+        .map_err(|e| format!("Failed to list pods on node '{}': {}", node_name, e))?;
+    let pods = drain_candidates(
+        list.items,
+        force.unwrap_or(false),
+        delete_emptydir_data.unwrap_or(false),
+    )?;
+    set_unschedulable(client.clone(), &node_name, true).await?;
+
+    let params = EvictParams {
+        delete_options: Some(load_settings(&state).delete_params(grace_period_seconds)),
+        ..Default::default()
+    };
+    let deadline =
+        Instant::now() + Duration::from_secs(timeout_seconds.unwrap_or(DEFAULT_DRAIN_TIMEOUT_SECS));
+    let event_name = drain_event_name(&node_name);
+    let total = pods.len();
+
+    for (i, pod) in pods.into_iter().enumerate() {
+        let pod_name = pod.metadata.name.unwrap_or_default();
+        let namespace = pod.metadata.namespace.unwrap_or_default();
+        let api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+
+        evict_with_retry(&api, &pod_name, &params, deadline).await?;
+
+        let progress = DrainProgress {
+            pod_name,
+            namespace,
+            evicted: i + 1,
+            total,
+        };
+        if let Err(e) = window.emit(&event_name, progress) {
+            println!("Failed to emit drain progress: {}", e);
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(yaml: &str) -> Node {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_summarize_node() {
+        let summary = summarize_node(&node(
+            r#"
+metadata:
+  name: cp-1
+  labels:
+    node-role.kubernetes.io/control-plane: ""
+    node-role.kubernetes.io/master: ""
+    kubernetes.io/hostname: cp-1
+spec:
+  taints:
+  - key: node-role.kubernetes.io/control-plane
+    effect: NoSchedule
+  - key: dedicated
+    value: infra
+    effect: NoExecute
+status:
+  capacity:
+    cpu: "4"
+    memory: 16Gi
+  addresses:
+  - type: Hostname
+    address: cp-1
+  - type: InternalIP
+    address: 10.0.0.5
+  conditions:
+  - type: Ready
+    status: "True"
+  nodeInfo:
+    architecture: amd64
+    bootID: ""
+    containerRuntimeVersion: containerd://1.7.0
+    kernelVersion: 6.1.0
+    kubeProxyVersion: v1.31.0
+    kubeletVersion: v1.31.0
+    machineID: ""
+    operatingSystem: linux
+    osImage: Ubuntu 24.04 LTS
+    systemUUID: ""
+"#,
+        ));
+
+        assert_eq!(summary.status, "Ready");
+        assert_eq!(summary.roles, vec!["control-plane", "master"]);
+        assert_eq!(summary.kubelet_version, "v1.31.0");
+        assert_eq!(summary.os_image, "Ubuntu 24.04 LTS");
+        assert_eq!(summary.cpu_capacity, "4");
+        assert_eq!(summary.memory_capacity, "16Gi");
+        assert_eq!(
+            summary.taints,
+            vec![
+                "node-role.kubernetes.io/control-plane:NoSchedule",
+                "dedicated=infra:NoExecute",
+            ]
+        );
+        assert_eq!(summary.internal_ip.as_deref(), Some("10.0.0.5"));
+    }
+
+    #[test]
+    fn test_node_status_not_ready_and_cordoned() {
+        let cordoned = node(
+            r#"
+metadata:
+  name: worker-1
+spec:
+  unschedulable: true
+status:
+  conditions:
+  - type: Ready
+    status: "False"
+"#,
+        );
+        assert_eq!(node_status(&cordoned), "NotReady,SchedulingDisabled");
+
+        let unreported = node("metadata:\n  name: worker-2\n");
+        assert_eq!(node_status(&unreported), "Unknown");
+    }
+
+    #[test]
+    fn test_drain_candidates_skip_daemonset_and_mirror_pods() {
+        let pods: Vec<Pod> = vec![
+            serde_yaml::from_str(
+                r#"
+metadata:
+  name: web-1
+  ownerReferences:
+  - apiVersion: apps/v1
+    kind: ReplicaSet
+    name: web-abc
+    uid: "1"
+    controller: true
+"#,
+            )
+            .unwrap(),
+            serde_yaml::from_str(
+                r#"
+metadata:
+  name: fluentd-x
+  ownerReferences:
+  - apiVersion: apps/v1
+    kind: DaemonSet
+    name: fluentd
+    uid: "2"
+    controller: true
+"#,
+            )
+            .unwrap(),
+            serde_yaml::from_str(
+                r#"
+metadata:
+  name: kube-apiserver-cp-1
+  annotations:
+    kubernetes.io/config.mirror: abc123
+"#,
+            )
+            .unwrap(),
+        ];
+
+        let names: Vec<String> = drain_candidates(pods, false, false)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.metadata.name.unwrap())
+            .collect();
+        assert_eq!(names, vec!["web-1"]);
+    }
+
+    #[test]
+    fn test_drain_candidates_refuse_unmanaged_and_emptydir_pods() {
+        let pods = || -> Vec<Pod> {
+            vec![
+                serde_yaml::from_str(
+                    r#"
+metadata:
+  name: bare
+  namespace: default
+"#,
+                )
+                .unwrap(),
+                serde_yaml::from_str(
+                    r#"
+metadata:
+  name: cache-1
+  namespace: default
+  ownerReferences:
+  - apiVersion: apps/v1
+    kind: ReplicaSet
+    name: cache-abc
+    uid: "1"
+    controller: true
+spec:
+  containers: []
+  volumes:
+  - name: scratch
+    emptyDir: {}
+"#,
+                )
+                .unwrap(),
+                serde_yaml::from_str(
+                    r#"
+metadata:
+  name: done
+  namespace: default
+status:
+  phase: Succeeded
+"#,
+                )
+                .unwrap(),
+            ]
+        };
+
+        let err = drain_candidates(pods(), false, false).unwrap_err();
+        assert!(err.contains("default/bare is not managed by a controller"));
+        assert!(err.contains("default/cache-1 uses emptyDir volumes"));
+        assert!(!err.contains("default/done"));
+
+        let err = drain_candidates(pods(), true, false).unwrap_err();
+        assert!(!err.contains("default/bare"));
+        assert!(drain_candidates(pods(), false, true).is_err());
+
+        let names: Vec<String> = drain_candidates(pods(), true, true)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.metadata.name.unwrap())
+            .collect();
+        assert_eq!(names, vec!["bare", "cache-1", "done"]);
+    }
+
+    #[test]
+    fn test_drain_event_name() {
+        assert_eq!(
+            drain_event_name("ip-10-0-0-1.ec2.internal"),
+            "drain_progress_ip-10-0-0-1_ec2_internal"
+        );
+    }
+}
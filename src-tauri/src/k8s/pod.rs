@@ -1,12 +1,23 @@
 use crate::cluster_manager::ClusterManagerState;
+use crate::error::AppError;
 use crate::k8s::client::{create_client_for_cluster, create_client_for_context};
-use crate::k8s::watcher::WatcherState;
+use crate::k8s::common::{check_selector, label_selector_params, next_continue_token, page_params};
+use crate::k8s::watcher::{
+    abort_pod_log_streams, emit_watch_status, finish_watch, reconnect_delay, watch_key,
+    WatchRegistration, WatchSpec, WatcherState, MAX_CONSECUTIVE_WATCH_ERRORS,
+};
+use crate::settings::{load_settings, AppSettings};
 use futures::{AsyncBufReadExt, StreamExt, TryStreamExt};
-use k8s_openapi::api::core::v1::Pod;
-use kube::api::{DeleteParams, ListParams, LogParams};
+use k8s_openapi::api::core::v1::{
+    Container, ContainerState, ContainerStatus, EphemeralContainer, Pod,
+};
+use kube::api::{AttachParams, ListParams, LogParams, TerminalSize};
 use kube::runtime::watcher;
-use kube::Api;
-use tauri::{Emitter, State, Window};
+use kube::{Api, Client};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, Manager, State, Window};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 #[derive(serde::Serialize, Clone, Debug)]
 pub struct ContainerPort {
@@ -87,10 +98,24 @@ pub struct PodSummary {
     service_account: String,
     priority_class: String,
     container_details: Vec<ContainerInfo>,
+    init_container_details: Vec<ContainerInfo>,
+    ephemeral_container_details: Vec<ContainerInfo>,
     volumes: Vec<VolumeInfo>,
     conditions: Vec<PodCondition>,
 }
 
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct InitContainerStatus {
+    name: String,
+    image: String,
+    state: String,
+    ready: bool,
+    restart_count: i32,
+    exit_code: Option<i32>,
+    last_terminated_reason: Option<String>,
+    last_terminated_exit_code: Option<i32>,
+}
+
 #[derive(serde::Serialize, Clone, Debug)]
 pub struct PodCondition {
     condition_type: String,
@@ -100,7 +125,7 @@ pub struct PodCondition {
     last_transition_time: Option<String>,
 }
 
-#[derive(serde::Serialize, Clone, Debug)]
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
 pub struct PodEventInfo {
     event_type: String, // "Normal", "Warning"
     reason: String,
@@ -111,6 +136,11 @@ pub struct PodEventInfo {
     source: String,
 }
 
+/// Host a probe connects to; without one the kubelet uses the pod IP
+fn probe_host(host: Option<&str>) -> &str {
+    host.filter(|h| !h.is_empty()).unwrap_or("<pod IP>")
+}
+
 fn probe_to_info(probe_type: &str, probe: &k8s_openapi::api::core::v1::Probe) -> ProbeInfo {
     let (handler_type, details) = if let Some(http) = probe.http_get.as_ref() {
         let path = http.path.clone().unwrap_or_else(|| "/".to_string());
@@ -118,17 +148,19 @@ fn probe_to_info(probe_type: &str, probe: &k8s_openapi::api::core::v1::Probe) ->
             k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(n) => n.to_string(),
             k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::String(s) => s.clone(),
         };
-        let scheme = http.scheme.clone().unwrap_or_else(|| "HTTP".to_string());
+        let scheme = http.scheme.as_deref().unwrap_or("HTTP").to_lowercase();
+        let host = probe_host(http.host.as_deref());
         (
             "httpGet".to_string(),
-            format!("{}://{}:{}{}", scheme, "localhost", port, path),
+            format!("{}://{}:{}{}", scheme, host, port, path),
         )
     } else if let Some(tcp) = probe.tcp_socket.as_ref() {
         let port = match &tcp.port {
             k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(n) => n.to_string(),
             k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::String(s) => s.clone(),
         };
-        ("tcpSocket".to_string(), format!(":{}", port))
+        let host = probe_host(tcp.host.as_deref());
+        ("tcpSocket".to_string(), format!("{}:{}", host, port))
     } else if let Some(exec) = probe.exec.as_ref() {
         let command = exec
             .command
@@ -152,6 +184,174 @@ fn probe_to_info(probe_type: &str, probe: &k8s_openapi::api::core::v1::Probe) ->
     }
 }
 
+/// Human-readable container state, e.g. "Running" or "Waiting: CrashLoopBackOff"
+fn describe_container_state(state: Option<&ContainerState>) -> String {
+    let Some(state) = state else {
+        return "Unknown".to_string();
+    };
+
+    if state.running.is_some() {
+        "Running".to_string()
+    } else if let Some(waiting) = state.waiting.as_ref() {
+        format!(
+            "Waiting: {}",
+            waiting
+                .reason
+                .clone()
+                .unwrap_or_else(|| "Waiting".to_string())
+        )
+    } else if let Some(terminated) = state.terminated.as_ref() {
+        format!(
+            "Terminated: {}",
+            terminated
+                .reason
+                .clone()
+                .unwrap_or_else(|| "Terminated".to_string())
+        )
+    } else {
+        "Unknown".to_string()
+    }
+}
+
+/// Details of one container, with its status when the kubelet has reported one
+fn container_info(
+    container: &Container,
+    container_status: Option<&ContainerStatus>,
+) -> ContainerInfo {
+    let ready = container_status.map(|s| s.ready).unwrap_or(false);
+    let restart_count = container_status.map(|s| s.restart_count).unwrap_or(0);
+
+    let state = describe_container_state(container_status.and_then(|cs| cs.state.as_ref()));
+
+    let resources = container.resources.as_ref();
+    let cpu_request = resources
+        .and_then(|r| r.requests.as_ref())
+        .and_then(|req| req.get("cpu"))
+        .map(|q| q.0.clone());
+    let cpu_limit = resources
+        .and_then(|r| r.limits.as_ref())
+        .and_then(|lim| lim.get("cpu"))
+        .map(|q| q.0.clone());
+    let memory_request = resources
+        .and_then(|r| r.requests.as_ref())
+        .and_then(|req| req.get("memory"))
+        .map(|q| q.0.clone());
+    let memory_limit = resources
+        .and_then(|r| r.limits.as_ref())
+        .and_then(|lim| lim.get("memory"))
+        .map(|q| q.0.clone());
+
+    // Ports
+    let ports = container
+        .ports
+        .as_ref()
+        .map(|ports| {
+            ports
+                .iter()
+                .map(|p| ContainerPort {
+                    name: p.name.clone(),
+                    container_port: p.container_port,
+                    host_port: p.host_port,
+                    protocol: p.protocol.clone().unwrap_or_else(|| "TCP".to_string()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Environment variables
+    let env = container
+        .env
+        .as_ref()
+        .map(|envs| {
+            envs.iter()
+                .map(|e| {
+                    let value_from = if e.value_from.is_some() {
+                        Some("(from ConfigMap/Secret)".to_string())
+                    } else {
+                        None
+                    };
+                    EnvVar {
+                        name: e.name.clone(),
+                        value: e.value.clone(),
+                        value_from,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Volume mounts
+    let volume_mounts = container
+        .volume_mounts
+        .as_ref()
+        .map(|mounts| {
+            mounts
+                .iter()
+                .map(|m| VolumeMount {
+                    name: m.name.clone(),
+                    mount_path: m.mount_path.clone(),
+                    sub_path: m.sub_path.clone(),
+                    read_only: m.read_only.unwrap_or(false),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Probes
+    let mut probes = Vec::new();
+    if let Some(liveness) = container.liveness_probe.as_ref() {
+        probes.push(probe_to_info("liveness", liveness));
+    }
+    if let Some(readiness) = container.readiness_probe.as_ref() {
+        probes.push(probe_to_info("readiness", readiness));
+    }
+    if let Some(startup) = container.startup_probe.as_ref() {
+        probes.push(probe_to_info("startup", startup));
+    }
+
+    let image_pull_policy = container
+        .image_pull_policy
+        .clone()
+        .unwrap_or_else(|| "IfNotPresent".to_string());
+
+    ContainerInfo {
+        name: container.name.clone(),
+        image: container.image.clone().unwrap_or_default(),
+        image_pull_policy,
+        ready,
+        restart_count,
+        state,
+        cpu_request,
+        cpu_limit,
+        memory_request,
+        memory_limit,
+        ports,
+        env,
+        volume_mounts,
+        probes,
+    }
+}
+
+fn status_for<'a>(
+    statuses: Option<&'a Vec<ContainerStatus>>,
+    name: &str,
+) -> Option<&'a ContainerStatus> {
+    statuses.and_then(|statuses| statuses.iter().find(|s| s.name == name))
+}
+
+/// Ephemeral containers share most of a container's fields; ports, resources and
+/// probes aren't allowed on them
+fn ephemeral_as_container(container: &EphemeralContainer) -> Container {
+    Container {
+        name: container.name.clone(),
+        image: container.image.clone(),
+        image_pull_policy: container.image_pull_policy.clone(),
+        env: container.env.clone(),
+        volume_mounts: container.volume_mounts.clone(),
+        ..Default::default()
+    }
+}
+
 fn map_pod_to_summary(p: Pod) -> PodSummary {
     let status = p
         .status
@@ -254,160 +454,42 @@ fn map_pod_to_summary(p: Pod) -> PodSummary {
         .and_then(|s| s.priority_class_name.clone())
         .unwrap_or_else(|| "-".to_string());
 
-    // Container details
-    let mut container_details = Vec::new();
-    if let Some(spec) = p.spec.as_ref() {
-        for container in &spec.containers {
-            let container_status = container_statuses
-                .and_then(|statuses| statuses.iter().find(|s| s.name == container.name))
-                .cloned();
-
-            let ready = container_status.as_ref().map(|s| s.ready).unwrap_or(false);
-            let restart_count = container_status
-                .as_ref()
-                .map(|s| s.restart_count)
-                .unwrap_or(0);
-
-            let state = if let Some(cs) = container_status.as_ref() {
-                if cs.state.as_ref().and_then(|s| s.running.as_ref()).is_some() {
-                    "Running".to_string()
-                } else if cs.state.as_ref().and_then(|s| s.waiting.as_ref()).is_some() {
-                    let reason = cs
-                        .state
-                        .as_ref()
-                        .and_then(|s| s.waiting.as_ref())
-                        .and_then(|w| w.reason.clone())
-                        .unwrap_or_else(|| "Waiting".to_string());
-                    format!("Waiting: {}", reason)
-                } else if cs
-                    .state
-                    .as_ref()
-                    .and_then(|s| s.terminated.as_ref())
-                    .is_some()
-                {
-                    let reason = cs
-                        .state
-                        .as_ref()
-                        .and_then(|s| s.terminated.as_ref())
-                        .and_then(|t| t.reason.clone())
-                        .unwrap_or_else(|| "Terminated".to_string());
-                    format!("Terminated: {}", reason)
-                } else {
-                    "Unknown".to_string()
-                }
-            } else {
-                "Unknown".to_string()
-            };
-
-            let resources = container.resources.as_ref();
-            let cpu_request = resources
-                .and_then(|r| r.requests.as_ref())
-                .and_then(|req| req.get("cpu"))
-                .map(|q| q.0.clone());
-            let cpu_limit = resources
-                .and_then(|r| r.limits.as_ref())
-                .and_then(|lim| lim.get("cpu"))
-                .map(|q| q.0.clone());
-            let memory_request = resources
-                .and_then(|r| r.requests.as_ref())
-                .and_then(|req| req.get("memory"))
-                .map(|q| q.0.clone());
-            let memory_limit = resources
-                .and_then(|r| r.limits.as_ref())
-                .and_then(|lim| lim.get("memory"))
-                .map(|q| q.0.clone());
-
-            // Ports
-            let ports = container
-                .ports
-                .as_ref()
-                .map(|ports| {
-                    ports
-                        .iter()
-                        .map(|p| ContainerPort {
-                            name: p.name.clone(),
-                            container_port: p.container_port,
-                            host_port: p.host_port,
-                            protocol: p.protocol.clone().unwrap_or_else(|| "TCP".to_string()),
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            // Environment variables
-            let env = container
-                .env
-                .as_ref()
-                .map(|envs| {
-                    envs.iter()
-                        .map(|e| {
-                            let value_from = if e.value_from.is_some() {
-                                Some("(from ConfigMap/Secret)".to_string())
-                            } else {
-                                None
-                            };
-                            EnvVar {
-                                name: e.name.clone(),
-                                value: e.value.clone(),
-                                value_from,
-                            }
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            // Volume mounts
-            let volume_mounts = container
-                .volume_mounts
-                .as_ref()
-                .map(|mounts| {
-                    mounts
-                        .iter()
-                        .map(|m| VolumeMount {
-                            name: m.name.clone(),
-                            mount_path: m.mount_path.clone(),
-                            sub_path: m.sub_path.clone(),
-                            read_only: m.read_only.unwrap_or(false),
-                        })
-                        .collect()
+    // Container details, each matched with its status by name
+    let pod_status = p.status.as_ref();
+    let init_statuses = pod_status.and_then(|s| s.init_container_statuses.as_ref());
+    let ephemeral_statuses = pod_status.and_then(|s| s.ephemeral_container_statuses.as_ref());
+    let spec = p.spec.as_ref();
+    let container_details = spec
+        .map(|spec| {
+            spec.containers
+                .iter()
+                .map(|c| container_info(c, status_for(container_statuses, &c.name)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let init_container_details = spec
+        .and_then(|spec| spec.init_containers.as_ref())
+        .map(|containers| {
+            containers
+                .iter()
+                .map(|c| container_info(c, status_for(init_statuses, &c.name)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let ephemeral_container_details = spec
+        .and_then(|spec| spec.ephemeral_containers.as_ref())
+        .map(|containers| {
+            containers
+                .iter()
+                .map(|c| {
+                    container_info(
+                        &ephemeral_as_container(c),
+                        status_for(ephemeral_statuses, &c.name),
+                    )
                 })
-                .unwrap_or_default();
-
-            // Probes
-            let mut probes = Vec::new();
-            if let Some(liveness) = container.liveness_probe.as_ref() {
-                probes.push(probe_to_info("liveness", liveness));
-            }
-            if let Some(readiness) = container.readiness_probe.as_ref() {
-                probes.push(probe_to_info("readiness", readiness));
-            }
-            if let Some(startup) = container.startup_probe.as_ref() {
-                probes.push(probe_to_info("startup", startup));
-            }
-
-            let image_pull_policy = container
-                .image_pull_policy
-                .clone()
-                .unwrap_or_else(|| "IfNotPresent".to_string());
-
-            container_details.push(ContainerInfo {
-                name: container.name.clone(),
-                image: container.image.clone().unwrap_or_default(),
-                image_pull_policy,
-                ready,
-                restart_count,
-                state,
-                cpu_request,
-                cpu_limit,
-                memory_request,
-                memory_limit,
-                ports,
-                env,
-                volume_mounts,
-                probes,
-            });
-        }
-    }
+                .collect()
+        })
+        .unwrap_or_default();
 
     // Volumes
     let mut volumes = Vec::new();
@@ -477,13 +559,19 @@ fn map_pod_to_summary(p: Pod) -> PodSummary {
         service_account,
         priority_class,
         container_details,
+        init_container_details,
+        ephemeral_container_details,
         volumes,
         conditions,
     }
 }
 
 #[tauri::command]
-pub async fn list_pods(context_name: String, namespace: String) -> Result<Vec<PodSummary>, String> {
+#[tracing::instrument]
+pub async fn list_pods(
+    context_name: String,
+    namespace: String,
+) -> Result<Vec<PodSummary>, AppError> {
     let client = create_client_for_context(&context_name).await?;
 
     let pods: Api<Pod> = if namespace == "all" {
@@ -497,7 +585,7 @@ pub async fn list_pods(context_name: String, namespace: String) -> Result<Vec<Po
     let pod_list = pods
         .list(&lp)
         .await
-        .map_err(|e| format!("Failed to list pods: {}", e))?;
+        .map_err(|e| AppError::from(e).context("Failed to list pods"))?;
 
     let summaries = pod_list.items.into_iter().map(map_pod_to_summary).collect();
 
@@ -505,27 +593,34 @@ pub async fn list_pods(context_name: String, namespace: String) -> Result<Vec<Po
 }
 
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn delete_pod(
     context_name: String,
     namespace: String,
     pod_name: String,
-) -> Result<(), String> {
+    grace_period_seconds: Option<u32>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), AppError> {
     let client = create_client_for_context(&context_name).await?;
     let pods: Api<Pod> = Api::namespaced(client, &namespace);
 
-    pods.delete(&pod_name, &DeleteParams::default())
-        .await
-        .map_err(|e| format!("Failed to delete pod: {}", e))?;
+    pods.delete(
+        &pod_name,
+        &load_settings(&state).delete_params(grace_period_seconds),
+    )
+    .await
+    .map_err(|e| AppError::from(e).context("Failed to delete pod"))?;
 
     Ok(())
 }
 
 #[tauri::command]
+#[tracing::instrument]
 pub async fn get_pod_events(
     context_name: String,
     namespace: String,
     pod_name: String,
-) -> Result<Vec<PodEventInfo>, String> {
+) -> Result<Vec<PodEventInfo>, AppError> {
     use k8s_openapi::api::core::v1::Event;
 
     let client = create_client_for_context(&context_name).await?;
@@ -536,7 +631,7 @@ pub async fn get_pod_events(
     let event_list = events
         .list(&lp)
         .await
-        .map_err(|e| format!("Failed to list events: {}", e))?;
+        .map_err(|e| AppError::from(e).context("Failed to list events"))?;
 
     let mut event_infos: Vec<PodEventInfo> = event_list
         .items
@@ -574,14 +669,17 @@ pub async fn stream_container_logs(
     pod_name: String,
     container_name: String,
     stream_id: String,
-) -> Result<(), String> {
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), AppError> {
     let client = create_client_for_context(&context_name).await?;
     let pods: Api<Pod> = Api::namespaced(client, &namespace);
 
+    let settings = load_settings(&state);
     let log_params = LogParams {
         follow: true,
-        tail_lines: Some(1000),
+        tail_lines: Some(settings.log_tail_lines),
         container: Some(container_name.clone()),
+        timestamps: settings.default_log_timestamps,
         ..Default::default()
     };
 
@@ -623,7 +721,6 @@ pub async fn stream_container_logs(
 #[serde(tag = "type", content = "payload")]
 pub enum PodEvent {
     Added(PodSummary),
-    #[allow(dead_code)]
     Modified(PodSummary),
     Deleted(PodSummary),
     #[allow(dead_code)]
@@ -635,7 +732,7 @@ pub async fn start_pod_watch(
     window: Window,
     context_name: String,
     namespace: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     use kube::runtime::watcher::Config as WatchConfig;
 
     let client = create_client_for_context(&context_name).await?;
@@ -679,12 +776,37 @@ pub async fn start_pod_watch(
     Ok(())
 }
 
+/// One page of `cluster_list_pods`
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct PodList {
+    pub items: Vec<PodSummary>,
+    /// Pass back as `continue_token` to fetch the next page; `None` on the last page
+    pub continue_token: Option<String>,
+}
+
+/// List pods, a page of `limit` at a time when given
+///
+/// A `label_selector` and a `field_selector` (e.g. `spec.nodeName=worker-1` or
+/// `status.phase=Running`) both filter within `namespace` (or every namespace
+/// for `all`); malformed selectors and unsupported fields come back with the
+/// API server's message.
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_list_pods(
     cluster_id: String,
     namespace: String,
+    label_selector: Option<String>,
+    field_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
     state: State<'_, ClusterManagerState>,
-) -> Result<Vec<PodSummary>, String> {
+) -> Result<PodList, AppError> {
+    let mut lp = label_selector_params(label_selector).map_err(AppError::InvalidInput)?;
+    if let Some(selector) =
+        check_selector(field_selector, "Field selector").map_err(AppError::InvalidInput)?
+    {
+        lp = lp.fields(&selector);
+    }
     let client = create_client_for_cluster(&cluster_id, &state).await?;
 
     let pods: Api<Pod> = if namespace == "all" {
@@ -693,56 +815,187 @@ pub async fn cluster_list_pods(
         Api::namespaced(client, &namespace)
     };
 
-    let lp = kube::api::ListParams::default();
+    let lp = page_params(lp, limit, continue_token);
     let list = pods
         .list(&lp)
         .await
-        .map_err(|e| format!("Failed to list pods: {}", e))?;
+        .map_err(|e| AppError::from(e).context("Failed to list pods"))?;
+
+    Ok(PodList {
+        continue_token: next_continue_token(&list.metadata),
+        items: list.items.into_iter().map(map_pod_to_summary).collect(),
+    })
+}
+
+/// Order summaries for `cluster_list_pods_sorted`: most restarts first, oldest first,
+/// or alphabetically by namespace then name
+fn sort_pod_summaries(summaries: &mut [PodSummary], sort_by: &str) -> Result<(), AppError> {
+    match sort_by {
+        "restarts" => summaries.sort_by(|a, b| b.restarts.cmp(&a.restarts)),
+        "age" => summaries.sort_by_key(|p| {
+            p.creation_timestamp
+                .as_deref()
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .map(|t| t.timestamp())
+                .unwrap_or(i64::MAX)
+        }),
+        "name" => summaries.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name))),
+        other => {
+            return Err(AppError::InvalidInput(format!(
+                "Unsupported sort field: {}",
+                other
+            )))
+        }
+    }
+    Ok(())
+}
+
+/// `cluster_list_pods`, sorted (`"restarts"`, `"age"` or `"name"`) and truncated on the
+/// backend so triage views only transfer the pods they show
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_list_pods_sorted(
+    cluster_id: String,
+    namespace: String,
+    sort_by: Option<String>,
+    limit: Option<u32>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<PodSummary>, AppError> {
+    let mut summaries = cluster_list_pods(cluster_id, namespace, None, None, None, None, state)
+        .await?
+        .items;
+
+    if let Some(sort_by) = sort_by.as_deref() {
+        sort_pod_summaries(&mut summaries, sort_by)?;
+    }
+    if let Some(limit) = limit {
+        summaries.truncate(limit as usize);
+    }
 
-    let summaries = list
-        .items
-        .iter()
-        .map(|p| map_pod_to_summary(p.clone()))
-        .collect();
     Ok(summaries)
 }
 
+/// Delete a pod. With `force` it is removed at once instead of gracefully; this can
+/// leave its containers running on a node that never acknowledged the deletion.
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_delete_pod(
     cluster_id: String,
     namespace: String,
     pod_name: String,
+    grace_period_seconds: Option<u32>,
+    force: Option<bool>,
     state: State<'_, ClusterManagerState>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     let client = create_client_for_cluster(&cluster_id, &state).await?;
     let pods: Api<Pod> = Api::namespaced(client, &namespace);
 
-    pods.delete(&pod_name, &kube::api::DeleteParams::default())
-        .await
-        .map_err(|e| format!("Failed to delete pod: {}", e))?;
+    pods.delete(
+        &pod_name,
+        &load_settings(&state).pod_delete_params(grace_period_seconds, force.unwrap_or(false)),
+    )
+    .await
+    .map_err(|e| AppError::from(e).context("Failed to delete pod"))?;
 
     Ok(())
 }
 
+/// Outcome of deleting every pod that matches a label selector
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct PodBulkDeleteResult {
+    /// Pods matching the selector when the request was made
+    pub matched: usize,
+    pub deleted: Vec<String>,
+}
+
+/// Delete all pods in a namespace matching a label selector in one request, like
+/// `kubectl delete pods -l`. The selector is required so a blank one can't empty the namespace.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_delete_pods_by_selector(
+    cluster_id: String,
+    namespace: String,
+    label_selector: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<PodBulkDeleteResult, AppError> {
+    let lp = label_selector_params(Some(label_selector)).map_err(AppError::InvalidInput)?;
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+
+    let matched: Vec<String> = pods
+        .list_metadata(&lp)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to list pods"))?
+        .items
+        .into_iter()
+        .filter_map(|p| p.metadata.name)
+        .collect();
+    if matched.is_empty() {
+        return Ok(PodBulkDeleteResult {
+            matched: 0,
+            deleted: vec![],
+        });
+    }
+
+    let response = pods
+        .delete_collection(&load_settings(&state).delete_params(None), &lp)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to delete pods"))?;
+
+    // The server answers with either the deleted objects or a bare Status
+    let deleted = response
+        .left()
+        .map(|list| {
+            list.items
+                .into_iter()
+                .filter_map(|p| p.metadata.name)
+                .collect()
+        })
+        .unwrap_or_else(|| matched.clone());
+
+    Ok(PodBulkDeleteResult {
+        matched: matched.len(),
+        deleted,
+    })
+}
+
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_pod_events(
     cluster_id: String,
     namespace: String,
     pod_name: String,
     state: State<'_, ClusterManagerState>,
-) -> Result<Vec<PodEventInfo>, String> {
-    use k8s_openapi::api::core::v1::Event;
-
+) -> Result<Vec<PodEventInfo>, AppError> {
     let client = create_client_for_cluster(&cluster_id, &state).await?;
-    let events_api: Api<Event> = Api::namespaced(client, &namespace);
+    fetch_pod_events(client, &namespace, &pod_name).await
+}
 
+/// Events for a pod, most recent first
+pub(crate) async fn fetch_pod_events(
+    client: Client,
+    namespace: &str,
+    pod_name: &str,
+) -> Result<Vec<PodEventInfo>, AppError> {
     let field_selector = format!("involvedObject.name={}", pod_name);
-    let lp = ListParams::default().fields(&field_selector);
+    fetch_events_matching(client, namespace, &field_selector).await
+}
+
+/// Events in a namespace matching an event field selector, most recent first
+pub(crate) async fn fetch_events_matching(
+    client: Client,
+    namespace: &str,
+    field_selector: &str,
+) -> Result<Vec<PodEventInfo>, AppError> {
+    use k8s_openapi::api::core::v1::Event;
+
+    let events_api: Api<Event> = Api::namespaced(client, namespace);
+    let lp = ListParams::default().fields(field_selector);
 
     let events_list = events_api
         .list(&lp)
         .await
-        .map_err(|e| format!("Failed to list events: {}", e))?;
+        .map_err(|e| AppError::from(e).context("Failed to list events"))?;
 
     let mut event_infos: Vec<PodEventInfo> = events_list
         .items
@@ -786,164 +1039,1673 @@ pub async fn cluster_get_pod_events(
     Ok(event_infos)
 }
 
+fn map_init_container_statuses(p: &Pod) -> Vec<InitContainerStatus> {
+    let statuses = p
+        .status
+        .as_ref()
+        .and_then(|s| s.init_container_statuses.as_ref());
+
+    // Follow spec order so the failing step is easy to spot in the init sequence
+    p.spec
+        .as_ref()
+        .and_then(|s| s.init_containers.as_ref())
+        .map(|containers| {
+            containers
+                .iter()
+                .map(|container| {
+                    let status =
+                        statuses.and_then(|all| all.iter().find(|s| s.name == container.name));
+                    let current_terminated = status
+                        .and_then(|s| s.state.as_ref())
+                        .and_then(|s| s.terminated.as_ref());
+                    let last_terminated = status
+                        .and_then(|s| s.last_state.as_ref())
+                        .and_then(|s| s.terminated.as_ref());
+
+                    InitContainerStatus {
+                        name: container.name.clone(),
+                        image: container.image.clone().unwrap_or_default(),
+                        state: describe_container_state(status.and_then(|s| s.state.as_ref())),
+                        ready: status.map(|s| s.ready).unwrap_or(false),
+                        restart_count: status.map(|s| s.restart_count).unwrap_or(0),
+                        exit_code: current_terminated.map(|t| t.exit_code),
+                        last_terminated_reason: last_terminated.and_then(|t| t.reason.clone()),
+                        last_terminated_exit_code: last_terminated.map(|t| t.exit_code),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[tauri::command]
-pub async fn cluster_stream_container_logs(
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_pod_init_status(
     cluster_id: String,
     namespace: String,
     pod_name: String,
-    container_name: String,
-    stream_id: String,
-    window: Window,
     state: State<'_, ClusterManagerState>,
-    watcher_state: State<'_, WatcherState>,
-) -> Result<(), String> {
+) -> Result<Vec<InitContainerStatus>, AppError> {
     let client = create_client_for_cluster(&cluster_id, &state).await?;
     let pods: Api<Pod> = Api::namespaced(client, &namespace);
 
-    let log_params = LogParams {
-        follow: true,
-        tail_lines: Some(1000),
-        container: Some(container_name.clone()),
-        ..Default::default()
-    };
+    let pod = pods
+        .get(&pod_name)
+        .await
+        .map_err(|e| AppError::from(e).context(format!("Failed to get pod '{}'", pod_name)))?;
 
-    let key = format!("logs:{}", stream_id);
+    Ok(map_init_container_statuses(&pod))
+}
 
-    // Abort existing if any
-    {
-        let mut watchers = watcher_state
-            .0
-            .lock()
-            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
-        if let Some(handle) = watchers.remove(&key) {
-            handle.abort();
-        }
-    }
+/// The full pod object as JSON, for views that need fields `PodSummary` doesn't carry
+/// (affinity, tolerations, topologySpreadConstraints, ...). managedFields are dropped
+/// since they're bulky and only matter to the API server.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_pod_raw(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<serde_json::Value, AppError> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
 
-    let watchers = watcher_state.inner().0.clone();
-    let key_clone = key.clone();
+    let mut pod = pods
+        .get(&pod_name)
+        .await
+        .map_err(|e| AppError::from(e).context(format!("Failed to get pod '{}'", pod_name)))?;
+    pod.metadata.managed_fields = None;
 
-    let handle = tauri::async_runtime::spawn(async move {
-        match pods.log_stream(&pod_name, &log_params).await {
-            Ok(stream) => {
-                let mut lines = stream.lines();
-                loop {
-                    match lines.try_next().await {
-                        Ok(Some(line)) => {
-                            let event_name = format!("container_logs_{}", stream_id);
-                            if let Err(e) = window.emit(&event_name, line) {
-                                println!("Failed to emit log line: {}", e);
-                                break;
-                            }
-                        }
-                        Ok(None) => break,
-                        Err(e) => {
-                            println!("Error reading log line: {}", e);
-                            break;
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                println!("Failed to open log stream: {}", e);
-            }
-        }
+    serde_json::to_value(&pod)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize pod: {}", e)))
+}
 
-        // Cleanup
-        if let Ok(mut watchers) = watchers.lock() {
-            watchers.remove(&key_clone);
-        } else {
-            eprintln!("Warning: failed to clean up log watcher state");
+/// What to read from a container's log. Omitted fields take the defaults of a plain
+/// `cluster_stream_container_logs` call: follow, no time bound, current container.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct LogStreamOptions {
+    /// Start with this many lines; `None` reads the whole log
+    pub tail_lines: Option<i64>,
+    pub since_seconds: Option<i64>,
+    /// Keep the RFC3339 timestamp the API server prefixes each line with
+    pub timestamps: bool,
+    /// Read the previous (crashed) container instead of the current one
+    pub previous: bool,
+    pub follow: bool,
+}
+
+impl Default for LogStreamOptions {
+    fn default() -> Self {
+        Self {
+            tail_lines: None,
+            since_seconds: None,
+            timestamps: false,
+            previous: false,
+            follow: true,
         }
-    });
+    }
+}
 
-    // Store new handle
-    {
-        let mut watchers = watcher_state
-            .0
-            .lock()
-            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
-        watchers.insert(key, handle);
+impl LogStreamOptions {
+    /// The options a stream started without any uses: tail and timestamps from settings
+    fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            tail_lines: Some(settings.log_tail_lines),
+            timestamps: settings.default_log_timestamps,
+            ..Default::default()
+        }
     }
 
-    Ok(())
+    pub(crate) fn log_params(&self, container_name: &str) -> LogParams {
+        LogParams {
+            container: Some(container_name.to_string()),
+            tail_lines: self.tail_lines,
+            since_seconds: self.since_seconds,
+            timestamps: self.timestamps,
+            previous: self.previous,
+            follow: self.follow,
+            ..Default::default()
+        }
+    }
 }
 
 #[tauri::command]
-pub async fn cluster_start_pod_watch(
+pub async fn cluster_stream_container_logs(
     cluster_id: String,
     namespace: String,
+    pod_name: String,
+    container_name: String,
+    stream_id: String,
+    options: Option<LogStreamOptions>,
     window: Window,
     state: State<'_, ClusterManagerState>,
     watcher_state: State<'_, WatcherState>,
-) -> Result<(), String> {
-    use kube::runtime::watcher::Config as WatchConfig;
+) -> Result<(), AppError> {
+    let options =
+        options.unwrap_or_else(|| LogStreamOptions::from_settings(&load_settings(&state)));
+    start_container_log_stream(
+        cluster_id,
+        namespace,
+        pod_name,
+        container_name,
+        stream_id,
+        options,
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+/// Save a container's log to `dest_path` (within the home directory), overwriting it,
+/// and return the number of bytes written. The log is copied to the file as it arrives
+/// rather than held in memory, and removed again if the download fails partway.
+/// Downloads never follow, whatever `options.follow` says.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_download_container_logs(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    container_name: String,
+    dest_path: String,
+    options: Option<LogStreamOptions>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<u64, AppError> {
+    let dest = crate::config::validate_download_path(std::path::Path::new(&dest_path))
+        .map_err(AppError::InvalidInput)?;
+    let log_params = LogStreamOptions {
+        follow: false,
+        ..options.unwrap_or_default()
+    }
+    .log_params(&container_name);
 
     let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+    let logs = pods
+        .log_stream(&pod_name, &log_params)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to read logs"))?;
 
-    let api: Api<Pod> = if namespace == "all" {
-        Api::all(client)
-    } else {
-        Api::namespaced(client, &namespace)
-    };
+    let file = tokio::fs::File::create(&dest)
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to create log file"))?;
 
-    let config = WatchConfig::default();
-    let key = format!("pod_watch:{}:{}", cluster_id, namespace);
+    let result = write_log_file(&dest, file, logs).await;
+    if result.is_err() {
+        // Don't leave a truncated log behind that looks like a complete one
+        let _ = tokio::fs::remove_file(&dest).await;
+    }
+    result
+}
 
-    // Abort existing if any
-    {
-        let mut watchers = watcher_state
-            .0
-            .lock()
-            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
-        if let Some(handle) = watchers.remove(&key) {
-            handle.abort();
+/// Copy a log stream into the freshly created `file` at `dest`, returning the bytes written
+async fn write_log_file(
+    dest: &std::path::Path,
+    file: tokio::fs::File,
+    logs: impl futures::AsyncBufRead,
+) -> Result<u64, AppError> {
+    // Logs can hold credentials printed by the app
+    crate::config::set_owner_only_file_permissions(dest)
+        .map_err(|e| AppError::from(e).context("Failed to set secure permissions"))?;
+
+    let mut writer = tokio::io::BufWriter::new(file);
+    let mut logs = std::pin::pin!(logs);
+    let mut written = 0;
+    loop {
+        let chunk = logs
+            .fill_buf()
+            .await
+            .map_err(|e| AppError::from(e).context("Failed to read logs"))?;
+        if chunk.is_empty() {
+            break;
         }
+        writer
+            .write_all(chunk)
+            .await
+            .map_err(|e| AppError::from(e).context("Failed to write log file"))?;
+        let len = chunk.len();
+        logs.consume_unpin(len);
+        written += len as u64;
     }
+    writer
+        .flush()
+        .await
+        .map_err(|e| AppError::from(e).context("Failed to write log file"))?;
 
-    let watchers = watcher_state.inner().0.clone();
-    let key_clone = key.clone();
+    Ok(written)
+}
 
-    let handle = tauri::async_runtime::spawn(async move {
-        let mut stream = watcher(api, config).boxed();
+fn previous_log_params(
+    container_name: &str,
+    tail_lines: Option<i64>,
+    settings: &AppSettings,
+) -> LogParams {
+    LogParams {
+        previous: true,
+        tail_lines: Some(tail_lines.unwrap_or(settings.log_tail_lines)),
+        container: Some(container_name.to_string()),
+        timestamps: settings.default_log_timestamps,
+        ..Default::default()
+    }
+}
 
-        while let Some(result) = stream.next().await {
-            match result {
-                Ok(event) => {
-                    let pod_event = match event {
-                        watcher::Event::Apply(pod) => PodEvent::Added(map_pod_to_summary(pod)),
-                        watcher::Event::Delete(pod) => PodEvent::Deleted(map_pod_to_summary(pod)),
-                        watcher::Event::InitApply(pod) => PodEvent::Added(map_pod_to_summary(pod)),
-                        _ => continue,
-                    };
+/// The previous container's log lines framed by markers separating them from the
+/// current container's output that follows
+fn labeled_previous_logs(container_name: &str, logs: &str) -> Vec<String> {
+    std::iter::once(format!("--- previous container ({}) ---", container_name))
+        .chain(logs.lines().map(str::to_string))
+        .chain(std::iter::once(format!(
+            "--- current container ({}) ---",
+            container_name
+        )))
+        .collect()
+}
 
-                    if let Err(e) = window.emit("pod_event", pod_event) {
-                        println!("Failed to emit event: {}", e);
-                        break;
+/// Emit the previous (crashed) container's logs, labeled, then follow the current
+/// container on the same `container_logs_<stream_id>` event. When there's no
+/// previous container this is the same as `cluster_stream_container_logs`.
+#[tauri::command]
+#[tracing::instrument(skip(window, state, watcher_state))]
+pub async fn cluster_stream_logs_with_previous(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    container_name: String,
+    stream_id: String,
+    tail_lines: Option<i64>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+
+    let settings = load_settings(&state);
+    let previous_params = previous_log_params(&container_name, tail_lines, &settings);
+
+    // The API answers 400 when the container has never restarted
+    if let Ok(previous) = pods.logs(&pod_name, &previous_params).await {
+        let event_name = format!("container_logs_{}", stream_id);
+        for line in labeled_previous_logs(&container_name, &previous) {
+            window
+                .emit(&event_name, line)
+                .map_err(|e| AppError::Internal(format!("Failed to emit log line: {}", e)))?;
+        }
+    }
+
+    start_container_log_stream(
+        cluster_id,
+        namespace,
+        pod_name,
+        container_name,
+        stream_id,
+        LogStreamOptions::from_settings(&settings),
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+/// Pause before re-opening a log stream that ended
+const LOG_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+/// Give up after this many consecutive reconnects that yield no lines
+const MAX_LOG_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// Split the RFC3339 timestamp the API server prefixes when `timestamps` is set
+fn split_log_timestamp(line: &str) -> (Option<chrono::DateTime<chrono::Utc>>, &str) {
+    if let Some((prefix, rest)) = line.split_once(' ') {
+        if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(prefix) {
+            return (Some(ts.with_timezone(&chrono::Utc)), rest);
+        }
+    }
+    (None, line)
+}
+
+/// Tracks what a followed log stream has emitted so a reconnect can skip the lines
+/// it replays. Several lines can share a timestamp, so lines at the latest one are
+/// matched on their text rather than dropped wholesale.
+#[derive(Default)]
+struct LogCursor {
+    last: Option<chrono::DateTime<chrono::Utc>>,
+    emitted_at_last: Vec<String>,
+    replay: Vec<String>,
+}
+
+impl LogCursor {
+    /// Called when a new stream opens; lines already emitted at the latest
+    /// timestamp are expected to come back once each
+    fn resume(&mut self) {
+        self.replay = self.emitted_at_last.clone();
+    }
+
+    /// Whether a line should be emitted, recording it if so
+    fn accept(&mut self, timestamp: Option<chrono::DateTime<chrono::Utc>>, text: &str) -> bool {
+        let Some(ts) = timestamp else {
+            return true;
+        };
+        match self.last {
+            Some(last) if ts < last => false,
+            Some(last) if ts == last => {
+                if let Some(pos) = self.replay.iter().position(|l| l == text) {
+                    self.replay.remove(pos);
+                    return false;
+                }
+                self.emitted_at_last.push(text.to_string());
+                true
+            }
+            _ => {
+                self.last = Some(ts);
+                self.emitted_at_last = vec![text.to_string()];
+                self.replay.clear();
+                true
+            }
+        }
+    }
+}
+
+async fn is_pod_running(pods: &Api<Pod>, pod_name: &str) -> bool {
+    matches!(
+        pods.get_opt(pod_name).await,
+        Ok(Some(pod)) if pod.status.and_then(|s| s.phase).as_deref() == Some("Running")
+    )
+}
+
+/// Start (or restart) reading a container's logs, emitting `container_logs_<stream_id>`.
+/// A followed stream re-opens across container restarts, emitting
+/// `log_stream_reconnected_<stream_id>`; other streams end once the log is read.
+/// An active pod watch ends it with `log_stream_ended_<stream_id>` once the pod is deleted.
+pub(crate) async fn start_container_log_stream(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    container_name: String,
+    stream_id: String,
+    options: LogStreamOptions,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+
+    // Timestamps let a reconnect resume where the previous stream stopped; they're
+    // stripped before emitting unless the user wants to see them
+    let keep_timestamps = options.timestamps;
+    let log_params = LogParams {
+        timestamps: true,
+        ..options.log_params(&container_name)
+    };
+    // A previous container has exited, so there's nothing to follow across restarts
+    let reconnect = options.follow && !options.previous;
+
+    let key = watch_key(window.label(), &format!("logs:{}", stream_id));
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::ContainerLogs {
+            cluster_id,
+            namespace,
+            pod_name: pod_name.clone(),
+            container_name,
+            stream_id: stream_id.clone(),
+            options,
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let event_name = format!("container_logs_{}", stream_id);
+        let mut cursor = LogCursor::default();
+        let mut window_closed = false;
+        let mut failed_attempts = 0;
+
+        loop {
+            // After a reconnect, resume from the last line we saw instead of re-tailing
+            let mut params = log_params.clone();
+            if let Some(last) = cursor.last {
+                params.tail_lines = None;
+                params.since_seconds = Some((chrono::Utc::now() - last).num_seconds().max(0) + 1);
+            }
+            cursor.resume();
+
+            let mut received = false;
+            match pods.log_stream(&pod_name, &params).await {
+                Ok(stream) => {
+                    let mut lines = stream.lines();
+                    loop {
+                        match lines.try_next().await {
+                            Ok(Some(line)) => {
+                                let (timestamp, text) = split_log_timestamp(&line);
+                                // `since_seconds` is coarse; drop lines we've already emitted
+                                if !cursor.accept(timestamp, text) {
+                                    continue;
+                                }
+                                received = true;
+
+                                let text = if keep_timestamps { line.as_str() } else { text };
+                                if let Err(e) = window.emit(&event_name, text.to_string()) {
+                                    println!("Failed to emit log line: {}", e);
+                                    window_closed = true;
+                                    break;
+                                }
+                            }
+                            Ok(None) => break,
+                            Err(e) => {
+                                println!("Error reading log line: {}", e);
+                                break;
+                            }
+                        }
                     }
                 }
                 Err(e) => {
-                    println!("Watch error: {}", e);
+                    println!("Failed to open log stream: {}", e);
+                }
+            }
+
+            if window_closed || !reconnect {
+                break;
+            }
+
+            // The stream ended (container restart, dropped connection, ...): keep
+            // following while the pod is still running
+            failed_attempts = if received { 0 } else { failed_attempts + 1 };
+            if failed_attempts >= MAX_LOG_RECONNECT_ATTEMPTS
+                || !is_pod_running(&pods, &pod_name).await
+            {
+                break;
+            }
+
+            tokio::time::sleep(LOG_RECONNECT_DELAY).await;
+
+            let marker = format!("log_stream_reconnected_{}", stream_id);
+            if window
+                .emit(&marker, chrono::Utc::now().to_rfc3339())
+                .is_err()
+            {
+                window_closed = true;
+                break;
+            }
+        }
+
+        // Cleanup; a stream that wasn't following has nothing to reconnect to
+        finish_watch(
+            &handles,
+            &registrations,
+            &key_clone,
+            window_closed || !reconnect,
+        );
+    });
+
+    // Store new handle
+    watcher_state
+        .insert(key, handle, registration)
+        .map_err(AppError::Internal)
+}
+
+#[tauri::command]
+pub async fn cluster_start_pod_watch(
+    cluster_id: String,
+    namespace: String,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    start_cluster_pod_watch(cluster_id, namespace, window, &state, &watcher_state).await
+}
+
+/// Pods in `namespace`, or in every namespace for `all`
+fn pods_api(client: Client, namespace: &str) -> Api<Pod> {
+    if namespace == "all" {
+        Api::all(client)
+    } else {
+        Api::namespaced(client, namespace)
+    }
+}
+
+/// Start (or restart) a pod watch for a window, emitting `pod_event`
+pub(crate) async fn start_cluster_pod_watch(
+    cluster_id: String,
+    namespace: String,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    use kube::runtime::watcher::Config as WatchConfig;
+
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let mut api = pods_api(client, &namespace);
+
+    let watch_name = format!("pod_watch:{}:{}", cluster_id, namespace);
+    let key = watch_key(window.label(), &watch_name);
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    // Deleted pods end any log streams following them
+    let watched_cluster_id = cluster_id.clone();
+    let watched_namespace = namespace.clone();
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::Pods {
+            cluster_id,
+            namespace,
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut window_closed = false;
+        let mut attempt = 0;
+
+        // The watcher retries on its own, but a stream that ends or keeps failing
+        // (expired token, dropped connection) is rebuilt with backoff
+        loop {
+            let mut stream = watcher(api.clone(), WatchConfig::default()).boxed();
+            let mut consecutive_errors = 0;
+
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(event) => {
+                        consecutive_errors = 0;
+                        if attempt > 0 {
+                            attempt = 0;
+                            if !emit_watch_status(&window, &watch_name, "connected", 0, None) {
+                                window_closed = true;
+                                break;
+                            }
+                        }
+
+                        let pod_event = match event {
+                            watcher::Event::Apply(pod) => PodEvent::Added(map_pod_to_summary(pod)),
+                            watcher::Event::Delete(pod) => {
+                                let summary = map_pod_to_summary(pod);
+                                let aborted = abort_pod_log_streams(
+                                    &handles,
+                                    &registrations,
+                                    &watched_cluster_id,
+                                    &summary.namespace,
+                                    &summary.name,
+                                );
+                                for (log_window, stream_id) in aborted {
+                                    let _ = log_window.emit(
+                                        &format!("log_stream_ended_{}", stream_id),
+                                        "Pod deleted",
+                                    );
+                                }
+                                PodEvent::Deleted(summary)
+                            }
+                            watcher::Event::InitApply(pod) => {
+                                PodEvent::Added(map_pod_to_summary(pod))
+                            }
+                            _ => continue,
+                        };
+
+                        if let Err(e) = window.emit("pod_event", pod_event) {
+                            println!("Failed to emit event: {}", e);
+                            window_closed = true;
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        println!("Watch error: {}", e);
+                        consecutive_errors += 1;
+                        if consecutive_errors >= MAX_CONSECUTIVE_WATCH_ERRORS {
+                            break;
+                        }
+                    }
                 }
             }
+
+            if window_closed {
+                break;
+            }
+
+            // Aborting the task cancels this sleep, so stopping the watch stays instant
+            attempt += 1;
+            let delay = reconnect_delay(attempt);
+            if !emit_watch_status(&window, &watch_name, "reconnecting", attempt, Some(delay)) {
+                window_closed = true;
+                break;
+            }
+            tokio::time::sleep(delay).await;
+
+            // Comes from the client cache, so credentials rotated in the saved config are
+            // only picked up once the cached client expires or is invalidated; keep the
+            // old client if this fails
+            match create_client_for_cluster(&watched_cluster_id, &window.state()).await {
+                Ok(client) => api = pods_api(client, &watched_namespace),
+                Err(e) => println!("Failed to recreate client for pod watch: {}", e),
+            }
         }
 
         // Cleanup
-        if let Ok(mut watchers) = watchers.lock() {
-            watchers.remove(&key_clone);
-        } else {
-            eprintln!("Warning: failed to clean up pod watcher state");
+        finish_watch(&handles, &registrations, &key_clone, window_closed);
+    });
+
+    // Store new handle
+    watcher_state
+        .insert(key, handle, registration)
+        .map_err(AppError::Internal)
+}
+
+/// Watch one pod for a detail view, emitting `pod_detail_event_<stream_id>` with the
+/// full summary on every change. The watch ends after a final Deleted event when
+/// the pod is removed; stop it earlier with `cluster_stop_watch("pod_detail:<stream_id>")`.
+#[tauri::command]
+pub async fn cluster_watch_pod(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    stream_id: String,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    start_pod_detail_watch(
+        cluster_id,
+        namespace,
+        pod_name,
+        stream_id,
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+pub(crate) async fn start_pod_detail_watch(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    stream_id: String,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    use kube::runtime::watcher::Config as WatchConfig;
+
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let mut api: Api<Pod> = Api::namespaced(client, &namespace);
+    let config = WatchConfig::default().fields(&format!("metadata.name={}", pod_name));
+
+    let watch_name = format!("pod_detail:{}", stream_id);
+    let key = watch_key(window.label(), &watch_name);
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let watched_cluster_id = cluster_id.clone();
+    let watched_namespace = namespace.clone();
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::Pod {
+            cluster_id,
+            namespace,
+            pod_name,
+            stream_id: stream_id.clone(),
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let event_name = format!("pod_detail_event_{}", stream_id);
+        let mut finished = false;
+        let mut attempt = 0;
+
+        // Same backoff as the pod list watch: a stream that ends or keeps failing is
+        // rebuilt, until the pod is deleted or the window goes away
+        loop {
+            let mut stream = watcher(api.clone(), config.clone()).boxed();
+            let mut consecutive_errors = 0;
+
+            while let Some(result) = stream.next().await {
+                let (pod_event, deleted) = match result {
+                    Ok(event) => {
+                        consecutive_errors = 0;
+                        if attempt > 0 {
+                            attempt = 0;
+                            if !emit_watch_status(&window, &watch_name, "connected", 0, None) {
+                                finished = true;
+                                break;
+                            }
+                        }
+
+                        match event {
+                            watcher::Event::InitApply(pod) => {
+                                (PodEvent::Added(map_pod_to_summary(pod)), false)
+                            }
+                            watcher::Event::Apply(pod) => {
+                                (PodEvent::Modified(map_pod_to_summary(pod)), false)
+                            }
+                            watcher::Event::Delete(pod) => {
+                                (PodEvent::Deleted(map_pod_to_summary(pod)), true)
+                            }
+                            _ => continue,
+                        }
+                    }
+                    Err(e) => {
+                        println!("Pod watch error: {}", e);
+                        consecutive_errors += 1;
+                        if consecutive_errors >= MAX_CONSECUTIVE_WATCH_ERRORS {
+                            break;
+                        }
+                        continue;
+                    }
+                };
+
+                if let Err(e) = window.emit(&event_name, pod_event) {
+                    println!("Failed to emit pod event: {}", e);
+                    finished = true;
+                    break;
+                }
+                if deleted {
+                    finished = true;
+                    break;
+                }
+            }
+
+            if finished {
+                break;
+            }
+
+            // Aborting the task cancels this sleep, so stopping the watch stays instant
+            attempt += 1;
+            let delay = reconnect_delay(attempt);
+            if !emit_watch_status(&window, &watch_name, "reconnecting", attempt, Some(delay)) {
+                break;
+            }
+            tokio::time::sleep(delay).await;
+
+            // Keep the old client if this fails
+            match create_client_for_cluster(&watched_cluster_id, &window.state()).await {
+                Ok(client) => api = Api::namespaced(client, &watched_namespace),
+                Err(e) => println!("Failed to recreate client for pod detail watch: {}", e),
+            }
         }
+
+        // A deleted pod (or a closed window) has nothing left to reconnect to
+        finish_watch(&handles, &registrations, &key_clone, true);
     });
 
     // Store new handle
-    {
-        let mut watchers = watcher_state
-            .0
-            .lock()
-            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
-        watchers.insert(key, handle);
+    watcher_state
+        .insert(key, handle, registration)
+        .map_err(AppError::Internal)
+}
+
+/// Input side of a running exec session, looked up by the same key as its task
+pub struct ExecSession {
+    input: futures::channel::mpsc::UnboundedSender<Vec<u8>>,
+    resize: Option<futures::channel::mpsc::Sender<TerminalSize>>,
+}
+
+/// Exec sessions by `watch_key(window, "exec:<stream_id>")`
+#[derive(Default)]
+pub struct ExecSessionsState(pub Arc<Mutex<HashMap<String, ExecSession>>>);
+
+/// Removes an exec session's entry when its task ends or is aborted, unless a newer
+/// session has taken over the key by then
+struct ExecSessionGuard {
+    sessions: Arc<Mutex<HashMap<String, ExecSession>>>,
+    key: String,
+    input: futures::channel::mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Drop for ExecSessionGuard {
+    fn drop(&mut self) {
+        if let Ok(mut sessions) = self.sessions.lock() {
+            if sessions
+                .get(&self.key)
+                .is_some_and(|session| session.input.same_receiver(&self.input))
+            {
+                sessions.remove(&self.key);
+            }
+        }
     }
+}
+
+/// Decode a read of terminal output. A character split across reads is held back in
+/// `pending` until the rest of it arrives instead of becoming U+FFFD.
+fn decode_utf8_chunk(pending: &mut Vec<u8>, chunk: &[u8]) -> String {
+    pending.extend_from_slice(chunk);
+    let split = pending.len() - incomplete_utf8_tail(pending);
+    let text = String::from_utf8_lossy(&pending[..split]).into_owned();
+    pending.drain(..split);
+    text
+}
+
+/// Length of a multi-byte character cut off at the end of `bytes`, if any
+fn incomplete_utf8_tail(bytes: &[u8]) -> usize {
+    for back in 1..=bytes.len().min(3) {
+        let byte = bytes[bytes.len() - back];
+        // Skip continuation bytes back to the character's lead byte
+        if byte & 0xC0 == 0x80 {
+            continue;
+        }
+        let width = match byte {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+        return if width > back { back } else { 0 };
+    }
+    0
+}
+
+/// Run a command (default `/bin/sh`) in a container with a TTY. Output is emitted as
+/// `exec_output_<stream_id>` and `exec_ended_<stream_id>` fires once the process exits;
+/// input and resizes go through `cluster_exec_write` and `cluster_exec_resize`.
+#[tauri::command]
+pub async fn cluster_exec_pod(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    container_name: String,
+    command: Option<Vec<String>>,
+    stream_id: String,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+    exec_state: State<'_, ExecSessionsState>,
+) -> Result<(), AppError> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+
+    let command = command
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| vec!["/bin/sh".to_string()]);
+    // A TTY merges stderr into stdout; the API rejects requesting both
+    let params = AttachParams::interactive_tty().container(container_name);
+
+    let mut attached = pods
+        .exec(&pod_name, command, &params)
+        .await
+        .map_err(|e| AppError::from(e).context(format!("Failed to exec in pod '{}'", pod_name)))?;
+    let mut stdout = attached
+        .stdout()
+        .ok_or_else(|| AppError::Internal("Exec session has no stdout".to_string()))?;
+    let mut stdin = attached
+        .stdin()
+        .ok_or_else(|| AppError::Internal("Exec session has no stdin".to_string()))?;
+    let status = attached.take_status();
+
+    let key = watch_key(window.label(), &format!("exec:{}", stream_id));
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let (input_tx, mut input_rx) = futures::channel::mpsc::unbounded::<Vec<u8>>();
+    let session_guard = ExecSessionGuard {
+        sessions: exec_state.0.clone(),
+        key: key.clone(),
+        input: input_tx.clone(),
+    };
+    exec_state
+        .0
+        .lock()
+        .map_err(|e| AppError::Internal(format!("Exec state lock poisoned: {}", e)))?
+        .insert(
+            key.clone(),
+            ExecSession {
+                input: input_tx,
+                resize: attached.terminal_size(),
+            },
+        );
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        // Keep the process handle alive for as long as the session runs, and forget
+        // the session however the task ends
+        let _attached = attached;
+        let _session_guard = session_guard;
+        let output_event = format!("exec_output_{}", stream_id);
+
+        let output = async {
+            let mut buf = vec![0u8; 8192];
+            let mut pending = Vec::new();
+            loop {
+                match stdout.read(&mut buf).await {
+                    Ok(0) => {
+                        if !pending.is_empty() {
+                            let text = String::from_utf8_lossy(&pending).into_owned();
+                            let _ = window.emit(&output_event, text);
+                        }
+                        break;
+                    }
+                    Ok(n) => {
+                        let text = decode_utf8_chunk(&mut pending, &buf[..n]);
+                        if text.is_empty() {
+                            continue;
+                        }
+                        if let Err(e) = window.emit(&output_event, text) {
+                            println!("Failed to emit exec output: {}", e);
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        println!("Error reading exec output: {}", e);
+                        break;
+                    }
+                }
+            }
+        };
+        let input = async {
+            while let Some(bytes) = input_rx.next().await {
+                if let Err(e) = stdin.write_all(&bytes).await {
+                    println!("Failed to write exec input: {}", e);
+                    break;
+                }
+            }
+        };
+        futures::future::select(Box::pin(output), Box::pin(input)).await;
+
+        let message = match status {
+            Some(status) => status
+                .await
+                .and_then(|s| s.message.or(s.status))
+                .unwrap_or_else(|| "Process exited".to_string()),
+            None => "Process exited".to_string(),
+        };
+        let _ = window.emit(&format!("exec_ended_{}", stream_id), message);
 
+        // An exited process can't be reconnected, so there's no registration to keep
+        finish_watch(&handles, &registrations, &key_clone, true);
+    });
+
+    // Store new handle; exec sessions aren't registered for `reconnect_watches`
+    watcher_state
+        .0
+        .lock()
+        .map_err(|e| AppError::Internal(format!("Watcher state lock poisoned: {}", e)))?
+        .insert(key, handle);
     Ok(())
 }
+
+/// Send keystrokes to an exec session started by `cluster_exec_pod`
+#[tauri::command]
+pub fn cluster_exec_write(
+    stream_id: String,
+    data: String,
+    window: Window,
+    exec_state: State<'_, ExecSessionsState>,
+) -> Result<(), AppError> {
+    let key = watch_key(window.label(), &format!("exec:{}", stream_id));
+    let sessions = exec_state
+        .0
+        .lock()
+        .map_err(|e| AppError::Internal(format!("Exec state lock poisoned: {}", e)))?;
+    let session = sessions
+        .get(&key)
+        .ok_or_else(|| AppError::NotFound(format!("No exec session '{}'", stream_id)))?;
+
+    session
+        .input
+        .unbounded_send(data.into_bytes())
+        .map_err(|e| AppError::Internal(format!("Failed to send exec input: {}", e)))
+}
+
+/// Resize the TTY of an exec session, e.g. when the terminal view changes size
+#[tauri::command]
+pub fn cluster_exec_resize(
+    stream_id: String,
+    cols: u16,
+    rows: u16,
+    window: Window,
+    exec_state: State<'_, ExecSessionsState>,
+) -> Result<(), AppError> {
+    let key = watch_key(window.label(), &format!("exec:{}", stream_id));
+    let mut sessions = exec_state
+        .0
+        .lock()
+        .map_err(|e| AppError::Internal(format!("Exec state lock poisoned: {}", e)))?;
+    let resize = sessions
+        .get_mut(&key)
+        .ok_or_else(|| AppError::NotFound(format!("No exec session '{}'", stream_id)))?
+        .resize
+        .as_mut()
+        .ok_or_else(|| AppError::Internal("Exec session has no terminal".to_string()))?;
+
+    resize
+        .try_send(TerminalSize {
+            width: cols,
+            height: rows,
+        })
+        .map_err(|e| AppError::Internal(format!("Failed to resize terminal: {}", e)))
+}
+
+/// Give up on a port-forward's listener after this many accept errors in a row
+const MAX_CONSECUTIVE_ACCEPT_ERRORS: u32 = 10;
+/// Pause after a failed accept, multiplied by the number of consecutive failures
+const ACCEPT_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Aborts a port-forward's connection tasks once its listener task ends or is aborted
+struct ForwardedConnections(Arc<Mutex<Vec<tauri::async_runtime::JoinHandle<()>>>>);
+
+impl Drop for ForwardedConnections {
+    fn drop(&mut self) {
+        if let Ok(mut connections) = self.0.lock() {
+            for connection in connections.drain(..) {
+                connection.abort();
+            }
+        }
+    }
+}
+
+/// Pipe one local connection to the pod's port until either side closes
+async fn forward_connection(
+    pods: &Api<Pod>,
+    pod_name: &str,
+    remote_port: u16,
+    socket: &mut tokio::net::TcpStream,
+) -> Result<(), String> {
+    let mut forwarder = pods
+        .portforward(pod_name, &[remote_port])
+        .await
+        .map_err(|e| format!("Failed to open port-forward: {}", e))?;
+    let mut upstream = forwarder
+        .take_stream(remote_port)
+        .ok_or("Port-forward has no stream for the port")?;
+
+    tokio::io::copy_bidirectional(socket, &mut upstream)
+        .await
+        .map_err(|e| format!("Port-forward connection failed: {}", e))?;
+
+    drop(upstream);
+    forwarder
+        .join()
+        .await
+        .map_err(|e| format!("Port-forward ended with error: {}", e))
+}
+
+fn port_forward_key(
+    window_label: &str,
+    cluster_id: &str,
+    pod_name: &str,
+    remote_port: u16,
+) -> String {
+    watch_key(
+        window_label,
+        &format!("portforward:{}:{}:{}", cluster_id, pod_name, remote_port),
+    )
+}
+
+/// Forward a local port (0 picks a free one) to a pod port and return the bound local
+/// port. Each accepted connection gets its own port-forward stream. If the pod is
+/// deleted or the local listener keeps failing, open connections are closed and
+/// `port_forward_ended_<local_port>` fires with the reason.
+#[tauri::command]
+pub async fn cluster_port_forward_pod(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    remote_port: u16,
+    local_port: Option<u16>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<u16, AppError> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+
+    pods.get(&pod_name)
+        .await
+        .map_err(|e| AppError::from(e).context(format!("Failed to get pod '{}'", pod_name)))?;
+
+    let requested_port = local_port.unwrap_or(0);
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", requested_port))
+        .await
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::AddrInUse => {
+                AppError::Conflict(format!("Local port {} is already in use", requested_port))
+            }
+            _ => AppError::from(e).context(format!("Failed to bind local port {}", requested_port)),
+        })?;
+    let bound_port = listener
+        .local_addr()
+        .map_err(|e| AppError::from(e).context("Failed to read bound port"))?
+        .port();
+
+    let key = port_forward_key(window.label(), &cluster_id, &pod_name, remote_port);
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let connections = ForwardedConnections(Arc::new(Mutex::new(Vec::new())));
+
+        let accept_loop = async {
+            let mut accept_errors = 0;
+            loop {
+                let mut socket = match listener.accept().await {
+                    Ok((socket, _)) => {
+                        accept_errors = 0;
+                        socket
+                    }
+                    Err(e) => {
+                        // Errors like running out of file descriptors repeat at once;
+                        // back off, and give up if the listener keeps failing
+                        println!("Port-forward accept error: {}", e);
+                        accept_errors += 1;
+                        if accept_errors >= MAX_CONSECUTIVE_ACCEPT_ERRORS {
+                            return format!("Listener failed: {}", e);
+                        }
+                        tokio::time::sleep(ACCEPT_RETRY_DELAY * accept_errors).await;
+                        continue;
+                    }
+                };
+                let pods = pods.clone();
+                let pod_name = pod_name.clone();
+                let connection = tauri::async_runtime::spawn(async move {
+                    if let Err(e) =
+                        forward_connection(&pods, &pod_name, remote_port, &mut socket).await
+                    {
+                        println!("{}", e);
+                    }
+                });
+                if let Ok(mut active) = connections.0.lock() {
+                    active.retain(|c| !c.inner().is_finished());
+                    active.push(connection);
+                }
+            }
+        };
+
+        let config = watcher::Config::default().fields(&format!("metadata.name={}", pod_name));
+        let pod_deleted = async {
+            let mut events = watcher(pods.clone(), config).boxed();
+            while let Some(event) = events.next().await {
+                if let Ok(watcher::Event::Delete(_)) = event {
+                    break;
+                }
+            }
+            "Pod deleted".to_string()
+        };
+
+        let reason = futures::future::select(Box::pin(accept_loop), Box::pin(pod_deleted))
+            .await
+            .factor_first()
+            .0;
+        let _ = window.emit(&format!("port_forward_ended_{}", bound_port), reason);
+
+        // Cleanup: dropping `connections` closes every forwarded connection
+        drop(connections);
+        finish_watch(&handles, &registrations, &key_clone, true);
+    });
+
+    // Store new handle; port-forwards aren't registered for `reconnect_watches`
+    watcher_state
+        .0
+        .lock()
+        .map_err(|e| AppError::Internal(format!("Watcher state lock poisoned: {}", e)))?
+        .insert(key, handle);
+    Ok(bound_port)
+}
+
+/// Stop a port-forward started by `cluster_port_forward_pod`, closing its connections
+#[tauri::command]
+pub fn cluster_stop_port_forward(
+    cluster_id: String,
+    pod_name: String,
+    remote_port: u16,
+    window: Window,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), AppError> {
+    watcher_state
+        .abort(&port_forward_key(
+            window.label(),
+            &cluster_id,
+            &pod_name,
+            remote_port,
+        ))
+        .map_err(AppError::Internal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(handler: serde_json::Value) -> k8s_openapi::api::core::v1::Probe {
+        serde_json::from_value(handler).unwrap()
+    }
+
+    #[test]
+    fn test_http_probe_details() {
+        let with_host = probe(serde_json::json!({
+            "httpGet": { "host": "10.0.0.5", "port": 8080, "path": "/healthz", "scheme": "HTTPS" }
+        }));
+        assert_eq!(
+            probe_to_info("liveness", &with_host).details,
+            "https://10.0.0.5:8080/healthz"
+        );
+
+        let without_host = probe(serde_json::json!({
+            "httpGet": { "port": 8080, "path": "/healthz" }
+        }));
+        assert_eq!(
+            probe_to_info("readiness", &without_host).details,
+            "http://<pod IP>:8080/healthz"
+        );
+
+        let https_without_host = probe(serde_json::json!({
+            "httpGet": { "port": "metrics", "scheme": "HTTPS" }
+        }));
+        assert_eq!(
+            probe_to_info("startup", &https_without_host).details,
+            "https://<pod IP>:metrics/"
+        );
+
+        let tcp_without_host = probe(serde_json::json!({ "tcpSocket": { "port": 5432 } }));
+        assert_eq!(
+            probe_to_info("liveness", &tcp_without_host).details,
+            "<pod IP>:5432"
+        );
+    }
+
+    #[test]
+    fn test_tcp_probe_details() {
+        let with_host = probe(serde_json::json!({
+            "tcpSocket": { "host": "db.internal", "port": 5432 }
+        }));
+        assert_eq!(
+            probe_to_info("liveness", &with_host).details,
+            "db.internal:5432"
+        );
+
+        let without_host = probe(serde_json::json!({ "tcpSocket": { "port": "grpc" } }));
+        assert_eq!(
+            probe_to_info("liveness", &without_host).details,
+            "<pod IP>:grpc"
+        );
+    }
+
+    #[test]
+    fn test_init_container_statuses_follow_spec_order() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web" },
+            "spec": {
+                "initContainers": [
+                    { "name": "wait-db", "image": "busybox" },
+                    { "name": "migrate", "image": "migrate:1" }
+                ],
+                "containers": [{ "name": "app" }]
+            },
+            "status": {
+                "initContainerStatuses": [
+                    {
+                        "name": "migrate",
+                        "image": "migrate:1",
+                        "imageID": "",
+                        "ready": false,
+                        "restartCount": 3,
+                        "state": { "waiting": { "reason": "CrashLoopBackOff" } },
+                        "lastState": { "terminated": { "exitCode": 1, "reason": "Error" } }
+                    },
+                    {
+                        "name": "wait-db",
+                        "image": "busybox",
+                        "imageID": "",
+                        "ready": true,
+                        "restartCount": 0,
+                        "state": { "terminated": { "exitCode": 0, "reason": "Completed" } }
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let statuses = map_init_container_statuses(&pod);
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].name, "wait-db");
+        assert_eq!(statuses[0].state, "Terminated: Completed");
+        assert_eq!(statuses[0].exit_code, Some(0));
+        assert_eq!(statuses[1].name, "migrate");
+        assert_eq!(statuses[1].state, "Waiting: CrashLoopBackOff");
+        assert_eq!(statuses[1].restart_count, 3);
+        assert_eq!(statuses[1].exit_code, None);
+        assert_eq!(statuses[1].last_terminated_reason.as_deref(), Some("Error"));
+        assert_eq!(statuses[1].last_terminated_exit_code, Some(1));
+    }
+
+    #[test]
+    fn test_init_container_statuses_without_status_yet() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web" },
+            "spec": {
+                "initContainers": [{ "name": "setup", "image": "setup:1" }],
+                "containers": [{ "name": "app" }]
+            }
+        }))
+        .unwrap();
+
+        let statuses = map_init_container_statuses(&pod);
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].state, "Unknown");
+        assert!(!statuses[0].ready);
+    }
+
+    #[test]
+    fn test_summary_includes_completed_init_container() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web", "namespace": "default" },
+            "spec": {
+                "initContainers": [{ "name": "migrate", "image": "migrate:1" }],
+                "containers": [{ "name": "app", "image": "app:1" }]
+            },
+            "status": {
+                "phase": "Running",
+                "initContainerStatuses": [{
+                    "name": "migrate",
+                    "image": "migrate:1",
+                    "imageID": "",
+                    "ready": true,
+                    "restartCount": 0,
+                    "state": { "terminated": { "exitCode": 0, "reason": "Completed" } }
+                }],
+                "containerStatuses": [{
+                    "name": "app",
+                    "image": "app:1",
+                    "imageID": "",
+                    "ready": true,
+                    "restartCount": 0,
+                    "state": { "running": {} }
+                }]
+            }
+        }))
+        .unwrap();
+
+        let summary = map_pod_to_summary(pod);
+
+        assert_eq!(summary.container_details.len(), 1);
+        assert_eq!(summary.container_details[0].state, "Running");
+        assert_eq!(summary.init_container_details.len(), 1);
+        let init = &summary.init_container_details[0];
+        assert_eq!(init.name, "migrate");
+        assert_eq!(init.state, "Terminated: Completed");
+        assert!(init.ready);
+        assert!(summary.ephemeral_container_details.is_empty());
+    }
+
+    #[test]
+    fn test_split_log_timestamp() {
+        let (ts, text) = split_log_timestamp("2024-05-01T10:00:00.123456789Z hello world");
+        assert_eq!(
+            ts.unwrap()
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            "2024-05-01T10:00:00.123Z"
+        );
+        assert_eq!(text, "hello world");
+
+        assert_eq!(
+            split_log_timestamp("no timestamp here"),
+            (None, "no timestamp here")
+        );
+        assert_eq!(split_log_timestamp("single"), (None, "single"));
+    }
+
+    #[test]
+    fn test_log_cursor_keeps_lines_sharing_a_timestamp() {
+        let t0 = chrono::DateTime::parse_from_rfc3339("2024-05-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let t1 = t0 + chrono::Duration::seconds(1);
+
+        let mut cursor = LogCursor::default();
+        assert!(cursor.accept(Some(t0), "a"));
+        assert!(cursor.accept(Some(t1), "b"));
+        assert!(cursor.accept(Some(t1), "c"));
+        assert!(cursor.accept(Some(t1), "c"));
+        assert!(cursor.accept(None, "untimed"));
+
+        // A reconnect replays from before the last timestamp
+        cursor.resume();
+        assert!(!cursor.accept(Some(t0), "a"));
+        assert!(!cursor.accept(Some(t1), "b"));
+        assert!(!cursor.accept(Some(t1), "c"));
+        assert!(!cursor.accept(Some(t1), "c"));
+        assert!(cursor.accept(Some(t1), "d"));
+        assert!(cursor.accept(Some(t1 + chrono::Duration::seconds(1)), "e"));
+    }
+
+    #[test]
+    fn test_previous_log_params() {
+        let settings = AppSettings {
+            default_log_timestamps: true,
+            ..AppSettings::default()
+        };
+        let params = previous_log_params("app", None, &settings);
+        assert!(params.previous);
+        assert!(params.timestamps);
+        assert_eq!(params.container.as_deref(), Some("app"));
+        assert_eq!(params.tail_lines, Some(settings.log_tail_lines));
+
+        let params = previous_log_params("app", Some(10), &settings);
+        assert_eq!(params.tail_lines, Some(10));
+    }
+
+    #[test]
+    fn test_log_stream_options_defaults_and_serde() {
+        let defaults = LogStreamOptions::default();
+        assert!(defaults.follow);
+        assert!(!defaults.previous);
+        assert!(!defaults.timestamps);
+        assert_eq!(defaults.tail_lines, None);
+        assert_eq!(defaults.since_seconds, None);
+
+        // Omitted fields keep their defaults
+        let empty: LogStreamOptions = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(empty, defaults);
+
+        let options: LogStreamOptions = serde_json::from_value(serde_json::json!({
+            "tail_lines": 100,
+            "since_seconds": 3600,
+            "previous": true,
+            "follow": false,
+        }))
+        .unwrap();
+        assert_eq!(
+            options,
+            LogStreamOptions {
+                tail_lines: Some(100),
+                since_seconds: Some(3600),
+                timestamps: false,
+                previous: true,
+                follow: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_log_stream_options_from_settings() {
+        let settings = AppSettings {
+            default_log_timestamps: true,
+            ..AppSettings::default()
+        };
+        let options = LogStreamOptions::from_settings(&settings);
+        assert_eq!(options.tail_lines, Some(settings.log_tail_lines));
+        assert!(options.timestamps);
+        assert!(options.follow);
+        assert!(!options.previous);
+    }
+
+    #[test]
+    fn test_log_stream_options_log_params() {
+        let params = LogStreamOptions {
+            tail_lines: Some(50),
+            since_seconds: Some(60),
+            timestamps: true,
+            previous: true,
+            follow: false,
+        }
+        .log_params("app");
+        assert_eq!(params.container.as_deref(), Some("app"));
+        assert_eq!(params.tail_lines, Some(50));
+        assert_eq!(params.since_seconds, Some(60));
+        assert!(params.timestamps);
+        assert!(params.previous);
+        assert!(!params.follow);
+
+        let params = LogStreamOptions::default().log_params("sidecar");
+        assert_eq!(params.container.as_deref(), Some("sidecar"));
+        assert_eq!(params.tail_lines, None);
+        assert!(params.follow);
+        assert!(!params.previous);
+    }
+
+    #[test]
+    fn test_labeled_previous_logs() {
+        assert_eq!(
+            labeled_previous_logs("app", "panic: boom\nexit 1\n"),
+            vec![
+                "--- previous container (app) ---",
+                "panic: boom",
+                "exit 1",
+                "--- current container (app) ---",
+            ]
+        );
+        assert_eq!(labeled_previous_logs("app", "").len(), 2);
+    }
+
+    fn pod_summary(namespace: &str, name: &str, created: &str, restarts: i32) -> PodSummary {
+        map_pod_to_summary(
+            serde_json::from_value(serde_json::json!({
+                "metadata": {
+                    "name": name,
+                    "namespace": namespace,
+                    "creationTimestamp": created
+                },
+                "spec": { "containers": [{ "name": "app" }] },
+                "status": {
+                    "containerStatuses": [{
+                        "name": "app",
+                        "image": "app:1",
+                        "imageID": "",
+                        "ready": true,
+                        "restartCount": restarts
+                    }]
+                }
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_sort_pod_summaries() {
+        let mut summaries = vec![
+            pod_summary("b", "web", "2024-01-02T00:00:00Z", 0),
+            pod_summary("a", "worker", "2024-01-03T00:00:00Z", 7),
+            pod_summary("a", "api", "2024-01-01T00:00:00Z", 2),
+        ];
+
+        sort_pod_summaries(&mut summaries, "restarts").unwrap();
+        assert_eq!(names(&summaries), ["worker", "api", "web"]);
+
+        sort_pod_summaries(&mut summaries, "age").unwrap();
+        assert_eq!(names(&summaries), ["api", "web", "worker"]);
+
+        sort_pod_summaries(&mut summaries, "name").unwrap();
+        assert_eq!(names(&summaries), ["api", "worker", "web"]);
+    }
+
+    #[test]
+    fn test_sort_pod_summaries_rejects_unknown_field() {
+        let mut summaries = vec![pod_summary("a", "api", "2024-01-01T00:00:00Z", 0)];
+        assert!(matches!(
+            sort_pod_summaries(&mut summaries, "cpu"),
+            Err(AppError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_utf8_chunk_carries_split_characters() {
+        let text = "héllo → 日本";
+        let bytes = text.as_bytes();
+        let mut pending = Vec::new();
+        let mut decoded = String::new();
+        // Feed one byte at a time so every multi-byte character is split
+        for byte in bytes {
+            decoded.push_str(&decode_utf8_chunk(&mut pending, std::slice::from_ref(byte)));
+        }
+        assert_eq!(decoded, text);
+        assert!(pending.is_empty());
+
+        // "→" is three bytes; cut after the first two
+        let arrow = "→".as_bytes();
+        assert_eq!(
+            decode_utf8_chunk(&mut pending, &[b'a', arrow[0], arrow[1]]),
+            "a"
+        );
+        assert_eq!(pending, &arrow[..2]);
+        assert_eq!(decode_utf8_chunk(&mut pending, &arrow[2..]), "→");
+    }
+
+    #[test]
+    fn test_decode_utf8_chunk_replaces_invalid_bytes() {
+        let mut pending = Vec::new();
+        assert_eq!(
+            decode_utf8_chunk(&mut pending, &[b'a', 0xFF, b'b']),
+            "a\u{FFFD}b"
+        );
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_exec_session_guard_removes_only_its_session() {
+        let sessions = Arc::new(Mutex::new(HashMap::new()));
+        let session = |input| ExecSession {
+            input,
+            resize: None,
+        };
+
+        let (first, _first_rx) = futures::channel::mpsc::unbounded();
+        sessions
+            .lock()
+            .unwrap()
+            .insert("w:exec:s1".to_string(), session(first.clone()));
+        drop(ExecSessionGuard {
+            sessions: sessions.clone(),
+            key: "w:exec:s1".to_string(),
+            input: first,
+        });
+        assert!(sessions.lock().unwrap().is_empty());
+
+        // A restarted session with the same key outlives the old task's guard
+        let (old, _old_rx) = futures::channel::mpsc::unbounded();
+        let (new, _new_rx) = futures::channel::mpsc::unbounded();
+        sessions
+            .lock()
+            .unwrap()
+            .insert("w:exec:s1".to_string(), session(new));
+        drop(ExecSessionGuard {
+            sessions: sessions.clone(),
+            key: "w:exec:s1".to_string(),
+            input: old,
+        });
+        assert!(sessions.lock().unwrap().contains_key("w:exec:s1"));
+    }
+}
@@ -0,0 +1,239 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::pod::{fetch_events_matching, PodEventInfo};
+use crate::k8s::watcher::{finish_watch, watch_key, WatchRegistration, WatchSpec, WatcherState};
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use kube::runtime::watcher;
+use kube::Api;
+use tauri::{Emitter, State, Window};
+
+/// How often provisioning events are re-checked while the claim itself is unchanged
+const PVC_EVENT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Binding state of a PVC plus the events explaining it, emitted as
+/// `pvc_progress_<stream_id>`
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct PvcProgress {
+    pub name: String,
+    pub namespace: String,
+    pub phase: String,
+    pub storage_class: Option<String>,
+    pub volume_name: Option<String>,
+    pub requested: Option<String>,
+    pub bound: bool,
+    /// Provisioning events for the claim, most recent first
+    pub events: Vec<PodEventInfo>,
+}
+
+fn pvc_progress(pvc: &PersistentVolumeClaim, events: Vec<PodEventInfo>) -> PvcProgress {
+    let phase = pvc
+        .status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Pending".to_string());
+    let spec = pvc.spec.as_ref();
+
+    PvcProgress {
+        name: pvc.metadata.name.clone().unwrap_or_default(),
+        namespace: pvc.metadata.namespace.clone().unwrap_or_default(),
+        bound: phase == "Bound",
+        phase,
+        storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+        volume_name: spec.and_then(|s| s.volume_name.clone()),
+        requested: spec
+            .and_then(|s| s.resources.as_ref())
+            .and_then(|r| r.requests.as_ref())
+            .and_then(|r| r.get("storage"))
+            .map(|q| q.0.clone()),
+        events,
+    }
+}
+
+enum PvcUpdate {
+    Watch(Result<watcher::Event<PersistentVolumeClaim>, watcher::Error>),
+    Poll,
+}
+
+#[tauri::command]
+pub async fn cluster_watch_pvc(
+    cluster_id: String,
+    namespace: String,
+    pvc_name: String,
+    stream_id: String,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), String> {
+    start_pvc_watch(
+        cluster_id,
+        namespace,
+        pvc_name,
+        stream_id,
+        window,
+        &state,
+        &watcher_state,
+    )
+    .await
+}
+
+/// Start (or restart) watching a PVC until it binds, emitting `pvc_progress_<stream_id>`
+/// on every phase or event change. The watch completes once the claim is Bound, deleted,
+/// or found not to exist.
+pub(crate) async fn start_pvc_watch(
+    cluster_id: String,
+    namespace: String,
+    pvc_name: String,
+    stream_id: String,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
+    if api
+        .get_opt(&pvc_name)
+        .await
+        .map_err(|e| format!("Failed to get PVC: {}", e))?
+        .is_none()
+    {
+        return Err(format!("PersistentVolumeClaim '{}' not found", pvc_name));
+    }
+
+    let config = watcher::Config::default().fields(&format!("metadata.name={}", pvc_name));
+    let event_selector = format!(
+        "involvedObject.kind=PersistentVolumeClaim,involvedObject.name={}",
+        pvc_name
+    );
+    let key = watch_key(window.label(), &format!("pvc_watch:{}", stream_id));
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let registration = WatchRegistration {
+        window: window.clone(),
+        spec: WatchSpec::Pvc {
+            cluster_id,
+            namespace: namespace.clone(),
+            pvc_name,
+            stream_id: stream_id.clone(),
+        },
+    };
+
+    let handles = watcher_state.0.clone();
+    let registrations = watcher_state.1.clone();
+    let key_clone = key.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let event_name = format!("pvc_progress_{}", stream_id);
+        let polls = futures::stream::unfold((), |_| async {
+            tokio::time::sleep(PVC_EVENT_POLL_INTERVAL).await;
+            Some((PvcUpdate::Poll, ()))
+        });
+        let mut updates = futures::stream::select(
+            watcher(api, config).map(PvcUpdate::Watch).boxed(),
+            polls.boxed(),
+        );
+
+        let mut current: Option<PersistentVolumeClaim> = None;
+        let mut last_emitted: Option<PvcProgress> = None;
+        let mut listed = false;
+
+        while let Some(update) = updates.next().await {
+            match update {
+                PvcUpdate::Watch(Ok(watcher::Event::Apply(pvc))) => current = Some(pvc),
+                PvcUpdate::Watch(Ok(watcher::Event::InitApply(pvc))) => {
+                    listed = true;
+                    current = Some(pvc);
+                }
+                PvcUpdate::Watch(Ok(watcher::Event::Init)) => {
+                    listed = false;
+                    continue;
+                }
+                // The (re)list came back empty: the claim doesn't exist (anymore)
+                PvcUpdate::Watch(Ok(watcher::Event::InitDone)) if !listed => break,
+                PvcUpdate::Watch(Ok(watcher::Event::Delete(_))) => break,
+                PvcUpdate::Watch(Ok(_)) => continue,
+                PvcUpdate::Watch(Err(e)) => {
+                    println!("PVC watch error: {}", e);
+                    continue;
+                }
+                PvcUpdate::Poll => {}
+            }
+
+            let Some(pvc) = current.as_ref() else {
+                continue;
+            };
+            let events = fetch_events_matching(client.clone(), &namespace, &event_selector)
+                .await
+                .unwrap_or_else(|e| {
+                    println!("Failed to fetch PVC events: {}", e);
+                    vec![]
+                });
+            let progress = pvc_progress(pvc, events);
+            if last_emitted.as_ref() == Some(&progress) {
+                continue;
+            }
+
+            if let Err(e) = window.emit(&event_name, progress.clone()) {
+                println!("Failed to emit PVC progress: {}", e);
+                break;
+            }
+            if progress.bound {
+                break;
+            }
+            last_emitted = Some(progress);
+        }
+
+        // A bound, deleted or missing claim (or a closed window) has nothing left to reconnect to
+        finish_watch(&handles, &registrations, &key_clone, true);
+    });
+
+    // Store new handle
+    watcher_state.insert(key, handle, registration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        PersistentVolumeClaimSpec, PersistentVolumeClaimStatus, VolumeResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_pvc_progress() {
+        let pvc = PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                name: Some("data".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PersistentVolumeClaimSpec {
+                storage_class_name: Some("fast".to_string()),
+                resources: Some(VolumeResourceRequirements {
+                    requests: Some(BTreeMap::from([(
+                        "storage".to_string(),
+                        Quantity("10Gi".to_string()),
+                    )])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            status: Some(PersistentVolumeClaimStatus {
+                phase: Some("Pending".to_string()),
+                ..Default::default()
+            }),
+        };
+
+        let progress = pvc_progress(&pvc, vec![]);
+
+        assert_eq!(progress.phase, "Pending");
+        assert!(!progress.bound);
+        assert_eq!(progress.storage_class.as_deref(), Some("fast"));
+        assert_eq!(progress.requested.as_deref(), Some("10Gi"));
+        assert_eq!(progress.volume_name, None);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::FIELD_MANAGER;
+use crate::k8s::kind::ResourceKind;
+use kube::api::{Api, DynamicObject, Patch, PatchParams};
+use kube::core::{GroupVersion, GroupVersionKind};
+use kube::discovery::{self, Scope};
+use tauri::State;
+
+/// Shown instead of a Secret's values; applying it back is refused
+const REDACTED: &str = "<redacted>";
+
+/// Replace the values of a Secret's `data` and `stringData`, keeping the keys
+fn redact_secret_values(obj: &mut DynamicObject) {
+    for field in ["data", "stringData"] {
+        if let Some(values) = obj.data.get_mut(field).and_then(|v| v.as_object_mut()) {
+            for value in values.values_mut() {
+                *value = serde_json::Value::String(REDACTED.to_string());
+            }
+        }
+    }
+}
+
+/// Whether a manifest still carries a value redacted by `cluster_get_resource_yaml`
+fn has_redacted_values(obj: &DynamicObject) -> bool {
+    ["data", "stringData"].iter().any(|field| {
+        obj.data
+            .get(field)
+            .and_then(|v| v.as_object())
+            .is_some_and(|values| values.values().any(|v| v.as_str() == Some(REDACTED)))
+    })
+}
+
+/// Serialize an object for display, without the managedFields bookkeeping
+fn to_display_yaml(mut obj: DynamicObject) -> Result<String, String> {
+    obj.metadata.managed_fields = None;
+    serde_yaml::to_string(&obj).map_err(|e| format!("Failed to serialize resource: {}", e))
+}
+
+/// The full object as YAML for any kind the resource views list (e.g. "Deployment",
+/// "ConfigMap"). `namespace` is ignored for cluster-scoped kinds. Secret values are
+/// redacted.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_resource_yaml(
+    cluster_id: String,
+    kind: String,
+    namespace: Option<String>,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let kind =
+        ResourceKind::parse(&kind).ok_or_else(|| format!("Unsupported resource kind: {}", kind))?;
+    let resource = kind.api_resource();
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let api: Api<DynamicObject> = if kind.namespaced() {
+        let namespace =
+            namespace.ok_or_else(|| format!("A namespace is required for {}", resource.kind))?;
+        Api::namespaced_with(client, &namespace, &resource)
+    } else {
+        Api::all_with(client, &resource)
+    };
+
+    let mut obj = api
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get {} '{}': {}", resource.kind, name, e))?;
+    // Objects read through the API don't carry their own type meta
+    obj.types = Some(kube::core::TypeMeta {
+        api_version: resource.api_version.clone(),
+        kind: resource.kind.clone(),
+    });
+    if kind == ResourceKind::Secret {
+        redact_secret_values(&mut obj);
+    }
+
+    to_display_yaml(obj)
+}
+
+/// Parse a single YAML document, checking that it names the object it applies to.
+/// Returns the object with its kind and name.
+fn parse_manifest(yaml: &str) -> Result<(DynamicObject, GroupVersionKind, String), String> {
+    let value: serde_yaml::Value =
+        serde_yaml::from_str(yaml).map_err(|e| format!("Invalid YAML: {}", e))?;
+    let field = |path: &[&str]| {
+        path.iter()
+            .try_fold(&value, |v, key| v.get(key))
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+
+    let api_version = field(&["apiVersion"]).ok_or("Manifest is missing apiVersion")?;
+    let kind = field(&["kind"]).ok_or("Manifest is missing kind")?;
+    let name = field(&["metadata", "name"]).ok_or("Manifest is missing metadata.name")?;
+    let gv: GroupVersion = api_version
+        .parse()
+        .map_err(|e| format!("Invalid apiVersion '{}': {}", api_version, e))?;
+
+    let obj: DynamicObject =
+        serde_yaml::from_value(value).map_err(|e| format!("Invalid manifest: {}", e))?;
+    Ok((obj, gv.with_kind(&kind), name))
+}
+
+/// Server-side apply an edited YAML document and return the resulting resourceVersion.
+/// Conflicts with other field managers are not forced and come back as the API's error.
+#[tauri::command]
+#[tracing::instrument(skip(state, yaml))]
+pub async fn cluster_apply_resource_yaml(
+    cluster_id: String,
+    yaml: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let (mut obj, gvk, name) = parse_manifest(&yaml)?;
+    if gvk.group.is_empty() && gvk.kind == "Secret" && has_redacted_values(&obj) {
+        return Err(format!(
+            "Secret '{}' still has {} values; enter the real values before applying",
+            name, REDACTED
+        ));
+    }
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let (resource, caps) = discovery::pinned_kind(&client, &gvk)
+        .await
+        .map_err(|e| format!("Failed to resolve {}/{}: {}", gvk.group, gvk.kind, e))?;
+
+    let api: Api<DynamicObject> = if caps.scope == Scope::Namespaced {
+        let namespace = obj
+            .metadata
+            .namespace
+            .clone()
+            .unwrap_or_else(|| "default".to_string());
+        Api::namespaced_with(client, &namespace, &resource)
+    } else {
+        obj.metadata.namespace = None;
+        Api::all_with(client, &resource)
+    };
+
+    // Server-populated bookkeeping is rejected by apply
+    obj.metadata.managed_fields = None;
+
+    let applied = api
+        .patch(
+            &name,
+            &PatchParams::apply(FIELD_MANAGER),
+            &Patch::Apply(&obj),
+        )
+        .await
+        .map_err(|e| format!("Failed to apply {} '{}': {}", resource.kind, name, e))?;
+
+    Ok(applied.metadata.resource_version.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_secret_values() {
+        let mut obj: DynamicObject = serde_yaml::from_str(
+            r#"
+apiVersion: v1
+kind: Secret
+metadata:
+  name: db
+type: Opaque
+data:
+  password: aHVudGVyMg==
+stringData:
+  user: admin
+"#,
+        )
+        .unwrap();
+        assert!(!has_redacted_values(&obj));
+
+        redact_secret_values(&mut obj);
+
+        assert_eq!(obj.data["data"]["password"], REDACTED);
+        assert_eq!(obj.data["stringData"]["user"], REDACTED);
+        assert_eq!(obj.data["type"], "Opaque");
+        assert!(has_redacted_values(&obj));
+    }
+
+    #[test]
+    fn test_to_display_yaml_strips_managed_fields() {
+        let obj: DynamicObject = serde_yaml::from_str(
+            r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: app-config
+  managedFields:
+  - manager: kubectl
+    operation: Apply
+data:
+  mode: production
+"#,
+        )
+        .unwrap();
+
+        let yaml = to_display_yaml(obj).unwrap();
+
+        assert!(!yaml.contains("managedFields"));
+        assert!(yaml.contains("mode: production"));
+        assert!(yaml.contains("kind: ConfigMap"));
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let (obj, gvk, name) = parse_manifest(
+            r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: shop
+spec:
+  replicas: 2
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(name, "web");
+        assert_eq!(gvk.group, "apps");
+        assert_eq!(gvk.version, "v1");
+        assert_eq!(gvk.kind, "Deployment");
+        assert_eq!(obj.metadata.namespace.as_deref(), Some("shop"));
+    }
+
+    #[test]
+    fn test_parse_manifest_requires_identity() {
+        assert_eq!(
+            parse_manifest("kind: ConfigMap\nmetadata:\n  name: app\n").unwrap_err(),
+            "Manifest is missing apiVersion"
+        );
+        assert_eq!(
+            parse_manifest("apiVersion: v1\nmetadata:\n  name: app\n").unwrap_err(),
+            "Manifest is missing kind"
+        );
+        assert_eq!(
+            parse_manifest("apiVersion: v1\nkind: ConfigMap\nmetadata: {}\n").unwrap_err(),
+            "Manifest is missing metadata.name"
+        );
+    }
+}
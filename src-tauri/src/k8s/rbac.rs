@@ -0,0 +1,109 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{is_forbidden, is_not_found};
+use k8s_openapi::api::authorization::v1::{
+    ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec,
+};
+use kube::api::{Api, PostParams};
+use tauri::State;
+
+/// Whether the current user may perform an action, as the API server's authorizer sees it
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct AccessCheck {
+    pub allowed: bool,
+    pub reason: Option<String>,
+}
+
+fn access_review(
+    verb: String,
+    group: Option<String>,
+    resource: String,
+    namespace: Option<String>,
+    name: Option<String>,
+) -> SelfSubjectAccessReview {
+    SelfSubjectAccessReview {
+        spec: SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                verb: Some(verb),
+                // The core group is the empty string
+                group: Some(group.unwrap_or_default()),
+                resource: Some(resource),
+                namespace: namespace.filter(|ns| !ns.is_empty()),
+                name: name.filter(|n| !n.is_empty()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// `kubectl auth can-i` for a single action, e.g. verb "delete", resource "pods".
+/// Some clusters deny or don't serve SelfSubjectAccessReview; with
+/// `assume_allowed_if_unavailable` that reports allowed (leaving the API to refuse
+/// the action itself), otherwise it's an error.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_can_i(
+    cluster_id: String,
+    verb: String,
+    group: Option<String>,
+    resource: String,
+    namespace: Option<String>,
+    name: Option<String>,
+    assume_allowed_if_unavailable: Option<bool>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<AccessCheck, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let reviews: Api<SelfSubjectAccessReview> = Api::all(client);
+    let review = access_review(verb, group, resource, namespace, name);
+
+    match reviews.create(&PostParams::default(), &review).await {
+        Ok(result) => {
+            let status = result.status.unwrap_or_default();
+            Ok(AccessCheck {
+                allowed: status.allowed,
+                reason: status
+                    .reason
+                    .or(status.evaluation_error)
+                    .filter(|r| !r.is_empty()),
+            })
+        }
+        Err(e)
+            if (is_forbidden(&e) || is_not_found(&e))
+                && assume_allowed_if_unavailable.unwrap_or(false) =>
+        {
+            Ok(AccessCheck {
+                allowed: true,
+                reason: Some(format!(
+                    "Access review unavailable, assuming allowed: {}",
+                    e
+                )),
+            })
+        }
+        Err(e) => Err(format!("Failed to check access: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_review_attributes() {
+        let review = access_review(
+            "delete".to_string(),
+            None,
+            "pods".to_string(),
+            Some("shop".to_string()),
+            Some(String::new()),
+        );
+        let attributes = review.spec.resource_attributes.unwrap();
+
+        assert_eq!(attributes.verb.as_deref(), Some("delete"));
+        assert_eq!(attributes.group.as_deref(), Some(""));
+        assert_eq!(attributes.resource.as_deref(), Some("pods"));
+        assert_eq!(attributes.namespace.as_deref(), Some("shop"));
+        assert_eq!(attributes.name, None);
+    }
+}
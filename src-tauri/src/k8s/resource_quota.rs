@@ -0,0 +1,160 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::metrics::{parse_cpu, parse_memory};
+use k8s_openapi::api::core::v1::ResourceQuota;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use kube::api::Api;
+use std::collections::{BTreeMap, HashMap};
+use tauri::State;
+
+/// Detailed information about a Kubernetes ResourceQuota
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ResourceQuotaDetails {
+    pub name: String,
+    pub namespace: String,
+    pub uid: String,
+    pub created_at: String,
+    pub labels: HashMap<String, String>,
+    pub annotations: HashMap<String, String>,
+    pub scopes: Vec<String>,
+    pub resources: Vec<QuotaResourceUsage>,
+}
+
+/// Hard limit and current usage of one quota'd resource (e.g. "pods: 12/20")
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct QuotaResourceUsage {
+    pub resource: String,
+    pub hard: String,
+    pub used: String,
+    /// Numeric values for percentage bars: cores for CPU, bytes for memory/storage
+    pub hard_value: f64,
+    pub used_value: f64,
+}
+
+/// Parse a quota quantity in the unit suited to its resource name
+fn parse_quota_quantity(resource: &str, quantity: &str) -> f64 {
+    if resource == "cpu" || resource.ends_with(".cpu") {
+        parse_cpu(quantity)
+    } else {
+        parse_memory(quantity)
+    }
+}
+
+fn map_quota_resources(
+    hard: &BTreeMap<String, Quantity>,
+    used: &BTreeMap<String, Quantity>,
+) -> Vec<QuotaResourceUsage> {
+    hard.iter()
+        .map(|(resource, hard)| {
+            let used = used
+                .get(resource)
+                .map(|q| q.0.clone())
+                .unwrap_or_else(|| "0".to_string());
+
+            QuotaResourceUsage {
+                resource: resource.clone(),
+                hard_value: parse_quota_quantity(resource, &hard.0),
+                used_value: parse_quota_quantity(resource, &used),
+                hard: hard.0.clone(),
+                used,
+            }
+        })
+        .collect()
+}
+
+/// Get the hard limits and current usage of a ResourceQuota
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_resource_quota_details(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ResourceQuotaDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let quotas: Api<ResourceQuota> = Api::namespaced(client, &namespace);
+
+    let quota = quotas
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get resource quota '{}': {}", name, e))?;
+
+    let meta = quota.metadata;
+    let spec = quota.spec.unwrap_or_default();
+    let status = quota.status.unwrap_or_default();
+
+    // Prefer the hard limits the quota controller has observed, falling back to the spec
+    let hard = status.hard.or(spec.hard).unwrap_or_default();
+    let used = status.used.unwrap_or_default();
+
+    // Scopes can come from the plain list or from a scope selector
+    let mut scopes = spec.scopes.unwrap_or_default();
+    if let Some(selector) = spec.scope_selector {
+        for expr in selector.match_expressions.unwrap_or_default() {
+            scopes.push(expr.scope_name);
+        }
+    }
+
+    let created_at = meta
+        .creation_timestamp
+        .map(|t| t.0.to_string())
+        .unwrap_or_default();
+
+    Ok(ResourceQuotaDetails {
+        name: meta.name.unwrap_or_default(),
+        namespace: meta.namespace.unwrap_or_default(),
+        uid: meta.uid.unwrap_or_default(),
+        created_at,
+        labels: meta.labels.unwrap_or_default().into_iter().collect(),
+        annotations: meta.annotations.unwrap_or_default().into_iter().collect(),
+        scopes,
+        resources: map_quota_resources(&hard, &used),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantities(entries: &[(&str, &str)]) -> BTreeMap<String, Quantity> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), Quantity(v.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn test_map_quota_resources_parses_units() {
+        let hard = quantities(&[
+            ("pods", "20"),
+            ("requests.cpu", "8"),
+            ("limits.memory", "16Gi"),
+        ]);
+        let used = quantities(&[
+            ("pods", "12"),
+            ("requests.cpu", "3500m"),
+            ("limits.memory", "4Gi"),
+        ]);
+
+        let resources = map_quota_resources(&hard, &used);
+
+        let by_name = |name: &str| resources.iter().find(|r| r.resource == name).unwrap();
+        assert_eq!(by_name("pods").used_value, 12.0);
+        assert_eq!(by_name("pods").hard_value, 20.0);
+        assert_eq!(by_name("requests.cpu").used_value, 3.5);
+        assert_eq!(
+            by_name("limits.memory").hard_value,
+            16.0 * 1024.0f64.powi(3)
+        );
+    }
+
+    #[test]
+    fn test_map_quota_resources_missing_usage_is_zero() {
+        let hard = quantities(&[("services", "5")]);
+
+        let resources = map_quota_resources(&hard, &BTreeMap::new());
+
+        assert_eq!(resources[0].used, "0");
+        assert_eq!(resources[0].used_value, 0.0);
+    }
+}
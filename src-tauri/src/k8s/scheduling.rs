@@ -0,0 +1,571 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::metrics::{parse_cpu, parse_memory};
+use k8s_openapi::api::core::v1::{
+    Node, NodeSelectorRequirement, NodeSelectorTerm, Pod, PodSpec, Taint, Toleration,
+};
+use kube::api::{Api, ListParams};
+use std::collections::BTreeMap;
+use tauri::State;
+
+/// Why a pod does or doesn't fit on each node, evaluated the way the scheduler's
+/// core filters do (resources, node selector, required node affinity, taints).
+/// Inter-pod affinity and topology spread constraints are not evaluated.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PodSchedulingExplanation {
+    pub pod_name: String,
+    pub namespace: String,
+    pub phase: String,
+    /// Node the pod is already bound to, if any
+    pub node_name: Option<String>,
+    pub cpu_request: f64,
+    pub memory_request: f64,
+    pub node_selector: BTreeMap<String, String>,
+    pub required_node_affinity: Vec<String>,
+    pub tolerations: Vec<String>,
+    pub nodes: Vec<NodeSchedulingVerdict>,
+}
+
+/// Verdict for a single node
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct NodeSchedulingVerdict {
+    pub node_name: String,
+    pub fits: bool,
+    pub ready: bool,
+    pub unschedulable: bool,
+    pub fits_resources: bool,
+    pub free_cpu: f64,
+    pub free_memory: f64,
+    pub free_pods: i64,
+    pub selector_matches: bool,
+    pub affinity_matches: bool,
+    pub untolerated_taints: Vec<String>,
+    pub reasons: Vec<String>,
+}
+
+/// Requested (cpu cores, memory bytes) of a pod: containers are summed, and the
+/// largest init container wins if it exceeds that sum
+fn pod_requests(spec: &PodSpec) -> (f64, f64) {
+    let container_requests = |c: &k8s_openapi::api::core::v1::Container| {
+        let requests = c.resources.as_ref().and_then(|r| r.requests.as_ref());
+        (
+            requests
+                .and_then(|r| r.get("cpu"))
+                .map(|q| parse_cpu(&q.0))
+                .unwrap_or(0.0),
+            requests
+                .and_then(|r| r.get("memory"))
+                .map(|q| parse_memory(&q.0))
+                .unwrap_or(0.0),
+        )
+    };
+
+    let (mut cpu, mut memory) = spec
+        .containers
+        .iter()
+        .map(container_requests)
+        .fold((0.0, 0.0), |acc, r| (acc.0 + r.0, acc.1 + r.1));
+
+    for (init_cpu, init_memory) in spec
+        .init_containers
+        .iter()
+        .flatten()
+        .map(container_requests)
+    {
+        cpu = f64::max(cpu, init_cpu);
+        memory = f64::max(memory, init_memory);
+    }
+
+    (cpu, memory)
+}
+
+fn format_toleration(t: &Toleration) -> String {
+    let key = t.key.as_deref().unwrap_or("*");
+    let effect = t.effect.as_deref().unwrap_or("*");
+    match t.operator.as_deref() {
+        Some("Exists") => format!("{} exists:{}", key, effect),
+        _ => format!("{}={}:{}", key, t.value.as_deref().unwrap_or(""), effect),
+    }
+}
+
+fn format_taint(t: &Taint) -> String {
+    match t.value.as_deref() {
+        Some(value) if !value.is_empty() => format!("{}={}:{}", t.key, value, t.effect),
+        _ => format!("{}:{}", t.key, t.effect),
+    }
+}
+
+fn toleration_matches(toleration: &Toleration, taint: &Taint) -> bool {
+    if let Some(effect) = toleration.effect.as_deref() {
+        if !effect.is_empty() && effect != taint.effect {
+            return false;
+        }
+    }
+
+    match toleration.operator.as_deref() {
+        // An empty key with Exists tolerates everything
+        Some("Exists") => toleration
+            .key
+            .as_deref()
+            .map(|k| k.is_empty() || k == taint.key)
+            .unwrap_or(true),
+        _ => {
+            toleration.key.as_deref() == Some(taint.key.as_str())
+                && toleration.value.as_deref().unwrap_or("") == taint.value.as_deref().unwrap_or("")
+        }
+    }
+}
+
+/// Taints that repel the pod: NoSchedule/NoExecute not covered by any toleration
+fn untolerated_taints(taints: &[Taint], tolerations: &[Toleration]) -> Vec<String> {
+    taints
+        .iter()
+        .filter(|taint| taint.effect == "NoSchedule" || taint.effect == "NoExecute")
+        .filter(|taint| !tolerations.iter().any(|t| toleration_matches(t, taint)))
+        .map(format_taint)
+        .collect()
+}
+
+fn requirement_matches(req: &NodeSelectorRequirement, value: Option<&String>) -> bool {
+    let values = req.values.as_deref().unwrap_or_default();
+    match req.operator.as_str() {
+        "In" => value.map(|v| values.contains(v)).unwrap_or(false),
+        "NotIn" => value.map(|v| !values.contains(v)).unwrap_or(true),
+        "Exists" => value.is_some(),
+        "DoesNotExist" => value.is_none(),
+        "Gt" | "Lt" => {
+            let (Some(actual), Some(bound)) = (
+                value.and_then(|v| v.parse::<i64>().ok()),
+                values.first().and_then(|v| v.parse::<i64>().ok()),
+            ) else {
+                return false;
+            };
+            if req.operator == "Gt" {
+                actual > bound
+            } else {
+                actual < bound
+            }
+        }
+        _ => false,
+    }
+}
+
+/// A term matches when all its label expressions and field expressions match
+fn term_matches(
+    term: &NodeSelectorTerm,
+    node_name: &str,
+    labels: &BTreeMap<String, String>,
+) -> bool {
+    let node_name = node_name.to_string();
+    let labels_match = term
+        .match_expressions
+        .iter()
+        .flatten()
+        .all(|req| requirement_matches(req, labels.get(&req.key)));
+    let fields_match = term.match_fields.iter().flatten().all(|req| {
+        let value = (req.key == "metadata.name").then_some(&node_name);
+        requirement_matches(req, value)
+    });
+    labels_match && fields_match
+}
+
+fn format_term(term: &NodeSelectorTerm) -> String {
+    term.match_expressions
+        .iter()
+        .flatten()
+        .chain(term.match_fields.iter().flatten())
+        .map(|req| {
+            let values = req.values.as_deref().unwrap_or_default();
+            if values.is_empty() {
+                format!("{} {}", req.key, req.operator)
+            } else {
+                format!("{} {} ({})", req.key, req.operator, values.join(","))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+fn required_affinity_terms(spec: &PodSpec) -> Vec<NodeSelectorTerm> {
+    spec.affinity
+        .as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|na| {
+            na.required_during_scheduling_ignored_during_execution
+                .as_ref()
+        })
+        .map(|selector| selector.node_selector_terms.clone())
+        .unwrap_or_default()
+}
+
+fn is_node_ready(node: &Node) -> bool {
+    node.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .map(|conds| {
+            conds
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True")
+        })
+        .unwrap_or(false)
+}
+
+/// Evaluate a pod against one node. `used` is (cpu, memory, pod count) already
+/// requested on that node by other running pods.
+fn evaluate_node(pod: &PodSpec, node: &Node, used: (f64, f64, i64)) -> NodeSchedulingVerdict {
+    let node_name = node.metadata.name.clone().unwrap_or_default();
+    let labels = node.metadata.labels.clone().unwrap_or_default();
+    let allocatable = node
+        .status
+        .as_ref()
+        .and_then(|s| s.allocatable.clone())
+        .unwrap_or_default();
+
+    let (cpu_request, memory_request) = pod_requests(pod);
+    let free_cpu = allocatable
+        .get("cpu")
+        .map(|q| parse_cpu(&q.0))
+        .unwrap_or(0.0)
+        - used.0;
+    let free_memory = allocatable
+        .get("memory")
+        .map(|q| parse_memory(&q.0))
+        .unwrap_or(0.0)
+        - used.1;
+    let free_pods = allocatable
+        .get("pods")
+        .and_then(|q| q.0.parse::<i64>().ok())
+        .unwrap_or(0)
+        - used.2;
+
+    let mut reasons = Vec::new();
+
+    let ready = is_node_ready(node);
+    if !ready {
+        reasons.push("Node is not Ready".to_string());
+    }
+
+    let unschedulable = node
+        .spec
+        .as_ref()
+        .and_then(|s| s.unschedulable)
+        .unwrap_or(false);
+    if unschedulable {
+        reasons.push("Node is cordoned".to_string());
+    }
+
+    let mut fits_resources = true;
+    if cpu_request > free_cpu {
+        fits_resources = false;
+        reasons.push(format!(
+            "Insufficient cpu: requests {:.3}, {:.3} free",
+            cpu_request, free_cpu
+        ));
+    }
+    if memory_request > free_memory {
+        fits_resources = false;
+        reasons.push(format!(
+            "Insufficient memory: requests {:.0} bytes, {:.0} free",
+            memory_request, free_memory
+        ));
+    }
+    if free_pods < 1 {
+        fits_resources = false;
+        reasons.push("Too many pods".to_string());
+    }
+
+    let selector_matches = pod
+        .node_selector
+        .iter()
+        .flatten()
+        .all(|(k, v)| labels.get(k) == Some(v));
+    if !selector_matches {
+        reasons.push("Node labels don't match nodeSelector".to_string());
+    }
+
+    let terms = required_affinity_terms(pod);
+    let affinity_matches =
+        terms.is_empty() || terms.iter().any(|t| term_matches(t, &node_name, &labels));
+    if !affinity_matches {
+        reasons.push("Node doesn't match required node affinity".to_string());
+    }
+
+    let untolerated = untolerated_taints(
+        node.spec
+            .as_ref()
+            .and_then(|s| s.taints.as_deref())
+            .unwrap_or_default(),
+        pod.tolerations.as_deref().unwrap_or_default(),
+    );
+    for taint in &untolerated {
+        reasons.push(format!("Untolerated taint {}", taint));
+    }
+
+    NodeSchedulingVerdict {
+        fits: reasons.is_empty(),
+        node_name,
+        ready,
+        unschedulable,
+        fits_resources,
+        free_cpu,
+        free_memory,
+        free_pods,
+        selector_matches,
+        affinity_matches,
+        untolerated_taints: untolerated,
+        reasons,
+    }
+}
+
+/// Explain why a pod does (or doesn't) fit on each node in the cluster
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_explain_pod_scheduling(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<PodSchedulingExplanation, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = pods_api
+        .get(&pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod '{}': {}", pod_name, e))?;
+    let spec = pod.spec.clone().unwrap_or_default();
+
+    let nodes_api: Api<Node> = Api::all(client.clone());
+    let nodes = nodes_api
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| format!("Failed to list nodes: {}", e))?;
+
+    // Requests already committed on each node by pods that still hold resources
+    let all_pods: Api<Pod> = Api::all(client);
+    let lp = ListParams::default().fields("status.phase!=Succeeded,status.phase!=Failed");
+    let running = all_pods
+        .list(&lp)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    let pod_uid = pod.metadata.uid.clone();
+    let mut used: BTreeMap<String, (f64, f64, i64)> = BTreeMap::new();
+    for other in &running.items {
+        if other.metadata.uid == pod_uid {
+            continue;
+        }
+        let Some(other_spec) = other.spec.as_ref() else {
+            continue;
+        };
+        let Some(node) = other_spec.node_name.clone() else {
+            continue;
+        };
+        let (cpu, memory) = pod_requests(other_spec);
+        let entry = used.entry(node).or_default();
+        entry.0 += cpu;
+        entry.1 += memory;
+        entry.2 += 1;
+    }
+
+    let verdicts = nodes
+        .items
+        .iter()
+        .map(|node| {
+            let name = node.metadata.name.clone().unwrap_or_default();
+            evaluate_node(&spec, node, used.get(&name).copied().unwrap_or_default())
+        })
+        .collect();
+
+    let (cpu_request, memory_request) = pod_requests(&spec);
+
+    Ok(PodSchedulingExplanation {
+        pod_name: pod.metadata.name.unwrap_or_default(),
+        namespace: pod.metadata.namespace.unwrap_or_default(),
+        phase: pod
+            .status
+            .and_then(|s| s.phase)
+            .unwrap_or_else(|| "Unknown".to_string()),
+        node_name: spec.node_name.clone(),
+        cpu_request,
+        memory_request,
+        node_selector: spec.node_selector.clone().unwrap_or_default(),
+        required_node_affinity: required_affinity_terms(&spec)
+            .iter()
+            .map(format_term)
+            .collect(),
+        tolerations: spec
+            .tolerations
+            .iter()
+            .flatten()
+            .map(format_toleration)
+            .collect(),
+        nodes: verdicts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Container, NodeCondition, NodeSpec, NodeStatus, ResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn quantities(entries: &[(&str, &str)]) -> BTreeMap<String, Quantity> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), Quantity(v.to_string())))
+            .collect()
+    }
+
+    fn mock_node(name: &str, labels: &[(&str, &str)], taints: Vec<Taint>) -> Node {
+        Node {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                labels: Some(
+                    labels
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            spec: Some(NodeSpec {
+                taints: Some(taints),
+                ..Default::default()
+            }),
+            status: Some(NodeStatus {
+                allocatable: Some(quantities(&[
+                    ("cpu", "2"),
+                    ("memory", "4Gi"),
+                    ("pods", "110"),
+                ])),
+                conditions: Some(vec![NodeCondition {
+                    type_: "Ready".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn mock_spec(cpu: &str, memory: &str) -> PodSpec {
+        PodSpec {
+            containers: vec![Container {
+                name: "app".to_string(),
+                resources: Some(ResourceRequirements {
+                    requests: Some(quantities(&[("cpu", cpu), ("memory", memory)])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn no_schedule_taint(key: &str) -> Taint {
+        Taint {
+            key: key.to_string(),
+            effect: "NoSchedule".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pod_fits_on_empty_node() {
+        let verdict = evaluate_node(
+            &mock_spec("500m", "1Gi"),
+            &mock_node("n1", &[], vec![]),
+            (0.0, 0.0, 0),
+        );
+
+        assert!(verdict.fits, "{:?}", verdict.reasons);
+    }
+
+    #[test]
+    fn test_insufficient_cpu_accounts_for_used() {
+        let verdict = evaluate_node(
+            &mock_spec("1", "1Gi"),
+            &mock_node("n1", &[], vec![]),
+            (1.5, 0.0, 3),
+        );
+
+        assert!(!verdict.fits);
+        assert!(!verdict.fits_resources);
+        assert!(verdict.reasons[0].contains("Insufficient cpu"));
+    }
+
+    #[test]
+    fn test_untolerated_taint() {
+        let node = mock_node("n1", &[], vec![no_schedule_taint("dedicated")]);
+        let mut spec = mock_spec("100m", "128Mi");
+
+        let verdict = evaluate_node(&spec, &node, (0.0, 0.0, 0));
+        assert_eq!(verdict.untolerated_taints, vec!["dedicated:NoSchedule"]);
+
+        spec.tolerations = Some(vec![Toleration {
+            key: Some("dedicated".to_string()),
+            operator: Some("Exists".to_string()),
+            ..Default::default()
+        }]);
+        let verdict = evaluate_node(&spec, &node, (0.0, 0.0, 0));
+        assert!(verdict.untolerated_taints.is_empty());
+        assert!(verdict.fits);
+    }
+
+    #[test]
+    fn test_node_selector_and_affinity() {
+        use k8s_openapi::api::core::v1::{Affinity, NodeAffinity, NodeSelector};
+
+        let node = mock_node("n1", &[("disk", "ssd"), ("zone", "a")], vec![]);
+        let mut spec = mock_spec("100m", "128Mi");
+        spec.node_selector = Some(BTreeMap::from([("disk".to_string(), "hdd".to_string())]));
+
+        let verdict = evaluate_node(&spec, &node, (0.0, 0.0, 0));
+        assert!(!verdict.selector_matches);
+
+        spec.node_selector = None;
+        spec.affinity = Some(Affinity {
+            node_affinity: Some(NodeAffinity {
+                required_during_scheduling_ignored_during_execution: Some(NodeSelector {
+                    node_selector_terms: vec![NodeSelectorTerm {
+                        match_expressions: Some(vec![NodeSelectorRequirement {
+                            key: "zone".to_string(),
+                            operator: "In".to_string(),
+                            values: Some(vec!["b".to_string(), "c".to_string()]),
+                        }]),
+                        ..Default::default()
+                    }],
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let verdict = evaluate_node(&spec, &node, (0.0, 0.0, 0));
+        assert!(verdict.selector_matches);
+        assert!(!verdict.affinity_matches);
+        assert_eq!(
+            format_term(&required_affinity_terms(&spec)[0]),
+            "zone In (b,c)"
+        );
+    }
+
+    #[test]
+    fn test_init_container_request_dominates() {
+        let mut spec = mock_spec("100m", "128Mi");
+        spec.init_containers = Some(vec![Container {
+            name: "migrate".to_string(),
+            resources: Some(ResourceRequirements {
+                requests: Some(quantities(&[("cpu", "1")])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }]);
+
+        assert_eq!(pod_requests(&spec), (1.0, 128.0 * 1024.0 * 1024.0));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::core::v1::Secret;
+use k8s_openapi::ByteString;
+use kube::api::Api;
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+use tauri::State;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::Pem;
+
+/// Summary of one certificate in a TLS secret's chain
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    pub serial: String,
+    pub sans: Vec<String>,
+    pub not_before: String,
+    pub not_after: String,
+    pub days_until_expiry: i64,
+    pub is_ca: bool,
+}
+
+/// Certificate details of a TLS secret. Key material is never returned.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TlsSecretInfo {
+    pub name: String,
+    pub namespace: String,
+    pub secret_type: String,
+    pub has_private_key: bool,
+    /// Leaf first, in the order they appear in `tls.crt`
+    pub certificates: Vec<CertificateInfo>,
+}
+
+fn format_general_name(name: &GeneralName) -> Option<String> {
+    match name {
+        GeneralName::DNSName(dns) => Some(dns.to_string()),
+        GeneralName::RFC822Name(email) => Some(email.to_string()),
+        GeneralName::URI(uri) => Some(uri.to_string()),
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => <[u8; 4]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(b).to_string()),
+            16 => <[u8; 16]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(b).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn format_asn1_time(time: &x509_parser::time::ASN1Time) -> String {
+    chrono::DateTime::from_timestamp(time.timestamp(), 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Parse every PEM certificate in `tls.crt`, relative to `now` for expiry
+fn parse_certificate_chain(
+    pem_data: &[u8],
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<CertificateInfo>, String> {
+    let mut certificates = Vec::new();
+
+    for pem in Pem::iter_from_buffer(pem_data) {
+        let pem = pem.map_err(|e| format!("Failed to read PEM block: {}", e))?;
+        if pem.label != "CERTIFICATE" {
+            continue;
+        }
+        let cert = pem
+            .parse_x509()
+            .map_err(|e| format!("Failed to parse certificate: {}", e))?;
+
+        let sans = cert
+            .subject_alternative_name()
+            .ok()
+            .flatten()
+            .map(|ext| {
+                ext.value
+                    .general_names
+                    .iter()
+                    .filter_map(format_general_name)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let validity = cert.validity();
+        let days_until_expiry =
+            (validity.not_after.timestamp() - now.timestamp()).div_euclid(86_400);
+
+        certificates.push(CertificateInfo {
+            subject: cert.subject().to_string(),
+            issuer: cert.issuer().to_string(),
+            serial: cert.raw_serial_as_string(),
+            sans,
+            not_before: format_asn1_time(&validity.not_before),
+            not_after: format_asn1_time(&validity.not_after),
+            days_until_expiry,
+            is_ca: cert.is_ca(),
+        });
+    }
+
+    if certificates.is_empty() {
+        return Err("No certificates found in tls.crt".to_string());
+    }
+
+    Ok(certificates)
+}
+
+/// Decode a TLS secret's certificate chain: subject, issuer, SANs and expiry
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_inspect_tls_secret(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<TlsSecretInfo, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let secrets: Api<Secret> = Api::namespaced(client, &namespace);
+
+    let secret = secrets
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get secret '{}': {}", name, e))?;
+
+    let data = secret.data.unwrap_or_default();
+    let cert_data = data
+        .get("tls.crt")
+        .ok_or_else(|| format!("Secret '{}' has no tls.crt", name))?;
+    let certificates = parse_certificate_chain(&cert_data.0, chrono::Utc::now())?;
+
+    Ok(TlsSecretInfo {
+        name: secret.metadata.name.unwrap_or_default(),
+        namespace: secret.metadata.namespace.unwrap_or_default(),
+        secret_type: secret.type_.unwrap_or_else(|| "Opaque".to_string()),
+        has_private_key: data
+            .get("tls.key")
+            .map(|k| !k.0.is_empty())
+            .unwrap_or(false),
+        certificates,
+    })
+}
+
+/// Decoded values of a secret, for explicit reveal in the UI
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SecretData {
+    pub secret_type: String,
+    /// Key to value, or `<binary N bytes>` when the value isn't valid UTF-8
+    pub data: HashMap<String, String>,
+}
+
+fn decode_secret_data(data: BTreeMap<String, ByteString>) -> HashMap<String, String> {
+    data.into_iter()
+        .map(|(key, value)| {
+            let decoded = String::from_utf8(value.0)
+                .unwrap_or_else(|e| format!("<binary {} bytes>", e.as_bytes().len()));
+            (key, decoded)
+        })
+        .collect()
+}
+
+/// Reveal a secret's values. Separate from listing so the UI only fetches them on
+/// request; the values must never be logged.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_secret_data(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<SecretData, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let secrets: Api<Secret> = Api::namespaced(client, &namespace);
+
+    let secret = secrets
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get secret '{}': {}", name, e))?;
+
+    Ok(SecretData {
+        secret_type: secret.type_.unwrap_or_else(|| "Opaque".to_string()),
+        data: decode_secret_data(secret.data.unwrap_or_default()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CERT: &str = "\
+-----BEGIN CERTIFICATE-----
+MIIB6jCCAY+gAwIBAgIUPu0nQsYUC5XUtaXX3ZEMtBTEdaEwCgYIKoZIzj0EAwIw
+LzEZMBcGA1UEAwwQdGVzdC5leGFtcGxlLmNvbTESMBAGA1UECgwJS29yZSBUZXN0
+MB4XDTI2MTAxNjAwMTQzMVoXDTM2MTAxMzAwMTQzMVowLzEZMBcGA1UEAwwQdGVz
+dC5leGFtcGxlLmNvbTESMBAGA1UECgwJS29yZSBUZXN0MFkwEwYHKoZIzj0CAQYI
+KoZIzj0DAQcDQgAECbQTTsWWJ+aVAaeRwjsSudIXyqURPKekxxh9DyrJdbBCVm5z
+ZWGjyJbVxUD91PPb6TNUPXjkfYoHfaBbiplJfKOBiDCBhTAdBgNVHQ4EFgQUhDMk
+dxyS6T/xf7mRIQ3gKdqk9NMwHwYDVR0jBBgwFoAUhDMkdxyS6T/xf7mRIQ3gKdqk
+9NMwDwYDVR0TAQH/BAUwAwEB/zAyBgNVHREEKzApghB0ZXN0LmV4YW1wbGUuY29t
+gg93d3cuZXhhbXBsZS5jb22HBAoAAAEwCgYIKoZIzj0EAwIDSQAwRgIhAJaTMTgS
+Z1wOeY8N8keAWzBOkU4CvvEpFwUjTwlnLAN4AiEAiTS1fC30jYd16GiGyU9UZtVC
+SVKOwsUfqIqnEg6TNJA=
+-----END CERTIFICATE-----
+";
+
+    fn at(rfc3339: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    #[test]
+    fn test_parse_certificate_chain() {
+        let certs =
+            parse_certificate_chain(TEST_CERT.as_bytes(), at("2036-10-03T00:14:31Z")).unwrap();
+
+        assert_eq!(certs.len(), 1);
+        let cert = &certs[0];
+        assert!(cert.subject.contains("CN=test.example.com"));
+        assert_eq!(cert.subject, cert.issuer);
+        assert_eq!(
+            cert.sans,
+            vec!["test.example.com", "www.example.com", "10.0.0.1"]
+        );
+        assert_eq!(cert.not_after, "2036-10-13T00:14:31+00:00");
+        assert_eq!(cert.days_until_expiry, 10);
+        assert!(cert.is_ca);
+    }
+
+    #[test]
+    fn test_expired_certificate_has_negative_days() {
+        let certs =
+            parse_certificate_chain(TEST_CERT.as_bytes(), at("2036-10-14T00:14:31Z")).unwrap();
+
+        assert_eq!(certs[0].days_until_expiry, -1);
+    }
+
+    #[test]
+    fn test_parse_certificate_chain_rejects_empty() {
+        assert!(parse_certificate_chain(b"", chrono::Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_decode_secret_data() {
+        let data = BTreeMap::from([
+            ("password".to_string(), ByteString(b"hunter2".to_vec())),
+            ("keystore".to_string(), ByteString(vec![0xff, 0xfe, 0x00])),
+        ]);
+
+        let decoded = decode_secret_data(data);
+
+        assert_eq!(decoded["password"], "hunter2");
+        assert_eq!(decoded["keystore"], "<binary 3 bytes>");
+    }
+}
@@ -0,0 +1,414 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::{build_label_selector, is_pod_ready};
+use k8s_openapi::api::core::v1::{Endpoints, Pod, Service};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{Api, ListParams};
+use std::collections::{BTreeMap, HashSet};
+use tauri::State;
+
+/// A pod selected by a Service and whether it is actually in rotation
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct ServiceBackend {
+    pub name: String,
+    pub ready: bool,
+    pub in_endpoints: bool,
+    pub pod_ip: String,
+}
+
+/// Addresses currently published for a Service, keyed by pod name and by IP
+#[derive(Debug, Default)]
+struct EndpointAddresses {
+    pod_names: HashSet<String>,
+    ips: HashSet<String>,
+}
+
+impl EndpointAddresses {
+    fn contains(&self, pod_name: &str, pod_ip: Option<&str>) -> bool {
+        self.pod_names.contains(pod_name) || pod_ip.map(|ip| self.ips.contains(ip)).unwrap_or(false)
+    }
+}
+
+/// Collect the ready addresses from a legacy Endpoints object.
+/// `notReadyAddresses` are deliberately ignored: those pods receive no traffic.
+fn collect_endpoint_addresses(endpoints: &Endpoints) -> EndpointAddresses {
+    let mut addresses = EndpointAddresses::default();
+
+    for subset in endpoints.subsets.iter().flatten() {
+        for address in subset.addresses.iter().flatten() {
+            addresses.ips.insert(address.ip.clone());
+            if let Some(target) = address.target_ref.as_ref() {
+                if target.kind.as_deref() == Some("Pod") {
+                    if let Some(name) = target.name.as_ref() {
+                        addresses.pod_names.insert(name.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    addresses
+}
+
+/// Collect the ready addresses from a Service's EndpointSlices.
+/// An endpoint with an unknown ready condition counts as ready, per the API docs.
+fn collect_endpoint_slice_addresses(slices: &[EndpointSlice]) -> EndpointAddresses {
+    let mut addresses = EndpointAddresses::default();
+
+    for endpoint in slices.iter().flat_map(|slice| slice.endpoints.iter()) {
+        let ready = endpoint
+            .conditions
+            .as_ref()
+            .and_then(|c| c.ready)
+            .unwrap_or(true);
+        if !ready {
+            continue;
+        }
+
+        addresses.ips.extend(endpoint.addresses.iter().cloned());
+        if let Some(target) = endpoint.target_ref.as_ref() {
+            if target.kind.as_deref() == Some("Pod") {
+                if let Some(name) = target.name.as_ref() {
+                    addresses.pod_names.insert(name.clone());
+                }
+            }
+        }
+    }
+
+    addresses
+}
+
+/// Cross-reference selected pods against the published endpoint addresses
+fn build_service_backends(pods: &[Pod], addresses: &EndpointAddresses) -> Vec<ServiceBackend> {
+    let mut backends: Vec<ServiceBackend> = pods
+        .iter()
+        .map(|pod| {
+            let name = pod.metadata.name.clone().unwrap_or_default();
+            let pod_ip = pod.status.as_ref().and_then(|s| s.pod_ip.clone());
+            let in_endpoints = addresses.contains(&name, pod_ip.as_deref());
+
+            ServiceBackend {
+                ready: is_pod_ready(pod),
+                in_endpoints,
+                pod_ip: pod_ip.unwrap_or_else(|| "-".to_string()),
+                name,
+            }
+        })
+        .collect();
+
+    backends.sort_by(|a, b| a.name.cmp(&b.name));
+    backends
+}
+
+/// One port of a Service; `target_port` may be a number or a named container port
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ServicePortInfo {
+    pub name: Option<String>,
+    pub port: i32,
+    pub target_port: Option<String>,
+    pub node_port: Option<i32>,
+    pub protocol: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ServiceDetails {
+    pub name: String,
+    pub namespace: String,
+    pub service_type: String,
+    pub cluster_ips: Vec<String>,
+    pub external_ips: Vec<String>,
+    pub session_affinity: String,
+    pub selector: BTreeMap<String, String>,
+    pub ports: Vec<ServicePortInfo>,
+}
+
+fn map_service_details(service: Service) -> ServiceDetails {
+    let spec = service.spec.unwrap_or_default();
+    // Older objects may only carry the singular clusterIP
+    let cluster_ips = spec
+        .cluster_ips
+        .or_else(|| spec.cluster_ip.map(|ip| vec![ip]))
+        .unwrap_or_default();
+
+    ServiceDetails {
+        name: service.metadata.name.unwrap_or_default(),
+        namespace: service.metadata.namespace.unwrap_or_default(),
+        service_type: spec.type_.unwrap_or_else(|| "ClusterIP".to_string()),
+        cluster_ips,
+        external_ips: spec.external_ips.unwrap_or_default(),
+        session_affinity: spec.session_affinity.unwrap_or_else(|| "None".to_string()),
+        selector: spec.selector.unwrap_or_default(),
+        ports: spec
+            .ports
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| ServicePortInfo {
+                name: p.name,
+                port: p.port,
+                target_port: p.target_port.map(|t| match t {
+                    IntOrString::Int(port) => port.to_string(),
+                    IntOrString::String(name) => name,
+                }),
+                node_port: p.node_port,
+                protocol: p.protocol.unwrap_or_else(|| "TCP".to_string()),
+            })
+            .collect(),
+    }
+}
+
+/// Ports, selector, type, addresses and session affinity of a Service
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_service_details(
+    cluster_id: String,
+    namespace: String,
+    service_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ServiceDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let services_api: Api<Service> = Api::namespaced(client, &namespace);
+
+    let service = services_api
+        .get(&service_name)
+        .await
+        .map_err(|e| format!("Failed to get service '{}': {}", service_name, e))?;
+
+    Ok(map_service_details(service))
+}
+
+/// Resolve the pods selected by a Service and whether each is in its endpoints
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_service_backends(
+    cluster_id: String,
+    namespace: String,
+    service_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<ServiceBackend>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let services_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    let service = services_api
+        .get(&service_name)
+        .await
+        .map_err(|e| format!("Failed to get service '{}': {}", service_name, e))?;
+
+    // Services without a selector have manually managed endpoints and no "selected" pods
+    let selector = service
+        .spec
+        .as_ref()
+        .and_then(|s| s.selector.clone())
+        .unwrap_or_default();
+
+    if selector.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let pods_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let lp = ListParams::default().labels(&build_label_selector(&selector));
+    let pods = pods_api
+        .list(&lp)
+        .await
+        .map_err(|e| format!("Failed to list pods: {}", e))?;
+
+    // Prefer EndpointSlices; fall back to legacy Endpoints on clusters that don't serve
+    // discovery.k8s.io/v1 or haven't published slices for this service
+    let slices_api: Api<EndpointSlice> = Api::namespaced(client.clone(), &namespace);
+    let slice_lp =
+        ListParams::default().labels(&format!("kubernetes.io/service-name={}", service_name));
+    let slices = slices_api
+        .list(&slice_lp)
+        .await
+        .map(|list| list.items)
+        .unwrap_or_default();
+
+    let addresses = if !slices.is_empty() {
+        collect_endpoint_slice_addresses(&slices)
+    } else {
+        // Endpoints share the service name; a missing object just means nothing is in rotation
+        let endpoints_api: Api<Endpoints> = Api::namespaced(client, &namespace);
+        endpoints_api
+            .get_opt(&service_name)
+            .await
+            .map_err(|e| format!("Failed to get endpoints '{}': {}", service_name, e))?
+            .map(|ep| collect_endpoint_addresses(&ep))
+            .unwrap_or_default()
+    };
+
+    Ok(build_service_backends(&pods.items, &addresses))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        EndpointAddress, EndpointSubset, ObjectReference, PodCondition, PodStatus,
+    };
+    use k8s_openapi::api::discovery::v1::{Endpoint, EndpointConditions};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn mock_pod(name: &str, ip: Option<&str>, ready: bool) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            status: Some(PodStatus {
+                pod_ip: ip.map(|s| s.to_string()),
+                conditions: Some(vec![PodCondition {
+                    type_: "Ready".to_string(),
+                    status: (if ready { "True" } else { "False" }).to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn mock_endpoints(addresses: &[(&str, Option<&str>)]) -> Endpoints {
+        Endpoints {
+            subsets: Some(vec![EndpointSubset {
+                addresses: Some(
+                    addresses
+                        .iter()
+                        .map(|(ip, pod)| EndpointAddress {
+                            ip: ip.to_string(),
+                            target_ref: pod.map(|name| ObjectReference {
+                                kind: Some("Pod".to_string()),
+                                name: Some(name.to_string()),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_map_service_details() {
+        let service: Service = serde_yaml::from_str(
+            r#"
+metadata:
+  name: web
+  namespace: shop
+spec:
+  type: NodePort
+  clusterIP: 10.96.0.10
+  externalIPs: [203.0.113.5]
+  selector:
+    app: web
+  ports:
+  - name: http
+    port: 80
+    targetPort: http
+    nodePort: 30080
+  - port: 9090
+    targetPort: 9090
+    protocol: UDP
+"#,
+        )
+        .unwrap();
+
+        let details = map_service_details(service);
+
+        assert_eq!(details.service_type, "NodePort");
+        assert_eq!(details.cluster_ips, vec!["10.96.0.10"]);
+        assert_eq!(details.external_ips, vec!["203.0.113.5"]);
+        assert_eq!(details.session_affinity, "None");
+        assert_eq!(details.selector["app"], "web");
+        assert_eq!(
+            details.ports,
+            vec![
+                ServicePortInfo {
+                    name: Some("http".to_string()),
+                    port: 80,
+                    target_port: Some("http".to_string()),
+                    node_port: Some(30080),
+                    protocol: "TCP".to_string(),
+                },
+                ServicePortInfo {
+                    name: None,
+                    port: 9090,
+                    target_port: Some("9090".to_string()),
+                    node_port: None,
+                    protocol: "UDP".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_backends_distinguish_selected_from_in_rotation() {
+        let pods = vec![
+            mock_pod("web-1", Some("10.0.0.1"), true),
+            mock_pod("web-2", Some("10.0.0.2"), false),
+        ];
+        let endpoints = mock_endpoints(&[("10.0.0.1", Some("web-1"))]);
+
+        let backends = build_service_backends(&pods, &collect_endpoint_addresses(&endpoints));
+
+        assert_eq!(backends.len(), 2);
+        assert!(backends[0].ready && backends[0].in_endpoints);
+        assert!(!backends[1].ready && !backends[1].in_endpoints);
+    }
+
+    #[test]
+    fn test_backends_match_by_ip_without_target_ref() {
+        let pods = vec![mock_pod("web-1", Some("10.0.0.1"), true)];
+        let endpoints = mock_endpoints(&[("10.0.0.1", None)]);
+
+        let backends = build_service_backends(&pods, &collect_endpoint_addresses(&endpoints));
+
+        assert!(backends[0].in_endpoints);
+    }
+
+    #[test]
+    fn test_backends_without_endpoints() {
+        let pods = vec![mock_pod("web-1", None, false)];
+
+        let backends = build_service_backends(&pods, &EndpointAddresses::default());
+
+        assert!(!backends[0].in_endpoints);
+        assert_eq!(backends[0].pod_ip, "-");
+    }
+
+    #[test]
+    fn test_backends_from_endpoint_slices_skip_unready() {
+        let pods = vec![
+            mock_pod("web-1", Some("10.0.0.1"), true),
+            mock_pod("web-2", Some("10.0.0.2"), true),
+        ];
+        let endpoint = |ip: &str, pod: &str, ready: Option<bool>| Endpoint {
+            addresses: vec![ip.to_string()],
+            conditions: Some(EndpointConditions {
+                ready,
+                ..Default::default()
+            }),
+            target_ref: Some(ObjectReference {
+                kind: Some("Pod".to_string()),
+                name: Some(pod.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let slices = vec![EndpointSlice {
+            address_type: "IPv4".to_string(),
+            endpoints: vec![
+                endpoint("10.0.0.1", "web-1", None),
+                endpoint("10.0.0.2", "web-2", Some(false)),
+            ],
+            ..Default::default()
+        }];
+
+        let backends = build_service_backends(&pods, &collect_endpoint_slice_addresses(&slices));
+
+        assert!(backends[0].in_endpoints);
+        assert!(!backends[1].in_endpoints);
+    }
+}
@@ -0,0 +1,446 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::config;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::FIELD_MANAGER;
+use crate::k8s::kind::ResourceKind;
+use kube::api::{Api, ApiResource, DynamicObject, ListParams, Patch, PatchParams};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Kinds a namespace snapshot can hold, in the order they're applied so that
+/// config and identities exist before the workloads referencing them
+fn snapshot_resource(kind: &str) -> Option<(u8, ApiResource)> {
+    let kind = ResourceKind::parse(kind)?;
+    Some((kind.snapshot_order()?, kind.api_resource()))
+}
+
+/// Objects Kubernetes creates on its own in every namespace, or that another
+/// object owns, would only conflict when applied elsewhere
+fn is_generated(obj: &DynamicObject, kind: &str) -> bool {
+    if obj
+        .metadata
+        .owner_references
+        .as_ref()
+        .is_some_and(|refs| !refs.is_empty())
+    {
+        return true;
+    }
+
+    let name = obj.metadata.name.as_deref().unwrap_or_default();
+    match kind {
+        "ServiceAccount" => name == "default",
+        "ConfigMap" => name == "kube-root-ca.crt",
+        "Secret" => {
+            obj.data.get("type").and_then(|t| t.as_str())
+                == Some("kubernetes.io/service-account-token")
+        }
+        _ => false,
+    }
+}
+
+/// Labels the job controller derives from a job's uid
+const JOB_UID_LABELS: &[&str] = &["controller-uid", "batch.kubernetes.io/controller-uid"];
+
+/// Strip server-assigned fields so the manifest can be applied to another namespace
+fn sanitize_object(obj: &mut DynamicObject, kind: &str) {
+    let meta = &mut obj.metadata;
+    if kind == "Job" {
+        if let Some(labels) = meta.labels.as_mut() {
+            for label in JOB_UID_LABELS {
+                labels.remove(*label);
+            }
+        }
+    }
+    meta.namespace = None;
+    meta.uid = None;
+    meta.resource_version = None;
+    meta.creation_timestamp = None;
+    meta.deletion_timestamp = None;
+    meta.generation = None;
+    meta.managed_fields = None;
+    meta.self_link = None;
+    if let Some(annotations) = meta.annotations.as_mut() {
+        annotations.remove("kubectl.kubernetes.io/last-applied-configuration");
+        annotations.remove("deployment.kubernetes.io/revision");
+        if annotations.is_empty() {
+            meta.annotations = None;
+        }
+    }
+
+    if let Some(data) = obj.data.as_object_mut() {
+        data.remove("status");
+        if let Some(spec) = data.get_mut("spec").and_then(|s| s.as_object_mut()) {
+            match kind {
+                // Cluster IPs are allocated per service; reusing them fails. "None"
+                // marks a headless service and has to be kept.
+                "Service" => {
+                    let headless = spec.get("clusterIP").and_then(|ip| ip.as_str()) == Some("None");
+                    if !headless {
+                        spec.remove("clusterIP");
+                        spec.remove("clusterIPs");
+                    }
+                }
+                // The selector and its labels are generated from the job's uid, which a
+                // new job won't share
+                "Job" => {
+                    spec.remove("selector");
+                    if let Some(labels) = spec
+                        .get_mut("template")
+                        .and_then(|t| t.get_mut("metadata"))
+                        .and_then(|m| m.get_mut("labels"))
+                        .and_then(|l| l.as_object_mut())
+                    {
+                        for label in JOB_UID_LABELS {
+                            labels.remove(*label);
+                        }
+                    }
+                }
+                // A bound volume can't be claimed twice
+                "PersistentVolumeClaim" => {
+                    spec.remove("volumeName");
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Directory name for a snapshot, e.g. `payments-20240101T120000Z`
+fn snapshot_dir_name(namespace: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    format!("{}-{}", namespace, now.format("%Y%m%dT%H%M%SZ"))
+}
+
+/// Dump the selected kinds (e.g. `["Deployment", "Service", "ConfigMap"]`) from a
+/// namespace as sanitized YAML, one `<kind>-<name>.yaml` per object, into a new
+/// directory under `dest_dir`. Returns the snapshot path.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_export_namespace(
+    cluster_id: String,
+    namespace: String,
+    dest_dir: String,
+    kinds: Vec<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let resources = kinds
+        .iter()
+        .map(|kind| {
+            snapshot_resource(kind)
+                .map(|(_, resource)| resource)
+                .ok_or_else(|| format!("Unsupported kind for export: {}", kind))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let snapshot_dir = config::validate_new_file_destination(
+        &PathBuf::from(dest_dir).join(snapshot_dir_name(&namespace, chrono::Utc::now())),
+    )?;
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let mut files = Vec::new();
+    for resource in &resources {
+        let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), &namespace, resource);
+        let list = api
+            .list(&ListParams::default())
+            .await
+            .map_err(|e| format!("Failed to list {}: {}", resource.plural, e))?;
+
+        for mut obj in list.items {
+            if is_generated(&obj, &resource.kind) {
+                continue;
+            }
+            sanitize_object(&mut obj, &resource.kind);
+            obj.types = Some(kube::core::TypeMeta {
+                api_version: resource.api_version.clone(),
+                kind: resource.kind.clone(),
+            });
+
+            let file_name = format!(
+                "{}-{}.yaml",
+                resource.kind.to_lowercase(),
+                obj.metadata.name.as_deref().unwrap_or_default()
+            );
+            let yaml = serde_yaml::to_string(&obj)
+                .map_err(|e| format!("Failed to serialize {}: {}", file_name, e))?;
+            files.push((file_name, yaml));
+        }
+    }
+
+    // Secrets end up in here, so keep the directory private
+    fs::create_dir(&snapshot_dir)
+        .map_err(|e| format!("Failed to create snapshot directory: {}", e))?;
+    config::set_owner_only_dir_permissions(&snapshot_dir)
+        .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+
+    for (file_name, yaml) in files {
+        let path = snapshot_dir.join(&file_name);
+        fs::write(&path, yaml).map_err(|e| format!("Failed to write {}: {}", file_name, e))?;
+        config::set_owner_only_file_permissions(&path)
+            .map_err(|e| format!("Failed to set secure permissions: {}", e))?;
+    }
+
+    Ok(snapshot_dir.to_string_lossy().to_string())
+}
+
+/// A manifest that couldn't be applied
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ApplyFailure {
+    pub file: String,
+    pub error: String,
+}
+
+/// Outcome of applying a directory of manifests
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ApplyDirectoryResult {
+    /// `Kind/name` of every object applied
+    pub applied: Vec<String>,
+    pub failed: Vec<ApplyFailure>,
+}
+
+/// Parse every document in a manifest file
+fn parse_manifests(contents: &str) -> Result<Vec<DynamicObject>, String> {
+    use serde::Deserialize;
+
+    serde_yaml::Deserializer::from_str(contents)
+        .map(|doc| {
+            let value = serde_yaml::Value::deserialize(doc).map_err(|e| e.to_string())?;
+            if value.is_null() {
+                return Ok(None);
+            }
+            serde_yaml::from_value::<DynamicObject>(value)
+                .map(Some)
+                .map_err(|e| e.to_string())
+        })
+        .filter_map(Result::transpose)
+        .collect()
+}
+
+/// Server-side apply every `*.yaml` manifest in `src_dir` into `target_namespace`,
+/// config and identities first. Objects that fail are reported, not fatal. With
+/// `force`, fields owned by another field manager are taken over instead of
+/// reported as conflicts.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_apply_directory(
+    cluster_id: String,
+    src_dir: String,
+    target_namespace: String,
+    force: Option<bool>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<ApplyDirectoryResult, String> {
+    let src_dir = Path::new(&src_dir)
+        .canonicalize()
+        .map_err(|e| format!("Invalid source directory: {}", e))?;
+    if !src_dir.is_dir() {
+        return Err("Source path is not a directory".to_string());
+    }
+
+    let mut failed = Vec::new();
+    let mut objects = Vec::new();
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&src_dir)
+        .map_err(|e| format!("Failed to read directory: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("yaml") | Some("yml")
+                )
+        })
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let file = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| parse_manifests(&contents));
+        match parsed {
+            Ok(docs) => objects.extend(docs.into_iter().map(|obj| (file.clone(), obj))),
+            Err(error) => failed.push(ApplyFailure { file, error }),
+        }
+    }
+
+    let mut resolved = Vec::new();
+    for (file, obj) in objects {
+        let kind = obj
+            .types
+            .as_ref()
+            .map(|t| t.kind.clone())
+            .unwrap_or_default();
+        match snapshot_resource(&kind) {
+            Some((order, resource)) => resolved.push((order, file, resource, obj)),
+            None => failed.push(ApplyFailure {
+                file,
+                error: format!("Unsupported kind: {}", kind),
+            }),
+        }
+    }
+    resolved.sort_by_key(|(order, ..)| *order);
+
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let mut params = PatchParams::apply(FIELD_MANAGER);
+    if force.unwrap_or(false) {
+        params = params.force();
+    }
+
+    let mut applied = Vec::new();
+    for (_, file, resource, mut obj) in resolved {
+        sanitize_object(&mut obj, &resource.kind);
+        obj.metadata.namespace = Some(target_namespace.clone());
+        let name = obj.metadata.name.clone().unwrap_or_default();
+
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(client.clone(), &target_namespace, &resource);
+        match api.patch(&name, &params, &Patch::Apply(&obj)).await {
+            Ok(_) => applied.push(format!("{}/{}", resource.kind, name)),
+            Err(e) => failed.push(ApplyFailure {
+                file,
+                error: format!("Failed to apply {}/{}: {}", resource.kind, name, e),
+            }),
+        }
+    }
+
+    Ok(ApplyDirectoryResult { applied, failed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(yaml: &str) -> DynamicObject {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_sanitize_object_strips_server_fields() {
+        let mut obj = object(
+            r#"
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+  namespace: payments
+  uid: "1234"
+  resourceVersion: "99"
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: "{}"
+spec:
+  clusterIP: 10.0.0.1
+  ports:
+  - port: 80
+status:
+  loadBalancer: {}
+"#,
+        );
+
+        sanitize_object(&mut obj, "Service");
+
+        assert_eq!(obj.metadata.name.as_deref(), Some("web"));
+        assert_eq!(obj.metadata.namespace, None);
+        assert_eq!(obj.metadata.uid, None);
+        assert_eq!(obj.metadata.resource_version, None);
+        assert_eq!(obj.metadata.annotations, None);
+        assert!(obj.data.get("status").is_none());
+        assert!(obj.data["spec"].get("clusterIP").is_none());
+        assert_eq!(obj.data["spec"]["ports"][0]["port"], 80);
+    }
+
+    #[test]
+    fn test_sanitize_object_keeps_headless_cluster_ip() {
+        let mut obj = object(
+            "apiVersion: v1\nkind: Service\nmetadata:\n  name: db\nspec:\n  clusterIP: None\n  clusterIPs:\n  - None\n",
+        );
+
+        sanitize_object(&mut obj, "Service");
+
+        assert_eq!(obj.data["spec"]["clusterIP"], "None");
+        assert_eq!(obj.data["spec"]["clusterIPs"][0], "None");
+    }
+
+    #[test]
+    fn test_sanitize_object_strips_job_selector() {
+        let mut obj = object(
+            r#"
+apiVersion: batch/v1
+kind: Job
+metadata:
+  name: migrate
+  labels:
+    app: migrate
+    batch.kubernetes.io/controller-uid: abc
+spec:
+  selector:
+    matchLabels:
+      batch.kubernetes.io/controller-uid: abc
+  template:
+    metadata:
+      labels:
+        app: migrate
+        controller-uid: abc
+        batch.kubernetes.io/controller-uid: abc
+        job-name: migrate
+    spec:
+      restartPolicy: Never
+      containers:
+      - name: migrate
+        image: migrate:1
+"#,
+        );
+
+        sanitize_object(&mut obj, "Job");
+
+        let spec = &obj.data["spec"];
+        assert!(spec.get("selector").is_none());
+        let labels = spec["template"]["metadata"]["labels"].as_object().unwrap();
+        assert!(!labels.contains_key("controller-uid"));
+        assert!(!labels.contains_key("batch.kubernetes.io/controller-uid"));
+        assert_eq!(labels["app"], "migrate");
+        assert_eq!(labels["job-name"], "migrate");
+        let meta_labels = obj.metadata.labels.as_ref().unwrap();
+        assert!(!meta_labels.contains_key("batch.kubernetes.io/controller-uid"));
+        assert_eq!(meta_labels["app"], "migrate");
+    }
+
+    #[test]
+    fn test_is_generated() {
+        let default_sa =
+            object("apiVersion: v1\nkind: ServiceAccount\nmetadata:\n  name: default\n");
+        let owned = object(
+            "apiVersion: batch/v1\nkind: Job\nmetadata:\n  name: nightly-1\n  ownerReferences:\n  - apiVersion: batch/v1\n    kind: CronJob\n    name: nightly\n    uid: abc\n",
+        );
+        let config = object("apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: app-config\n");
+
+        assert!(is_generated(&default_sa, "ServiceAccount"));
+        assert!(is_generated(&owned, "Job"));
+        assert!(!is_generated(&config, "ConfigMap"));
+    }
+
+    #[test]
+    fn test_parse_manifests_multi_document() {
+        let docs = parse_manifests(
+            "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: a\n---\n---\napiVersion: v1\nkind: Secret\nmetadata:\n  name: b\n",
+        )
+        .unwrap();
+
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[1].types.as_ref().unwrap().kind, "Secret");
+    }
+
+    #[test]
+    fn test_snapshot_resource_orders_config_before_workloads() {
+        let (config_order, config) = snapshot_resource("ConfigMap").unwrap();
+        let (deploy_order, deploy) = snapshot_resource("deployment").unwrap();
+
+        assert!(config_order < deploy_order);
+        assert_eq!(config.plural, "configmaps");
+        assert_eq!(deploy.api_version, "apps/v1");
+        assert!(snapshot_resource("Pod").is_none());
+    }
+}
@@ -1,9 +1,9 @@
 use crate::cluster_manager::ClusterManagerState;
 use crate::k8s::client::create_client_for_cluster;
-use crate::k8s::common::{calculate_age, K8sEventInfo};
+use crate::k8s::common::{calculate_age, scale_patch, K8sEventInfo};
 use k8s_openapi::api::apps::v1::StatefulSet;
 use k8s_openapi::api::core::v1::{Event, Pod};
-use kube::api::{Api, ListParams};
+use kube::api::{Api, ListParams, Patch, PatchParams};
 use std::collections::HashMap;
 use tauri::State;
 
@@ -41,6 +41,7 @@ pub struct StatefulSetCondition {
 
 /// Get detailed information about a specific statefulset
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_statefulset_details(
     cluster_id: String,
     namespace: String,
@@ -220,6 +221,7 @@ pub fn map_pod_to_statefulset_pod_info(pod: &Pod) -> StatefulSetPodInfo {
 
 /// Get all pods matching a statefulset's selector labels
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_statefulset_pods(
     cluster_id: String,
     namespace: String,
@@ -308,23 +310,7 @@ fn filter_statefulset_events(
 
             name_matches && kind_matches && uid_matches
         })
-        .map(|event| {
-            let source = event
-                .source
-                .as_ref()
-                .and_then(|s| s.component.clone())
-                .unwrap_or_else(|| "unknown".to_string());
-
-            K8sEventInfo {
-                event_type: event.type_.unwrap_or_else(|| "Normal".to_string()),
-                reason: event.reason.unwrap_or_default(),
-                message: event.message.unwrap_or_default(),
-                count: event.count.unwrap_or(1),
-                first_timestamp: event.first_timestamp.as_ref().map(|t| t.0.to_string()),
-                last_timestamp: event.last_timestamp.as_ref().map(|t| t.0.to_string()),
-                source,
-            }
-        })
+        .map(K8sEventInfo::from)
         .collect();
 
     // Sort by last_timestamp descending (most recent first)
@@ -335,6 +321,7 @@ fn filter_statefulset_events(
 
 /// Fetches events related to a specific statefulset
 #[tauri::command]
+#[tracing::instrument(skip(state))]
 pub async fn cluster_get_statefulset_events(
     cluster_id: String,
     namespace: String,
@@ -367,3 +354,26 @@ pub async fn cluster_get_statefulset_events(
 
     Ok(event_infos)
 }
+
+/// Set the replica count through the `scale` subresource and return the new desired count
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_scale_statefulset(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    replicas: i32,
+    state: State<'_, ClusterManagerState>,
+) -> Result<i32, String> {
+    let patch = scale_patch(replicas)?;
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let statefulsets: Api<StatefulSet> = Api::namespaced(client, &namespace);
+
+    let scale = statefulsets
+        .patch_scale(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| format!("Failed to scale statefulset '{}': {}", name, e))?;
+
+    // The API omits `replicas` when it is 0
+    Ok(scale.spec.and_then(|s| s.replicas).unwrap_or(0))
+}
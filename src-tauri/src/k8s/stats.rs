@@ -0,0 +1,267 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::core::v1::Pod;
+use kube::Api;
+use serde::Deserialize;
+use tauri::State;
+
+/// Filesystem usage of one container, from the kubelet stats summary
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct ContainerFsStats {
+    pub name: String,
+    pub rootfs_used_bytes: Option<u64>,
+    pub rootfs_capacity_bytes: Option<u64>,
+    pub logs_used_bytes: Option<u64>,
+}
+
+/// Usage of one pod volume (emptyDir, PVC, ...)
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct VolumeFsStats {
+    pub name: String,
+    pub used_bytes: Option<u64>,
+    pub capacity_bytes: Option<u64>,
+}
+
+/// Disk usage of a pod as reported by its node's kubelet. When the stats endpoint
+/// can't be reached through the API server proxy, `available` is false and
+/// `message` says why.
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct PodFsStats {
+    pub pod_name: String,
+    pub namespace: String,
+    pub node_name: Option<String>,
+    pub available: bool,
+    pub message: Option<String>,
+    pub ephemeral_used_bytes: Option<u64>,
+    pub ephemeral_capacity_bytes: Option<u64>,
+    pub containers: Vec<ContainerFsStats>,
+    pub volumes: Vec<VolumeFsStats>,
+}
+
+impl PodFsStats {
+    fn unavailable(
+        pod_name: String,
+        namespace: String,
+        node_name: Option<String>,
+        message: String,
+    ) -> Self {
+        Self {
+            pod_name,
+            namespace,
+            node_name,
+            available: false,
+            message: Some(message),
+            ephemeral_used_bytes: None,
+            ephemeral_capacity_bytes: None,
+            containers: vec![],
+            volumes: vec![],
+        }
+    }
+}
+
+// Subset of the kubelet `/stats/summary` response
+
+#[derive(Debug, Deserialize)]
+struct StatsSummary {
+    #[serde(default)]
+    pods: Vec<SummaryPod>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryPod {
+    pod_ref: SummaryPodRef,
+    #[serde(default)]
+    containers: Vec<SummaryContainer>,
+    #[serde(default)]
+    volume: Vec<SummaryVolume>,
+    #[serde(rename = "ephemeral-storage")]
+    ephemeral_storage: Option<SummaryFs>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SummaryPodRef {
+    name: String,
+    namespace: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SummaryContainer {
+    name: String,
+    rootfs: Option<SummaryFs>,
+    logs: Option<SummaryFs>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SummaryVolume {
+    name: String,
+    #[serde(flatten)]
+    fs: SummaryFs,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryFs {
+    used_bytes: Option<u64>,
+    capacity_bytes: Option<u64>,
+}
+
+/// Pick one pod out of a node's stats summary
+fn pod_fs_stats(
+    summary: StatsSummary,
+    namespace: &str,
+    pod_name: &str,
+    node_name: Option<String>,
+) -> PodFsStats {
+    let Some(pod) = summary
+        .pods
+        .into_iter()
+        .find(|p| p.pod_ref.namespace == namespace && p.pod_ref.name == pod_name)
+    else {
+        return PodFsStats::unavailable(
+            pod_name.to_string(),
+            namespace.to_string(),
+            node_name,
+            "Stats unavailable: the kubelet has no stats for this pod yet".to_string(),
+        );
+    };
+
+    PodFsStats {
+        pod_name: pod.pod_ref.name,
+        namespace: pod.pod_ref.namespace,
+        node_name,
+        available: true,
+        message: None,
+        ephemeral_used_bytes: pod.ephemeral_storage.as_ref().and_then(|fs| fs.used_bytes),
+        ephemeral_capacity_bytes: pod
+            .ephemeral_storage
+            .as_ref()
+            .and_then(|fs| fs.capacity_bytes),
+        containers: pod
+            .containers
+            .into_iter()
+            .map(|c| ContainerFsStats {
+                name: c.name,
+                rootfs_used_bytes: c.rootfs.as_ref().and_then(|fs| fs.used_bytes),
+                rootfs_capacity_bytes: c.rootfs.as_ref().and_then(|fs| fs.capacity_bytes),
+                logs_used_bytes: c.logs.as_ref().and_then(|fs| fs.used_bytes),
+            })
+            .collect(),
+        volumes: pod
+            .volume
+            .into_iter()
+            .map(|v| VolumeFsStats {
+                name: v.name,
+                used_bytes: v.fs.used_bytes,
+                capacity_bytes: v.fs.capacity_bytes,
+            })
+            .collect(),
+    }
+}
+
+/// Ephemeral-storage and per-container rootfs usage for a pod, read from the kubelet
+/// stats summary through the API server's node proxy (needs `nodes/proxy` access)
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_pod_stats(
+    cluster_id: String,
+    namespace: String,
+    pod_name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<PodFsStats, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let pod = pods
+        .get(&pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod '{}': {}", pod_name, e))?;
+
+    let Some(node_name) = pod.spec.and_then(|s| s.node_name) else {
+        return Ok(PodFsStats::unavailable(
+            pod_name,
+            namespace,
+            None,
+            "Stats unavailable: pod is not scheduled to a node".to_string(),
+        ));
+    };
+
+    let request = kube::core::Request::new("/api/v1/nodes")
+        .get_subresource("proxy/stats/summary", &node_name)
+        .map_err(|e| format!("Failed to build stats request: {}", e))?;
+
+    match client.request::<StatsSummary>(request).await {
+        Ok(summary) => Ok(pod_fs_stats(
+            summary,
+            &namespace,
+            &pod_name,
+            Some(node_name),
+        )),
+        Err(e) => Ok(PodFsStats::unavailable(
+            pod_name,
+            namespace,
+            Some(node_name),
+            format!(
+                "Stats unavailable: kubelet stats endpoint not accessible ({})",
+                e
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUMMARY: &str = r#"{
+        "node": {"nodeName": "node-1"},
+        "pods": [
+            {
+                "podRef": {"name": "other", "namespace": "default", "uid": "1"},
+                "containers": []
+            },
+            {
+                "podRef": {"name": "web", "namespace": "default", "uid": "2"},
+                "containers": [
+                    {
+                        "name": "app",
+                        "rootfs": {"usedBytes": 4096, "capacityBytes": 100000},
+                        "logs": {"usedBytes": 512}
+                    }
+                ],
+                "volume": [{"name": "cache", "usedBytes": 2048, "capacityBytes": 100000}],
+                "ephemeral-storage": {"usedBytes": 6656, "capacityBytes": 100000}
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_pod_fs_stats_picks_pod() {
+        let summary: StatsSummary = serde_json::from_str(SUMMARY).unwrap();
+
+        let stats = pod_fs_stats(summary, "default", "web", Some("node-1".to_string()));
+
+        assert!(stats.available);
+        assert_eq!(stats.ephemeral_used_bytes, Some(6656));
+        assert_eq!(
+            stats.containers,
+            vec![ContainerFsStats {
+                name: "app".to_string(),
+                rootfs_used_bytes: Some(4096),
+                rootfs_capacity_bytes: Some(100000),
+                logs_used_bytes: Some(512),
+            }]
+        );
+        assert_eq!(stats.volumes[0].used_bytes, Some(2048));
+    }
+
+    #[test]
+    fn test_pod_fs_stats_missing_pod() {
+        let summary: StatsSummary = serde_json::from_str(SUMMARY).unwrap();
+
+        let stats = pod_fs_stats(summary, "default", "gone", None);
+
+        assert!(!stats.available);
+        assert!(stats.message.unwrap().contains("unavailable"));
+    }
+}
@@ -1,11 +1,782 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use crate::k8s::common::WorkloadSummary;
+use crate::k8s::job::start_job_watch;
+use crate::k8s::metrics::{start_event_watch, start_metrics_poll, MetricsHistoryState};
+use crate::k8s::pod::{
+    start_cluster_pod_watch, start_container_log_stream, start_pod_detail_watch, LogStreamOptions,
+};
+use crate::k8s::pvc::start_pvc_watch;
+use crate::k8s::workload::{
+    map_configmap_to_summary, map_cronjob_to_summary, map_daemonset_to_summary,
+    map_deployment_to_summary, map_ingress_to_summary, map_job_to_summary, map_pvc_to_summary,
+    map_replicaset_to_summary, map_secret_to_summary, map_service_to_summary,
+    map_statefulset_to_summary,
+};
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use kube::runtime::watcher;
+use kube::{Api, Client, Resource};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tauri::async_runtime::JoinHandle;
+use tauri::{Emitter, State, Window};
 
-pub struct WatcherState(pub Arc<Mutex<HashMap<String, JoinHandle<()>>>>);
+/// What a watch streams, kept so it can be re-established once its connection dies
+#[derive(Debug, Clone)]
+pub enum WatchSpec {
+    Pods {
+        cluster_id: String,
+        namespace: String,
+    },
+    Pod {
+        cluster_id: String,
+        namespace: String,
+        pod_name: String,
+        stream_id: String,
+    },
+    ContainerLogs {
+        cluster_id: String,
+        namespace: String,
+        pod_name: String,
+        container_name: String,
+        stream_id: String,
+        options: LogStreamOptions,
+    },
+    MetricsPoll {
+        cluster_id: String,
+        stream_id: String,
+        interval_secs: Option<u64>,
+        history_len: Option<usize>,
+    },
+    Pvc {
+        cluster_id: String,
+        namespace: String,
+        pvc_name: String,
+        stream_id: String,
+    },
+    Job {
+        cluster_id: String,
+        namespace: String,
+        job_name: String,
+        stream_id: String,
+    },
+    Workloads {
+        cluster_id: String,
+        kind: String,
+        namespace: Option<String>,
+    },
+    Events {
+        cluster_id: String,
+        namespace: Option<String>,
+        types: Vec<String>,
+    },
+}
+
+/// A watch started on behalf of a window
+pub struct WatchRegistration<W = Window> {
+    pub window: W,
+    pub spec: WatchSpec,
+}
+
+/// Running watch tasks (`.0`) and the specs they were started from (`.1`),
+/// both keyed by `watch_key`
+pub struct WatcherState(
+    pub Arc<Mutex<HashMap<String, JoinHandle<()>>>>,
+    pub Arc<Mutex<HashMap<String, WatchRegistration>>>,
+);
 
 impl Default for WatcherState {
     fn default() -> Self {
-        Self(Arc::new(Mutex::new(HashMap::new())))
+        Self(
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+        )
+    }
+}
+
+/// Scope a watch key to the window that owns it, so two windows watching the
+/// same resource don't abort each other's streams
+pub fn watch_key(window_label: &str, key: &str) -> String {
+    format!("{}:{}", window_label, key)
+}
+
+impl WatcherState {
+    /// Abort the watch running under `key`, if any
+    pub fn abort(&self, key: &str) -> Result<(), String> {
+        let mut watchers = self
+            .0
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
+        if let Some(handle) = watchers.remove(key) {
+            handle.abort();
+        }
+        Ok(())
+    }
+
+    /// Store a newly started watch along with the spec needed to restart it
+    pub fn insert(
+        &self,
+        key: String,
+        handle: JoinHandle<()>,
+        registration: WatchRegistration,
+    ) -> Result<(), String> {
+        self.1
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?
+            .insert(key.clone(), registration);
+        self.0
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?
+            .insert(key, handle);
+        Ok(())
+    }
+
+    /// Abort a window's watch under `key` (without the window prefix) and forget it,
+    /// so `reconnect_watches` won't restart it either. Returns whether there was
+    /// anything to stop.
+    pub fn stop(&self, window_label: &str, key: &str) -> Result<bool, String> {
+        stop_watch(&self.0, &self.1, window_label, key)
+    }
+
+    /// Keys of the watches running for a window, without the window prefix
+    pub fn active_keys(&self, window_label: &str) -> Result<Vec<String>, String> {
+        let prefix = watch_key(window_label, "");
+        let mut keys: Vec<String> = self
+            .0
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?
+            .keys()
+            .filter_map(|key| key.strip_prefix(&prefix).map(str::to_string))
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+}
+
+/// Stop a watch this window started, e.g. when leaving a view. `key` is as returned
+/// by `cluster_list_active_watches`, such as `pod_watch:<cluster_id>:<namespace>`.
+/// Returns whether anything was stopped.
+#[tauri::command]
+pub fn cluster_stop_watch(
+    key: String,
+    window: Window,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<bool, String> {
+    watcher_state.stop(window.label(), &key)
+}
+
+/// Keys of the watches currently running for this window
+#[tauri::command]
+pub fn cluster_list_active_watches(
+    window: Window,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<Vec<String>, String> {
+    watcher_state.active_keys(window.label())
+}
+
+/// Watch errors in a row after which the stream is dropped and re-created
+pub(crate) const MAX_CONSECUTIVE_WATCH_ERRORS: u32 = 5;
+const WATCH_RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const WATCH_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Delay before reconnect `attempt` (1-based): doubling from 1s up to 60s, plus up to
+/// 25% jitter so watches that dropped together don't all reconnect at once
+pub(crate) fn reconnect_delay(attempt: u32) -> Duration {
+    let base = WATCH_RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(WATCH_RECONNECT_MAX_DELAY);
+    let jitter = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0)
+        % 1000;
+    base + base.mul_f64(jitter as f64 / 4000.0)
+}
+
+/// Payload of `watch_status`, sent while a watch re-establishes its stream
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WatchStatus {
+    /// The watch's key without the window prefix, as in `cluster_list_active_watches`
+    pub watch: String,
+    /// `reconnecting` or `connected`
+    pub status: &'static str,
+    pub attempt: u32,
+    /// How long until the next attempt, while reconnecting
+    pub retry_in_ms: Option<u64>,
+}
+
+/// Emit `watch_status`. Returns false once the window is gone.
+pub(crate) fn emit_watch_status(
+    window: &Window,
+    watch: &str,
+    status: &'static str,
+    attempt: u32,
+    retry_in: Option<Duration>,
+) -> bool {
+    let payload = WatchStatus {
+        watch: watch.to_string(),
+        status,
+        attempt,
+        retry_in_ms: retry_in.map(|d| d.as_millis() as u64),
+    };
+    window.emit("watch_status", payload).is_ok()
+}
+
+/// Clean up after a watch task exits. The registration is only dropped when the
+/// window is gone; otherwise the watch stays eligible for `reconnect_watches`.
+pub fn finish_watch(
+    handles: &Mutex<HashMap<String, JoinHandle<()>>>,
+    registrations: &Mutex<HashMap<String, WatchRegistration>>,
+    key: &str,
+    window_closed: bool,
+) {
+    if let Ok(mut handles) = handles.lock() {
+        handles.remove(key);
+    } else {
+        eprintln!("Warning: failed to clean up watcher state");
+    }
+
+    if window_closed {
+        if let Ok(mut registrations) = registrations.lock() {
+            registrations.remove(key);
+        }
+    }
+}
+
+/// Abort a window's watch under `key` and forget it. Other watches on the same
+/// object, such as log streams on a watched pod, keep running.
+fn stop_watch<W>(
+    handles: &Mutex<HashMap<String, JoinHandle<()>>>,
+    registrations: &Mutex<HashMap<String, WatchRegistration<W>>>,
+    window_label: &str,
+    key: &str,
+) -> Result<bool, String> {
+    let key = watch_key(window_label, key);
+    // Drop the registration first so a finishing task can't leave it behind
+    let registration = registrations
+        .lock()
+        .map_err(|e| format!("Watcher state lock poisoned: {}", e))?
+        .remove(&key);
+    let handle = handles
+        .lock()
+        .map_err(|e| format!("Watcher state lock poisoned: {}", e))?
+        .remove(&key);
+    // Aborting a task that already finished is a no-op
+    if let Some(handle) = &handle {
+        handle.abort();
+    }
+    Ok(handle.is_some() || registration.is_some())
+}
+
+/// Abort and forget every log stream following a pod that has been deleted, so the
+/// tasks don't linger until their connections error out. Returns the stream ids
+/// of the aborted streams together with the windows that owned them.
+pub fn abort_pod_log_streams<W>(
+    handles: &Mutex<HashMap<String, JoinHandle<()>>>,
+    registrations: &Mutex<HashMap<String, WatchRegistration<W>>>,
+    cluster_id: &str,
+    namespace: &str,
+    pod_name: &str,
+) -> Vec<(W, String)> {
+    let Ok(mut registrations) = registrations.lock() else {
+        eprintln!("Warning: failed to clean up watcher state");
+        return vec![];
+    };
+
+    let keys: Vec<String> = registrations
+        .iter()
+        .filter(|(_, r)| {
+            matches!(
+                &r.spec,
+                WatchSpec::ContainerLogs { cluster_id: c, namespace: ns, pod_name: p, .. }
+                    if c == cluster_id && ns == namespace && p == pod_name
+            )
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    let mut handles = handles.lock().ok();
+    keys.into_iter()
+        .filter_map(|key| {
+            if let Some(handle) = handles.as_mut().and_then(|h| h.remove(&key)) {
+                handle.abort();
+            }
+            match registrations.remove(&key)? {
+                WatchRegistration {
+                    window,
+                    spec: WatchSpec::ContainerLogs { stream_id, .. },
+                } => Some((window, stream_id)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, serde::Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum WorkloadEvent {
+    Added(WorkloadSummary),
+    Deleted(WorkloadSummary),
+}
+
+/// Payload of `workload_event`
+#[derive(Clone, serde::Serialize)]
+pub struct WorkloadWatchEvent {
+    /// e.g. "Deployment"
+    pub kind: String,
+    pub event: WorkloadEvent,
+}
+
+/// A workload watch about to start, with everything it needs besides the kind
+struct WorkloadWatch {
+    client: Client,
+    namespace: Option<String>,
+    window: Window,
+    handles: Arc<Mutex<HashMap<String, JoinHandle<()>>>>,
+    registrations: Arc<Mutex<HashMap<String, WatchRegistration>>>,
+    key: String,
+}
+
+impl WorkloadWatch {
+    /// The task watching `K` and emitting each change as `workload_event` until the
+    /// stream ends or the window goes away
+    fn run<K>(self, map_fn: fn(K) -> WorkloadSummary) -> BoxFuture<'static, ()>
+    where
+        K: Resource<Scope = k8s_openapi::NamespaceResourceScope>
+            + Clone
+            + serde::de::DeserializeOwned
+            + std::fmt::Debug
+            + Send
+            + 'static,
+        K::DynamicType: Default,
+    {
+        let api: Api<K> = match self.namespace.as_deref() {
+            Some(ns) => Api::namespaced(self.client, ns),
+            None => Api::all(self.client),
+        };
+        let kind = K::kind(&Default::default()).to_string();
+
+        async move {
+            let mut stream = watcher(api, watcher::Config::default()).boxed();
+            let mut window_closed = false;
+
+            while let Some(result) = stream.next().await {
+                let event = match result {
+                    Ok(watcher::Event::Apply(obj)) | Ok(watcher::Event::InitApply(obj)) => {
+                        WorkloadEvent::Added(map_fn(obj))
+                    }
+                    Ok(watcher::Event::Delete(obj)) => WorkloadEvent::Deleted(map_fn(obj)),
+                    Ok(_) => continue,
+                    Err(e) => {
+                        println!("Watch error: {}", e);
+                        continue;
+                    }
+                };
+
+                let payload = WorkloadWatchEvent {
+                    kind: kind.clone(),
+                    event,
+                };
+                if let Err(e) = self.window.emit("workload_event", payload) {
+                    println!("Failed to emit event: {}", e);
+                    window_closed = true;
+                    break;
+                }
+            }
+
+            // Cleanup
+            finish_watch(&self.handles, &self.registrations, &self.key, window_closed);
+        }
+        .boxed()
+    }
+}
+
+#[tauri::command]
+pub async fn cluster_start_workload_watch(
+    cluster_id: String,
+    kind: String,
+    namespace: Option<String>,
+    window: Window,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+) -> Result<(), String> {
+    start_workload_watch(cluster_id, kind, namespace, window, &state, &watcher_state).await
+}
+
+/// Start (or restart) watching a kind by name (e.g. "Deployment") for a window,
+/// across all namespaces when `namespace` is `None`
+pub(crate) async fn start_workload_watch(
+    cluster_id: String,
+    kind: String,
+    namespace: Option<String>,
+    window: Window,
+    state: &State<'_, ClusterManagerState>,
+    watcher_state: &State<'_, WatcherState>,
+) -> Result<(), String> {
+    let client = create_client_for_cluster(&cluster_id, state).await?;
+    let kind_key = kind.to_lowercase();
+    let key = watch_key(
+        window.label(),
+        &format!(
+            "workload_watch:{}:{}:{}",
+            cluster_id,
+            kind_key,
+            namespace.as_deref().unwrap_or("all")
+        ),
+    );
+
+    let watch = WorkloadWatch {
+        client,
+        namespace: namespace.clone(),
+        window: window.clone(),
+        handles: watcher_state.0.clone(),
+        registrations: watcher_state.1.clone(),
+        key: key.clone(),
+    };
+
+    // Resolve the kind before touching any running watch
+    let task = match kind_key.as_str() {
+        "deployment" => watch.run(map_deployment_to_summary),
+        "statefulset" => watch.run(map_statefulset_to_summary),
+        "daemonset" => watch.run(map_daemonset_to_summary),
+        "replicaset" => watch.run(map_replicaset_to_summary),
+        "job" => watch.run(map_job_to_summary),
+        "cronjob" => watch.run(map_cronjob_to_summary),
+        "configmap" => watch.run(map_configmap_to_summary),
+        "secret" => watch.run(map_secret_to_summary),
+        "service" => watch.run(map_service_to_summary),
+        "ingress" => watch.run(map_ingress_to_summary),
+        "persistentvolumeclaim" => watch.run(map_pvc_to_summary),
+        _ => return Err(format!("Unsupported kind for watching: {}", kind)),
+    };
+
+    // Abort existing if any
+    watcher_state.abort(&key)?;
+
+    let registration = WatchRegistration {
+        window,
+        spec: WatchSpec::Workloads {
+            cluster_id,
+            kind,
+            namespace,
+        },
+    };
+    let handle = tauri::async_runtime::spawn(task);
+
+    // Store new handle
+    watcher_state.insert(key, handle, registration)
+}
+
+/// Whether the task behind `key` has ended (or was never stored), so its
+/// registration needs a new task. Running watches recover on their own.
+fn is_watch_dead(handles: &HashMap<String, JoinHandle<()>>, key: &str) -> bool {
+    handles
+        .get(key)
+        .map_or(true, |handle| handle.inner().is_finished())
+}
+
+/// Re-establish the watches of a window whose tasks have died, e.g. after the machine
+/// wakes from sleep and their connections are gone. Watches that are still running
+/// are left alone, so a live log stream isn't restarted and doesn't repeat its tail.
+/// Returns how many watches were restarted.
+#[tauri::command]
+pub async fn reconnect_watches(
+    window_label: String,
+    state: State<'_, ClusterManagerState>,
+    watcher_state: State<'_, WatcherState>,
+    history_state: State<'_, MetricsHistoryState>,
+) -> Result<usize, String> {
+    let registrations: Vec<(Window, WatchSpec)> = {
+        let registrations = watcher_state
+            .1
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
+        let handles = watcher_state
+            .0
+            .lock()
+            .map_err(|e| format!("Watcher state lock poisoned: {}", e))?;
+        registrations
+            .iter()
+            .filter(|(key, r)| r.window.label() == window_label && is_watch_dead(&handles, key))
+            .map(|(_, r)| (r.window.clone(), r.spec.clone()))
+            .collect()
+    };
+
+    let mut reconnected = 0;
+    for (window, spec) in registrations {
+        let result = match spec {
+            WatchSpec::Pods {
+                cluster_id,
+                namespace,
+            } => start_cluster_pod_watch(cluster_id, namespace, window, &state, &watcher_state)
+                .await
+                .map_err(String::from),
+            WatchSpec::Pod {
+                cluster_id,
+                namespace,
+                pod_name,
+                stream_id,
+            } => start_pod_detail_watch(
+                cluster_id,
+                namespace,
+                pod_name,
+                stream_id,
+                window,
+                &state,
+                &watcher_state,
+            )
+            .await
+            .map_err(String::from),
+            WatchSpec::ContainerLogs {
+                cluster_id,
+                namespace,
+                pod_name,
+                container_name,
+                stream_id,
+                options,
+            } => start_container_log_stream(
+                cluster_id,
+                namespace,
+                pod_name,
+                container_name,
+                stream_id,
+                options,
+                window,
+                &state,
+                &watcher_state,
+            )
+            .await
+            .map_err(String::from),
+            WatchSpec::MetricsPoll {
+                cluster_id,
+                stream_id,
+                interval_secs,
+                history_len,
+            } => {
+                start_metrics_poll(
+                    cluster_id,
+                    stream_id,
+                    interval_secs,
+                    history_len,
+                    window,
+                    &state,
+                    &watcher_state,
+                    &history_state,
+                )
+                .await
+            }
+            WatchSpec::Pvc {
+                cluster_id,
+                namespace,
+                pvc_name,
+                stream_id,
+            } => {
+                start_pvc_watch(
+                    cluster_id,
+                    namespace,
+                    pvc_name,
+                    stream_id,
+                    window,
+                    &state,
+                    &watcher_state,
+                )
+                .await
+            }
+            WatchSpec::Job {
+                cluster_id,
+                namespace,
+                job_name,
+                stream_id,
+            } => {
+                start_job_watch(
+                    cluster_id,
+                    namespace,
+                    job_name,
+                    stream_id,
+                    window,
+                    &state,
+                    &watcher_state,
+                )
+                .await
+            }
+            WatchSpec::Workloads {
+                cluster_id,
+                kind,
+                namespace,
+            } => {
+                start_workload_watch(cluster_id, kind, namespace, window, &state, &watcher_state)
+                    .await
+            }
+            WatchSpec::Events {
+                cluster_id,
+                namespace,
+                types,
+            } => {
+                start_event_watch(cluster_id, namespace, types, window, &state, &watcher_state)
+                    .await
+            }
+        };
+
+        match result {
+            Ok(()) => reconnected += 1,
+            Err(e) => println!("Failed to reconnect watch: {}", e),
+        }
+    }
+
+    Ok(reconnected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_delay_doubles_with_jitter_and_caps() {
+        for (attempt, base) in [(1, 1), (2, 2), (3, 4), (7, 60), (40, 60)] {
+            let delay = reconnect_delay(attempt);
+            let base = Duration::from_secs(base);
+            assert!(delay >= base, "attempt {}: {:?}", attempt, delay);
+            assert!(
+                delay <= base.mul_f64(1.25),
+                "attempt {}: {:?}",
+                attempt,
+                delay
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_dead_watches_need_reconnect() {
+        let mut handles = HashMap::new();
+        let finished = tauri::async_runtime::spawn(async {});
+        tauri::async_runtime::block_on(async {
+            while !finished.inner().is_finished() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+        handles.insert("w:finished".to_string(), finished);
+        handles.insert(
+            "w:running".to_string(),
+            tauri::async_runtime::spawn(futures::future::pending::<()>()),
+        );
+
+        assert!(is_watch_dead(&handles, "w:finished"));
+        assert!(is_watch_dead(&handles, "w:missing"));
+        assert!(!is_watch_dead(&handles, "w:running"));
+
+        handles["w:running"].abort();
+    }
+
+    fn log_spec(pod_name: &str, stream_id: &str) -> WatchSpec {
+        WatchSpec::ContainerLogs {
+            cluster_id: "c1".to_string(),
+            namespace: "default".to_string(),
+            pod_name: pod_name.to_string(),
+            container_name: "app".to_string(),
+            stream_id: stream_id.to_string(),
+            options: LogStreamOptions::default(),
+        }
+    }
+
+    #[test]
+    fn test_abort_pod_log_streams_only_touches_that_pod() {
+        let handles = Mutex::new(HashMap::new());
+        let registrations = Mutex::new(HashMap::new());
+        let entries = [
+            ("w:logs:s1", "window-a", log_spec("web-0", "s1")),
+            ("w:logs:s2", "window-b", log_spec("web-0", "s2")),
+            ("w:logs:s3", "window-a", log_spec("web-1", "s3")),
+            (
+                "w:pod:s4",
+                "window-a",
+                WatchSpec::Pod {
+                    cluster_id: "c1".to_string(),
+                    namespace: "default".to_string(),
+                    pod_name: "web-0".to_string(),
+                    stream_id: "s4".to_string(),
+                },
+            ),
+        ];
+        for (key, window, spec) in entries {
+            handles.lock().unwrap().insert(
+                key.to_string(),
+                tauri::async_runtime::spawn(futures::future::pending::<()>()),
+            );
+            registrations
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), WatchRegistration { window, spec });
+        }
+
+        let mut aborted = abort_pod_log_streams(&handles, &registrations, "c1", "default", "web-0");
+        aborted.sort();
+        assert_eq!(
+            aborted,
+            vec![
+                ("window-a", "s1".to_string()),
+                ("window-b", "s2".to_string())
+            ]
+        );
+
+        let mut remaining: Vec<String> = registrations.lock().unwrap().keys().cloned().collect();
+        remaining.sort();
+        assert_eq!(remaining, vec!["w:logs:s3", "w:pod:s4"]);
+        assert!(!handles.lock().unwrap().contains_key("w:logs:s1"));
+
+        assert!(
+            abort_pod_log_streams(&handles, &registrations, "c2", "default", "web-1").is_empty()
+        );
+
+        for handle in handles.lock().unwrap().values() {
+            handle.abort();
+        }
+    }
+
+    #[test]
+    fn test_stop_watch_aborts_only_its_key() {
+        let handles = Mutex::new(HashMap::new());
+        let registrations = Mutex::new(HashMap::new());
+        let pod_spec = WatchSpec::Pod {
+            cluster_id: "c1".to_string(),
+            namespace: "default".to_string(),
+            pod_name: "web-0".to_string(),
+            stream_id: "s4".to_string(),
+        };
+        let entries = [
+            ("a:pod_detail:s4", "window-a", pod_spec),
+            ("a:logs:s1", "window-a", log_spec("web-0", "s1")),
+            ("a:logs:s3", "window-a", log_spec("web-1", "s3")),
+            ("b:logs:s2", "window-b", log_spec("web-0", "s2")),
+        ];
+        for (key, window, spec) in entries {
+            handles.lock().unwrap().insert(
+                key.to_string(),
+                tauri::async_runtime::spawn(futures::future::pending::<()>()),
+            );
+            registrations
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), WatchRegistration { window, spec });
+        }
+
+        assert!(stop_watch(&handles, &registrations, "a", "pod_detail:s4").unwrap());
+
+        // Log streams on the same pod belong to their own views and keep running
+        let mut remaining: Vec<String> = handles.lock().unwrap().keys().cloned().collect();
+        remaining.sort();
+        assert_eq!(remaining, vec!["a:logs:s1", "a:logs:s3", "b:logs:s2"]);
+        assert!(!registrations
+            .lock()
+            .unwrap()
+            .contains_key("a:pod_detail:s4"));
+
+        assert!(stop_watch(&handles, &registrations, "a", "logs:s3").unwrap());
+        assert!(!stop_watch(&handles, &registrations, "a", "missing").unwrap());
+
+        for handle in handles.lock().unwrap().values() {
+            handle.abort();
+        }
     }
 }
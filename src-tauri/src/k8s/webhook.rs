@@ -0,0 +1,302 @@
+use crate::cluster_manager::ClusterManagerState;
+use crate::k8s::client::create_client_for_cluster;
+use k8s_openapi::api::admissionregistration::v1::{
+    MutatingWebhookConfiguration, RuleWithOperations, ValidatingWebhookConfiguration,
+    WebhookClientConfig,
+};
+use k8s_openapi::api::core::v1::Endpoints;
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use kube::api::{Api, ListParams};
+use std::collections::HashSet;
+use tauri::State;
+
+/// A single admission webhook and where it sends requests
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct WebhookInfo {
+    pub configuration: String,
+    /// "Mutating" or "Validating"
+    pub kind: String,
+    pub name: String,
+    /// `namespace/service:port/path` or the webhook URL
+    pub target: String,
+    pub service_namespace: Option<String>,
+    pub service_name: Option<String>,
+    pub failure_policy: String,
+    pub timeout_seconds: i32,
+    pub rules: Vec<String>,
+    /// Backed by a service with no ready endpoints: requests will fail (or be skipped with `Ignore`)
+    pub at_risk: bool,
+}
+
+/// Render a rule as e.g. "CREATE,UPDATE apps/v1 deployments"
+fn format_rule(rule: &RuleWithOperations) -> String {
+    let join = |v: &Option<Vec<String>>| v.as_ref().map(|v| v.join(",")).unwrap_or_default();
+    let groups = rule
+        .api_groups
+        .as_ref()
+        .map(|g| {
+            g.iter()
+                .map(|g| if g.is_empty() { "core" } else { g.as_str() })
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+
+    format!(
+        "{} {}/{} {}",
+        join(&rule.operations),
+        groups,
+        join(&rule.api_versions),
+        join(&rule.resources)
+    )
+}
+
+fn build_webhook_info(
+    configuration: &str,
+    kind: &str,
+    name: &str,
+    client_config: &WebhookClientConfig,
+    failure_policy: Option<&String>,
+    rules: Option<&Vec<RuleWithOperations>>,
+    timeout_seconds: Option<i32>,
+) -> WebhookInfo {
+    let service = client_config.service.as_ref();
+    let target = match (service, client_config.url.as_ref()) {
+        (Some(svc), _) => format!(
+            "{}/{}:{}{}",
+            svc.namespace,
+            svc.name,
+            svc.port.unwrap_or(443),
+            svc.path.as_deref().unwrap_or("")
+        ),
+        (None, Some(url)) => url.clone(),
+        (None, None) => "-".to_string(),
+    };
+
+    WebhookInfo {
+        configuration: configuration.to_string(),
+        kind: kind.to_string(),
+        name: name.to_string(),
+        target,
+        service_namespace: service.map(|s| s.namespace.clone()),
+        service_name: service.map(|s| s.name.clone()),
+        // API defaults for admissionregistration.k8s.io/v1
+        failure_policy: failure_policy
+            .cloned()
+            .unwrap_or_else(|| "Fail".to_string()),
+        timeout_seconds: timeout_seconds.unwrap_or(10),
+        rules: rules
+            .map(|r| r.iter().map(format_rule).collect())
+            .unwrap_or_default(),
+        at_risk: false,
+    }
+}
+
+/// Services (namespace, name) with at least one ready endpoint, from EndpointSlices
+fn ready_services_from_slices(slices: &[EndpointSlice]) -> HashSet<(String, String)> {
+    slices
+        .iter()
+        .filter(|slice| {
+            slice
+                .endpoints
+                .iter()
+                .any(|ep| ep.conditions.as_ref().and_then(|c| c.ready).unwrap_or(true))
+        })
+        .filter_map(|slice| {
+            let namespace = slice.metadata.namespace.clone()?;
+            let service = slice
+                .metadata
+                .labels
+                .as_ref()?
+                .get("kubernetes.io/service-name")?
+                .clone();
+            Some((namespace, service))
+        })
+        .collect()
+}
+
+/// Services (namespace, name) with at least one ready address, from legacy Endpoints
+fn ready_services_from_endpoints(endpoints: &[Endpoints]) -> HashSet<(String, String)> {
+    endpoints
+        .iter()
+        .filter(|ep| {
+            ep.subsets
+                .iter()
+                .flatten()
+                .any(|s| s.addresses.as_ref().map(|a| !a.is_empty()).unwrap_or(false))
+        })
+        .filter_map(|ep| Some((ep.metadata.namespace.clone()?, ep.metadata.name.clone()?)))
+        .collect()
+}
+
+fn mark_at_risk(webhooks: &mut [WebhookInfo], ready: &HashSet<(String, String)>) {
+    for webhook in webhooks.iter_mut() {
+        if let (Some(ns), Some(name)) = (&webhook.service_namespace, &webhook.service_name) {
+            webhook.at_risk = !ready.contains(&(ns.clone(), name.clone()));
+        }
+    }
+}
+
+/// List mutating and validating admission webhooks, flagging those whose service is down
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_list_webhook_configs(
+    cluster_id: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<Vec<WebhookInfo>, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let lp = ListParams::default();
+
+    let mutating_api: Api<MutatingWebhookConfiguration> = Api::all(client.clone());
+    let mutating = mutating_api
+        .list(&lp)
+        .await
+        .map_err(|e| format!("Failed to list mutating webhook configurations: {}", e))?;
+
+    let validating_api: Api<ValidatingWebhookConfiguration> = Api::all(client.clone());
+    let validating = validating_api
+        .list(&lp)
+        .await
+        .map_err(|e| format!("Failed to list validating webhook configurations: {}", e))?;
+
+    let mut webhooks: Vec<WebhookInfo> = Vec::new();
+
+    for config in &mutating.items {
+        let config_name = config.metadata.name.clone().unwrap_or_default();
+        for webhook in config.webhooks.iter().flatten() {
+            webhooks.push(build_webhook_info(
+                &config_name,
+                "Mutating",
+                &webhook.name,
+                &webhook.client_config,
+                webhook.failure_policy.as_ref(),
+                webhook.rules.as_ref(),
+                webhook.timeout_seconds,
+            ));
+        }
+    }
+
+    for config in &validating.items {
+        let config_name = config.metadata.name.clone().unwrap_or_default();
+        for webhook in config.webhooks.iter().flatten() {
+            webhooks.push(build_webhook_info(
+                &config_name,
+                "Validating",
+                &webhook.name,
+                &webhook.client_config,
+                webhook.failure_policy.as_ref(),
+                webhook.rules.as_ref(),
+                webhook.timeout_seconds,
+            ));
+        }
+    }
+
+    if webhooks.iter().any(|w| w.service_name.is_some()) {
+        // Prefer EndpointSlices, falling back to legacy Endpoints like service backends do
+        let slices_api: Api<EndpointSlice> = Api::all(client.clone());
+        let ready = match slices_api.list(&lp).await {
+            Ok(list) => ready_services_from_slices(&list.items),
+            Err(_) => {
+                let endpoints_api: Api<Endpoints> = Api::all(client);
+                let endpoints = endpoints_api
+                    .list(&lp)
+                    .await
+                    .map_err(|e| format!("Failed to list endpoints: {}", e))?;
+                ready_services_from_endpoints(&endpoints.items)
+            }
+        };
+        mark_at_risk(&mut webhooks, &ready);
+    }
+
+    Ok(webhooks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::admissionregistration::v1::ServiceReference;
+
+    fn service_config(namespace: &str, name: &str) -> WebhookClientConfig {
+        WebhookClientConfig {
+            service: Some(ServiceReference {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+                path: Some("/validate".to_string()),
+                port: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_webhook_info_defaults() {
+        let info = build_webhook_info(
+            "gatekeeper",
+            "Validating",
+            "check.gatekeeper.sh",
+            &service_config("gatekeeper-system", "gatekeeper-webhook"),
+            None,
+            Some(&vec![RuleWithOperations {
+                api_groups: Some(vec!["".to_string()]),
+                api_versions: Some(vec!["v1".to_string()]),
+                operations: Some(vec!["CREATE".to_string(), "UPDATE".to_string()]),
+                resources: Some(vec!["pods".to_string()]),
+                scope: None,
+            }]),
+            None,
+        );
+
+        assert_eq!(
+            info.target,
+            "gatekeeper-system/gatekeeper-webhook:443/validate"
+        );
+        assert_eq!(info.failure_policy, "Fail");
+        assert_eq!(info.timeout_seconds, 10);
+        assert_eq!(info.rules, vec!["CREATE,UPDATE core/v1 pods"]);
+    }
+
+    #[test]
+    fn test_url_webhooks_are_never_at_risk() {
+        let mut webhooks = vec![build_webhook_info(
+            "external",
+            "Mutating",
+            "hook.example.com",
+            &WebhookClientConfig {
+                url: Some("https://hook.example.com/mutate".to_string()),
+                ..Default::default()
+            },
+            Some(&"Ignore".to_string()),
+            None,
+            Some(5),
+        )];
+
+        mark_at_risk(&mut webhooks, &HashSet::new());
+
+        assert_eq!(webhooks[0].target, "https://hook.example.com/mutate");
+        assert!(!webhooks[0].at_risk);
+    }
+
+    #[test]
+    fn test_mark_at_risk_without_ready_endpoints() {
+        let config = service_config("system", "webhook");
+        let mut webhooks = vec![
+            build_webhook_info("a", "Validating", "a", &config, None, None, None),
+            build_webhook_info(
+                "b",
+                "Validating",
+                "b",
+                &service_config("system", "healthy"),
+                None,
+                None,
+                None,
+            ),
+        ];
+        let ready: HashSet<(String, String)> =
+            [("system".to_string(), "healthy".to_string())].into();
+
+        mark_at_risk(&mut webhooks, &ready);
+
+        assert!(webhooks[0].at_risk);
+        assert!(!webhooks[1].at_risk);
+    }
+}
@@ -1,52 +1,95 @@
 use crate::cluster_manager::ClusterManagerState;
 use crate::k8s::client::create_client_for_cluster;
-use crate::k8s::common::{calculate_age, get_created_at, WorkloadSummary};
+use crate::k8s::common::{
+    calculate_age, get_created_at, is_forbidden, is_not_found, label_selector_params,
+    next_continue_token, page_params, parse_propagation_policy, WorkloadList, WorkloadSummary,
+};
+use crate::k8s::cron::CronSchedule;
+use crate::k8s::kind::ResourceKind;
+use crate::settings::load_settings;
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
-use k8s_openapi::api::autoscaling::v1::HorizontalPodAutoscaler;
+use k8s_openapi::api::autoscaling::{v1, v2};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::{
     ConfigMap, Endpoints, LimitRange, PersistentVolume, PersistentVolumeClaim, ResourceQuota,
     Secret, Service, ServiceAccount,
 };
+use k8s_openapi::api::discovery::v1::EndpointSlice;
 use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::api::rbac::v1::{ClusterRole, Role};
 use k8s_openapi::api::storage::v1::StorageClass;
-use kube::api::Api;
+use kube::api::{
+    Api, DeleteParams, ListParams, ObjectList, ObjectMeta, Patch, PatchParams, PostParams,
+};
+use kube::{Client, Resource};
 use tauri::State;
 
 macro_rules! impl_workload_commands {
-    ($resource:ty, $list_fn:ident, $delete_fn:ident, $map_fn:ident) => {
+    ($resource:ty, $list_fn:ident, $list_filtered_fn:ident, $delete_fn:ident, $map_fn:ident) => {
+        /// List in `namespace` (or all namespaces), keeping only objects that match
+        /// `label_selector` when one is given: the selector narrows within the namespace
         #[tauri::command]
+        #[tracing::instrument(skip(state), fields(kind = stringify!($resource)))]
         pub async fn $list_fn(
             cluster_id: String,
             namespace: Option<String>,
+            label_selector: Option<String>,
+            limit: Option<u32>,
+            continue_token: Option<String>,
             state: State<'_, ClusterManagerState>,
-        ) -> Result<Vec<WorkloadSummary>, String> {
+        ) -> Result<WorkloadList, String> {
+            let lp = label_selector_params(label_selector)?;
             let client = create_client_for_cluster(&cluster_id, &state).await?;
+            let cluster_wide = namespace.is_none();
             let api: Api<$resource> = if let Some(ns) = namespace {
                 Api::namespaced(client, &ns)
             } else {
                 Api::all(client)
             };
+            list_page(api, lp, limit, continue_token, $map_fn, cluster_wide).await
+        }
 
-            let list = api
-                .list(&Default::default())
-                .await
-                .map_err(|e| e.to_string())?;
-            Ok(list.items.into_iter().map($map_fn).collect())
+        /// Deprecated alias of the plain list, which takes `label_selector` itself;
+        /// kept so existing callers of the `_filtered` name keep working
+        #[tauri::command]
+        pub async fn $list_filtered_fn(
+            cluster_id: String,
+            namespace: Option<String>,
+            label_selector: Option<String>,
+            limit: Option<u32>,
+            continue_token: Option<String>,
+            state: State<'_, ClusterManagerState>,
+        ) -> Result<WorkloadList, String> {
+            $list_fn(
+                cluster_id,
+                namespace,
+                label_selector,
+                limit,
+                continue_token,
+                state,
+            )
+            .await
         }
 
+        /// Delete an object; `propagation` decides whether its dependents are removed too
         #[tauri::command]
+        #[tracing::instrument(skip(state), fields(kind = stringify!($resource)))]
         pub async fn $delete_fn(
             cluster_id: String,
             namespace: String,
             name: String,
+            grace_period_seconds: Option<u32>,
+            propagation: Option<String>,
             state: State<'_, ClusterManagerState>,
         ) -> Result<(), String> {
+            let params = DeleteParams {
+                propagation_policy: Some(parse_propagation_policy(propagation.as_deref())?),
+                ..load_settings(&state).delete_params(grace_period_seconds)
+            };
             let client = create_client_for_cluster(&cluster_id, &state).await?;
             let api: Api<$resource> = Api::namespaced(client, &namespace);
-            api.delete(&name, &Default::default())
+            api.delete(&name, &params)
                 .await
                 .map_err(|e| e.to_string())?;
             Ok(())
@@ -55,33 +98,65 @@ macro_rules! impl_workload_commands {
 }
 
 macro_rules! impl_cluster_resource_commands {
-    ($resource:ty, $list_fn:ident, $delete_fn:ident, $map_fn:ident) => {
+    ($resource:ty, $list_fn:ident, $list_filtered_fn:ident, $delete_fn:ident, $map_fn:ident) => {
+        /// List every object, keeping only those that match `label_selector` when one
+        /// is given. `_namespace` is ignored: these objects aren't namespaced.
         #[tauri::command]
+        #[tracing::instrument(skip(state), fields(kind = stringify!($resource)))]
         pub async fn $list_fn(
             cluster_id: String,
             _namespace: Option<String>,
+            label_selector: Option<String>,
+            limit: Option<u32>,
+            continue_token: Option<String>,
             state: State<'_, ClusterManagerState>,
-        ) -> Result<Vec<WorkloadSummary>, String> {
+        ) -> Result<WorkloadList, String> {
+            let lp = label_selector_params(label_selector)?;
             let client = create_client_for_cluster(&cluster_id, &state).await?;
             let api: Api<$resource> = Api::all(client);
+            list_page(api, lp, limit, continue_token, $map_fn, true).await
+        }
 
-            let list = api
-                .list(&Default::default())
-                .await
-                .map_err(|e| e.to_string())?;
-            Ok(list.items.into_iter().map($map_fn).collect())
+        /// Deprecated alias of the plain list, which takes `label_selector` itself;
+        /// kept so existing callers of the `_filtered` name keep working
+        #[tauri::command]
+        pub async fn $list_filtered_fn(
+            cluster_id: String,
+            _namespace: Option<String>,
+            label_selector: Option<String>,
+            limit: Option<u32>,
+            continue_token: Option<String>,
+            state: State<'_, ClusterManagerState>,
+        ) -> Result<WorkloadList, String> {
+            $list_fn(
+                cluster_id,
+                _namespace,
+                label_selector,
+                limit,
+                continue_token,
+                state,
+            )
+            .await
         }
 
+        /// Delete an object; `propagation` decides whether its dependents are removed too
         #[tauri::command]
+        #[tracing::instrument(skip(state), fields(kind = stringify!($resource)))]
         pub async fn $delete_fn(
             cluster_id: String,
             _namespace: String,
             name: String,
+            grace_period_seconds: Option<u32>,
+            propagation: Option<String>,
             state: State<'_, ClusterManagerState>,
         ) -> Result<(), String> {
+            let params = DeleteParams {
+                propagation_policy: Some(parse_propagation_policy(propagation.as_deref())?),
+                ..load_settings(&state).delete_params(grace_period_seconds)
+            };
             let client = create_client_for_cluster(&cluster_id, &state).await?;
             let api: Api<$resource> = Api::all(client);
-            api.delete(&name, &Default::default())
+            api.delete(&name, &params)
                 .await
                 .map_err(|e| e.to_string())?;
             Ok(())
@@ -89,7 +164,31 @@ macro_rules! impl_cluster_resource_commands {
     };
 }
 
-fn map_deployment_to_summary(d: Deployment) -> WorkloadSummary {
+/// Wrap a list result (items and the next page's continue token) for the frontend.
+/// A Forbidden cluster-wide list becomes an empty, partial result so users who can
+/// only list some namespaces still get a view.
+fn into_workload_list(
+    result: Result<(Vec<WorkloadSummary>, Option<String>), kube::Error>,
+    cluster_wide: bool,
+) -> Result<WorkloadList, String> {
+    match result {
+        Ok((items, continue_token)) => Ok(WorkloadList {
+            items,
+            partial: false,
+            partial_reason: None,
+            continue_token,
+        }),
+        Err(e) if cluster_wide && is_forbidden(&e) => Ok(WorkloadList {
+            items: vec![],
+            partial: true,
+            partial_reason: Some(e.to_string()),
+            continue_token: None,
+        }),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub(crate) fn map_deployment_to_summary(d: Deployment) -> WorkloadSummary {
     let meta = d.metadata;
     let spec = d.spec.unwrap_or_default();
     let status = d.status.unwrap_or_default();
@@ -120,7 +219,7 @@ fn map_deployment_to_summary(d: Deployment) -> WorkloadSummary {
     }
 }
 
-fn map_statefulset_to_summary(s: StatefulSet) -> WorkloadSummary {
+pub(crate) fn map_statefulset_to_summary(s: StatefulSet) -> WorkloadSummary {
     let meta = s.metadata;
     let spec = s.spec.unwrap_or_default();
     let status = s.status.unwrap_or_default();
@@ -151,7 +250,7 @@ fn map_statefulset_to_summary(s: StatefulSet) -> WorkloadSummary {
     }
 }
 
-fn map_daemonset_to_summary(d: DaemonSet) -> WorkloadSummary {
+pub(crate) fn map_daemonset_to_summary(d: DaemonSet) -> WorkloadSummary {
     let meta = d.metadata;
     let spec = d.spec.unwrap_or_default();
     let status = d.status.unwrap_or_default();
@@ -182,7 +281,7 @@ fn map_daemonset_to_summary(d: DaemonSet) -> WorkloadSummary {
     }
 }
 
-fn map_replicaset_to_summary(r: ReplicaSet) -> WorkloadSummary {
+pub(crate) fn map_replicaset_to_summary(r: ReplicaSet) -> WorkloadSummary {
     let meta = r.metadata;
     let spec = r.spec.unwrap_or_default();
     let status = r.status.unwrap_or_default();
@@ -217,7 +316,7 @@ fn map_replicaset_to_summary(r: ReplicaSet) -> WorkloadSummary {
     }
 }
 
-fn map_job_to_summary(j: Job) -> WorkloadSummary {
+pub(crate) fn map_job_to_summary(j: Job) -> WorkloadSummary {
     let meta = j.metadata;
     let spec = j.spec.unwrap_or_default();
     let status = j.status.unwrap_or_default();
@@ -248,7 +347,7 @@ fn map_job_to_summary(j: Job) -> WorkloadSummary {
     }
 }
 
-fn map_cronjob_to_summary(c: CronJob) -> WorkloadSummary {
+pub(crate) fn map_cronjob_to_summary(c: CronJob) -> WorkloadSummary {
     let meta = c.metadata;
     let spec = c.spec.unwrap_or_default();
     let status = c.status.unwrap_or_default();
@@ -282,8 +381,222 @@ fn map_cronjob_to_summary(c: CronJob) -> WorkloadSummary {
     }
 }
 
+/// Schedule and run history of a CronJob
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CronJobDetails {
+    pub schedule: String,
+    pub time_zone: Option<String>,
+    pub suspend: bool,
+    pub last_schedule_time: Option<String>,
+    /// Names of the Jobs currently running
+    pub active_jobs: Vec<String>,
+    pub concurrency_policy: String,
+    pub successful_jobs_history_limit: Option<i32>,
+    /// Next run after the last one, by the schedule. `None` when suspended, when the
+    /// schedule can't be parsed, or when it's in a time zone other than UTC.
+    pub next_estimated_run: Option<String>,
+}
+
+fn parse_k8s_time(
+    time: &k8s_openapi::apimachinery::pkg::apis::meta::v1::Time,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(&time.0.to_string())
+        .ok()
+        .map(|t| t.with_timezone(&chrono::Utc))
+}
+
+/// Estimate the next run from the last one; a run already overdue (the controller
+/// was down, say) is estimated from `now` instead
+fn estimate_next_run(
+    schedule: &str,
+    last_schedule_time: Option<chrono::DateTime<chrono::Utc>>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let schedule = CronSchedule::parse(schedule).ok()?;
+    last_schedule_time
+        .and_then(|last| schedule.next_after(last))
+        .filter(|next| *next > now)
+        .or_else(|| schedule.next_after(now))
+}
+
+fn map_cronjob_details(c: CronJob, now: chrono::DateTime<chrono::Utc>) -> CronJobDetails {
+    let spec = c.spec.unwrap_or_default();
+    let status = c.status.unwrap_or_default();
+    let suspend = spec.suspend.unwrap_or(false);
+    let last_schedule_time = status.last_schedule_time.as_ref().and_then(parse_k8s_time);
+
+    // Schedules are evaluated in the controller's zone (UTC) unless timeZone says otherwise
+    let in_utc = spec
+        .time_zone
+        .as_deref()
+        .is_none_or(|tz| matches!(tz, "UTC" | "Etc/UTC"));
+    let next_estimated_run = if suspend || !in_utc {
+        None
+    } else {
+        estimate_next_run(&spec.schedule, last_schedule_time, now)
+    };
+
+    CronJobDetails {
+        schedule: spec.schedule,
+        time_zone: spec.time_zone,
+        suspend,
+        last_schedule_time: last_schedule_time.map(|t| t.to_rfc3339()),
+        active_jobs: status
+            .active
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|job| job.name)
+            .collect(),
+        concurrency_policy: spec
+            .concurrency_policy
+            .unwrap_or_else(|| "Allow".to_string()),
+        successful_jobs_history_limit: spec.successful_jobs_history_limit,
+        next_estimated_run: next_estimated_run.map(|t| t.to_rfc3339()),
+    }
+}
+
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_get_cronjob_details(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<CronJobDetails, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let api: Api<CronJob> = Api::namespaced(client, &namespace);
+    let cronjob = api
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get cronjob '{}': {}", name, e))?;
+
+    Ok(map_cronjob_details(cronjob, chrono::Utc::now()))
+}
+
+/// A Job from the CronJob's template, named `<cronjob>-manual-<suffix>` and owned by
+/// the CronJob, like `kubectl create job --from=cronjob/<name>`
+fn manual_job_from_cronjob(cronjob: &CronJob, suffix: &str) -> Result<Job, String> {
+    let cronjob_name = cronjob.metadata.name.clone().unwrap_or_default();
+    let template = cronjob
+        .spec
+        .as_ref()
+        .map(|spec| spec.job_template.clone())
+        .ok_or_else(|| format!("CronJob '{}' has no spec", cronjob_name))?;
+    let template_meta = template.metadata.unwrap_or_default();
+
+    // Job names end up in the `job-name` pod label, which is capped at 63 characters
+    let suffix = format!("-manual-{}", suffix);
+    let prefix: String = cronjob_name
+        .chars()
+        .take(63 - suffix.len())
+        .collect::<String>()
+        .trim_end_matches(['-', '.'])
+        .to_string();
+
+    let mut annotations = template_meta.annotations.unwrap_or_default();
+    annotations.insert(
+        "cronjob.kubernetes.io/instantiate".to_string(),
+        "manual".to_string(),
+    );
+
+    Ok(Job {
+        metadata: ObjectMeta {
+            name: Some(format!("{}{}", prefix, suffix)),
+            namespace: cronjob.metadata.namespace.clone(),
+            labels: template_meta.labels,
+            annotations: Some(annotations),
+            owner_references: cronjob.controller_owner_ref(&()).map(|r| vec![r]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        ..Default::default()
+    })
+}
+
+/// Run a CronJob now by creating a one-off Job from its template. Returns the Job's name.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_trigger_cronjob(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    state: State<'_, ClusterManagerState>,
+) -> Result<String, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let cronjobs: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    let cronjob = cronjobs
+        .get(&name)
+        .await
+        .map_err(|e| format!("Failed to get cronjob '{}': {}", name, e))?;
+
+    let suffix = uuid::Uuid::new_v4().simple().to_string()[..5].to_string();
+    let job = manual_job_from_cronjob(&cronjob, &suffix)?;
+
+    let jobs: Api<Job> = Api::namespaced(client, &namespace);
+    let created = jobs
+        .create(&PostParams::default(), &job)
+        .await
+        .map_err(|e| format!("Failed to create job from cronjob '{}': {}", name, e))?;
+
+    Ok(created.metadata.name.unwrap_or_default())
+}
+
+/// Merge-patch `spec.suspend`, which adds the field when the object never set it
+fn suspend_patch(suspend: bool) -> serde_json::Value {
+    serde_json::json!({ "spec": { "suspend": suspend } })
+}
+
+/// Suspend or resume a CronJob's schedule. Returns the suspend state now on the object.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_set_cronjob_suspend(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    suspend: bool,
+    state: State<'_, ClusterManagerState>,
+) -> Result<bool, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let api: Api<CronJob> = Api::namespaced(client, &namespace);
+    let cronjob = api
+        .patch(
+            &name,
+            &PatchParams::default(),
+            &Patch::Merge(&suspend_patch(suspend)),
+        )
+        .await
+        .map_err(|e| format!("Failed to update cronjob '{}': {}", name, e))?;
+
+    Ok(cronjob.spec.and_then(|s| s.suspend).unwrap_or(false))
+}
+
+/// Suspend a Job (terminating its running pods) or resume it. Returns the suspend
+/// state now on the object.
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_set_job_suspend(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    suspend: bool,
+    state: State<'_, ClusterManagerState>,
+) -> Result<bool, String> {
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let api: Api<Job> = Api::namespaced(client, &namespace);
+    let job = api
+        .patch(
+            &name,
+            &PatchParams::default(),
+            &Patch::Merge(&suspend_patch(suspend)),
+        )
+        .await
+        .map_err(|e| format!("Failed to update job '{}': {}", name, e))?;
+
+    Ok(job.spec.and_then(|s| s.suspend).unwrap_or(false))
+}
+
 // Config Maps
-fn map_configmap_to_summary(c: ConfigMap) -> WorkloadSummary {
+pub(crate) fn map_configmap_to_summary(c: ConfigMap) -> WorkloadSummary {
     let meta = c.metadata;
     let count = c.data.map(|d| d.len()).unwrap_or(0) + c.binary_data.map(|d| d.len()).unwrap_or(0);
 
@@ -300,7 +613,7 @@ fn map_configmap_to_summary(c: ConfigMap) -> WorkloadSummary {
 }
 
 // Secrets
-fn map_secret_to_summary(s: Secret) -> WorkloadSummary {
+pub(crate) fn map_secret_to_summary(s: Secret) -> WorkloadSummary {
     let meta = s.metadata;
     let count = s.data.map(|d| d.len()).unwrap_or(0) + s.string_data.map(|d| d.len()).unwrap_or(0);
 
@@ -352,7 +665,31 @@ fn map_limit_range_to_summary(l: LimitRange) -> WorkloadSummary {
 }
 
 // HPA
-fn map_hpa_to_summary(h: HorizontalPodAutoscaler) -> WorkloadSummary {
+fn map_hpa_to_summary(h: v2::HorizontalPodAutoscaler) -> WorkloadSummary {
+    let meta = h.metadata;
+    let spec = h.spec.unwrap_or_default();
+    let status = h.status.unwrap_or_default();
+
+    let current = status.current_replicas.unwrap_or(0);
+    let desired = status.desired_replicas;
+    let min = spec.min_replicas.unwrap_or(1);
+    let max = spec.max_replicas;
+
+    let status_str = format!("{}/{} (min: {}, max: {})", current, desired, min, max);
+
+    WorkloadSummary {
+        id: meta.uid.clone().unwrap_or_default(),
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        age: calculate_age(meta.creation_timestamp.as_ref()),
+        created_at: get_created_at(meta.creation_timestamp.as_ref()),
+        labels: meta.labels.unwrap_or_default(),
+        status: status_str,
+        images: vec![],
+    }
+}
+
+fn map_hpa_v1_to_summary(h: v1::HorizontalPodAutoscaler) -> WorkloadSummary {
     let meta = h.metadata;
     let spec = h.spec.unwrap_or_default();
     let status = h.status.unwrap_or_default();
@@ -376,6 +713,99 @@ fn map_hpa_to_summary(h: HorizontalPodAutoscaler) -> WorkloadSummary {
     }
 }
 
+/// List a page of HPAs through autoscaling/v2, falling back to v1 on clusters that
+/// don't serve v2. Returns the summaries and the next page's continue token.
+async fn list_hpa_summaries(
+    client: Client,
+    namespace: Option<&str>,
+    lp: &ListParams,
+) -> Result<(Vec<WorkloadSummary>, Option<String>), kube::Error> {
+    let api: Api<v2::HorizontalPodAutoscaler> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    match api.list(lp).await {
+        Ok(list) => Ok(summarize_page(list, map_hpa_to_summary)),
+        Err(e) if is_not_found(&e) => {
+            let api: Api<v1::HorizontalPodAutoscaler> = match namespace {
+                Some(ns) => Api::namespaced(client, ns),
+                None => Api::all(client),
+            };
+            let list = api.list(lp).await?;
+            Ok(summarize_page(list, map_hpa_v1_to_summary))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// List HPAs in `namespace` (or all namespaces), a page of `limit` at a time when given
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_list_hpa(
+    cluster_id: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<WorkloadList, String> {
+    let lp = page_params(
+        label_selector_params(label_selector)?,
+        limit,
+        continue_token,
+    );
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    let result = list_hpa_summaries(client, namespace.as_deref(), &lp).await;
+    into_workload_list(result, namespace.is_none())
+}
+
+/// Deprecated alias of `cluster_list_hpa`, which takes `label_selector` itself; kept
+/// so existing callers of the `_filtered` name keep working
+#[tauri::command]
+pub async fn cluster_list_hpa_filtered(
+    cluster_id: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<WorkloadList, String> {
+    cluster_list_hpa(
+        cluster_id,
+        namespace,
+        label_selector,
+        limit,
+        continue_token,
+        state,
+    )
+    .await
+}
+
+/// Delete an HPA; `propagation` decides whether its dependents are removed too
+#[tauri::command]
+#[tracing::instrument(skip(state))]
+pub async fn cluster_delete_hpa(
+    cluster_id: String,
+    namespace: String,
+    name: String,
+    grace_period_seconds: Option<u32>,
+    propagation: Option<String>,
+    state: State<'_, ClusterManagerState>,
+) -> Result<(), String> {
+    let params = DeleteParams {
+        propagation_policy: Some(parse_propagation_policy(propagation.as_deref())?),
+        ..load_settings(&state).delete_params(grace_period_seconds)
+    };
+    let client = create_client_for_cluster(&cluster_id, &state).await?;
+    // Deleting through v1 works regardless of which version the HPA was authored in
+    let api: Api<v1::HorizontalPodAutoscaler> = Api::namespaced(client, &namespace);
+    api.delete(&name, &params)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 // PDB
 fn map_pdb_to_summary(p: PodDisruptionBudget) -> WorkloadSummary {
     let meta = p.metadata;
@@ -395,7 +825,7 @@ fn map_pdb_to_summary(p: PodDisruptionBudget) -> WorkloadSummary {
 }
 
 // Services
-fn map_service_to_summary(s: Service) -> WorkloadSummary {
+pub(crate) fn map_service_to_summary(s: Service) -> WorkloadSummary {
     let meta = s.metadata;
     let spec = s.spec.unwrap_or_default();
 
@@ -445,8 +875,48 @@ fn map_endpoints_to_summary(e: Endpoints) -> WorkloadSummary {
     }
 }
 
+// Endpoint Slices
+fn map_endpoint_slice_to_summary(e: EndpointSlice) -> WorkloadSummary {
+    let meta = e.metadata;
+    // An endpoint with an unknown ready condition is treated as ready, per the API docs
+    let ready = e
+        .endpoints
+        .iter()
+        .filter(|ep| ep.conditions.as_ref().and_then(|c| c.ready).unwrap_or(true))
+        .map(|ep| ep.addresses.len())
+        .sum::<usize>();
+    let terminating = e
+        .endpoints
+        .iter()
+        .filter(|ep| {
+            ep.conditions
+                .as_ref()
+                .and_then(|c| c.terminating)
+                .unwrap_or(false)
+        })
+        .map(|ep| ep.addresses.len())
+        .sum::<usize>();
+    let service = meta
+        .labels
+        .as_ref()
+        .and_then(|l| l.get("kubernetes.io/service-name"))
+        .cloned()
+        .unwrap_or_default();
+
+    WorkloadSummary {
+        id: meta.uid.clone().unwrap_or_default(),
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        age: calculate_age(meta.creation_timestamp.as_ref()),
+        created_at: get_created_at(meta.creation_timestamp.as_ref()),
+        labels: meta.labels.unwrap_or_default(),
+        status: format!("{} ready, {} terminating", ready, terminating),
+        images: vec![service], // Hijacking images field for the owning service
+    }
+}
+
 // Ingresses
-fn map_ingress_to_summary(i: Ingress) -> WorkloadSummary {
+pub(crate) fn map_ingress_to_summary(i: Ingress) -> WorkloadSummary {
     let meta = i.metadata;
     let lbs = i
         .status
@@ -488,7 +958,7 @@ fn map_network_policy_to_summary(n: NetworkPolicy) -> WorkloadSummary {
 }
 
 // PVC
-fn map_pvc_to_summary(p: PersistentVolumeClaim) -> WorkloadSummary {
+pub(crate) fn map_pvc_to_summary(p: PersistentVolumeClaim) -> WorkloadSummary {
     let meta = p.metadata;
     let status = p.status.unwrap_or_default();
     let phase = status.phase.unwrap_or_default();
@@ -598,36 +1068,42 @@ fn map_cluster_role_to_summary(r: ClusterRole) -> WorkloadSummary {
 impl_workload_commands!(
     Deployment,
     cluster_list_deployments,
+    cluster_list_deployments_filtered,
     cluster_delete_deployment,
     map_deployment_to_summary
 );
 impl_workload_commands!(
     StatefulSet,
     cluster_list_statefulsets,
+    cluster_list_statefulsets_filtered,
     cluster_delete_statefulset,
     map_statefulset_to_summary
 );
 impl_workload_commands!(
     DaemonSet,
     cluster_list_daemonsets,
+    cluster_list_daemonsets_filtered,
     cluster_delete_daemonset,
     map_daemonset_to_summary
 );
 impl_workload_commands!(
     ReplicaSet,
     cluster_list_replicasets,
+    cluster_list_replicasets_filtered,
     cluster_delete_replicaset,
     map_replicaset_to_summary
 );
 impl_workload_commands!(
     Job,
     cluster_list_jobs,
+    cluster_list_jobs_filtered,
     cluster_delete_job,
     map_job_to_summary
 );
 impl_workload_commands!(
     CronJob,
     cluster_list_cronjobs,
+    cluster_list_cronjobs_filtered,
     cluster_delete_cronjob,
     map_cronjob_to_summary
 );
@@ -635,78 +1111,91 @@ impl_workload_commands!(
 impl_workload_commands!(
     ConfigMap,
     cluster_list_config_maps,
+    cluster_list_config_maps_filtered,
     cluster_delete_config_map,
     map_configmap_to_summary
 );
 impl_workload_commands!(
     Secret,
     cluster_list_secrets,
+    cluster_list_secrets_filtered,
     cluster_delete_secret,
     map_secret_to_summary
 );
 impl_workload_commands!(
     ResourceQuota,
     cluster_list_resource_quotas,
+    cluster_list_resource_quotas_filtered,
     cluster_delete_resource_quota,
     map_resource_quota_to_summary
 );
 impl_workload_commands!(
     LimitRange,
     cluster_list_limit_ranges,
+    cluster_list_limit_ranges_filtered,
     cluster_delete_limit_range,
     map_limit_range_to_summary
 );
-impl_workload_commands!(
-    HorizontalPodAutoscaler,
-    cluster_list_hpa,
-    cluster_delete_hpa,
-    map_hpa_to_summary
-);
 impl_workload_commands!(
     PodDisruptionBudget,
     cluster_list_pdb,
+    cluster_list_pdb_filtered,
     cluster_delete_pdb,
     map_pdb_to_summary
 );
 impl_workload_commands!(
     Service,
     cluster_list_services,
+    cluster_list_services_filtered,
     cluster_delete_service,
     map_service_to_summary
 );
 impl_workload_commands!(
     Endpoints,
     cluster_list_endpoints,
+    cluster_list_endpoints_filtered,
     cluster_delete_endpoint,
     map_endpoints_to_summary
 );
+impl_workload_commands!(
+    EndpointSlice,
+    cluster_list_endpoint_slices,
+    cluster_list_endpoint_slices_filtered,
+    cluster_delete_endpoint_slice,
+    map_endpoint_slice_to_summary
+);
 impl_workload_commands!(
     Ingress,
     cluster_list_ingresses,
+    cluster_list_ingresses_filtered,
     cluster_delete_ingress,
     map_ingress_to_summary
 );
 impl_workload_commands!(
     NetworkPolicy,
     cluster_list_network_policies,
+    cluster_list_network_policies_filtered,
     cluster_delete_network_policy,
     map_network_policy_to_summary
 );
 impl_workload_commands!(
     PersistentVolumeClaim,
     cluster_list_pvc,
+    cluster_list_pvc_filtered,
     cluster_delete_pvc,
     map_pvc_to_summary
 );
 impl_workload_commands!(
     ServiceAccount,
     cluster_list_service_accounts,
+    cluster_list_service_accounts_filtered,
     cluster_delete_service_account,
     map_service_account_to_summary
 );
 impl_workload_commands!(
     Role,
     cluster_list_roles,
+    cluster_list_roles_filtered,
     cluster_delete_role,
     map_role_to_summary
 );
@@ -715,18 +1204,417 @@ impl_workload_commands!(
 impl_cluster_resource_commands!(
     PersistentVolume,
     cluster_list_pv,
+    cluster_list_pv_filtered,
     cluster_delete_pv,
     map_pv_to_summary
 );
 impl_cluster_resource_commands!(
     StorageClass,
     cluster_list_storage_classes,
+    cluster_list_storage_classes_filtered,
     cluster_delete_storage_class,
     map_storage_class_to_summary
 );
 impl_cluster_resource_commands!(
     ClusterRole,
     cluster_list_cluster_roles,
+    cluster_list_cluster_roles_filtered,
     cluster_delete_cluster_role,
     map_cluster_role_to_summary
 );
+
+/// Fetch one page of a list and map it to summaries
+async fn list_page<K>(
+    api: Api<K>,
+    lp: ListParams,
+    limit: Option<u32>,
+    continue_token: Option<String>,
+    map_fn: fn(K) -> WorkloadSummary,
+    cluster_wide: bool,
+) -> Result<WorkloadList, String>
+where
+    K: Clone + serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    let result = api
+        .list(&page_params(lp, limit, continue_token))
+        .await
+        .map(|list| summarize_page(list, map_fn));
+    into_workload_list(result, cluster_wide)
+}
+
+/// Map a listed page to summaries, with the continue token for the next page
+fn summarize_page<K: Clone>(
+    list: ObjectList<K>,
+    map_fn: fn(K) -> WorkloadSummary,
+) -> (Vec<WorkloadSummary>, Option<String>) {
+    let next = next_continue_token(&list.metadata);
+    (list.items.into_iter().map(map_fn).collect(), next)
+}
+
+async fn list_summaries<K>(
+    client: Client,
+    namespace: Option<&str>,
+    map_fn: fn(K) -> WorkloadSummary,
+) -> Result<Vec<WorkloadSummary>, String>
+where
+    K: Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    <K as Resource>::DynamicType: Default,
+{
+    let api: Api<K> = match namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
+
+    let list = api
+        .list(&Default::default())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(list.items.into_iter().map(map_fn).collect())
+}
+
+/// List a namespaced kind by name (e.g. "Deployment"), for callers that pick the kind at runtime
+pub(crate) async fn list_workload_summaries(
+    client: Client,
+    kind: &str,
+    namespace: Option<&str>,
+) -> Result<Vec<WorkloadSummary>, String> {
+    match ResourceKind::parse(kind) {
+        Some(ResourceKind::Deployment) => {
+            list_summaries(client, namespace, map_deployment_to_summary).await
+        }
+        Some(ResourceKind::StatefulSet) => {
+            list_summaries(client, namespace, map_statefulset_to_summary).await
+        }
+        Some(ResourceKind::DaemonSet) => {
+            list_summaries(client, namespace, map_daemonset_to_summary).await
+        }
+        Some(ResourceKind::ReplicaSet) => {
+            list_summaries(client, namespace, map_replicaset_to_summary).await
+        }
+        Some(ResourceKind::Job) => list_summaries(client, namespace, map_job_to_summary).await,
+        Some(ResourceKind::CronJob) => {
+            list_summaries(client, namespace, map_cronjob_to_summary).await
+        }
+        Some(ResourceKind::ConfigMap) => {
+            list_summaries(client, namespace, map_configmap_to_summary).await
+        }
+        Some(ResourceKind::Secret) => {
+            list_summaries(client, namespace, map_secret_to_summary).await
+        }
+        Some(ResourceKind::ResourceQuota) => {
+            list_summaries(client, namespace, map_resource_quota_to_summary).await
+        }
+        Some(ResourceKind::LimitRange) => {
+            list_summaries(client, namespace, map_limit_range_to_summary).await
+        }
+        Some(ResourceKind::HorizontalPodAutoscaler) => {
+            list_hpa_summaries(client, namespace, &Default::default())
+                .await
+                .map(|(items, _)| items)
+                .map_err(|e| e.to_string())
+        }
+        Some(ResourceKind::PodDisruptionBudget) => {
+            list_summaries(client, namespace, map_pdb_to_summary).await
+        }
+        Some(ResourceKind::Service) => {
+            list_summaries(client, namespace, map_service_to_summary).await
+        }
+        Some(ResourceKind::Ingress) => {
+            list_summaries(client, namespace, map_ingress_to_summary).await
+        }
+        Some(ResourceKind::NetworkPolicy) => {
+            list_summaries(client, namespace, map_network_policy_to_summary).await
+        }
+        Some(ResourceKind::PersistentVolumeClaim) => {
+            list_summaries(client, namespace, map_pvc_to_summary).await
+        }
+        Some(ResourceKind::ServiceAccount) => {
+            list_summaries(client, namespace, map_service_account_to_summary).await
+        }
+        Some(ResourceKind::Role) => list_summaries(client, namespace, map_role_to_summary).await,
+        _ => Err(format!("Unsupported kind for comparison: {}", kind)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: u16, reason: &str) -> kube::Error {
+        kube::Error::Api(
+            serde_json::from_value(serde_json::json!({
+                "status": "Failure",
+                "message": format!("{} error", reason),
+                "reason": reason,
+                "code": code
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn summary(name: &str) -> WorkloadSummary {
+        WorkloadSummary {
+            id: name.to_string(),
+            name: name.to_string(),
+            namespace: "default".to_string(),
+            age: "1d".to_string(),
+            labels: Default::default(),
+            status: "1/1".to_string(),
+            images: vec![],
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_into_workload_list_ok() {
+        let list = into_workload_list(Ok((vec![summary("web")], Some("next".to_string()))), false)
+            .unwrap();
+        assert_eq!(list.items.len(), 1);
+        assert!(!list.partial);
+        assert_eq!(list.continue_token.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_into_workload_list_forbidden_cluster_wide_is_partial() {
+        let list = into_workload_list(Err(api_error(403, "Forbidden")), true).unwrap();
+        assert!(list.items.is_empty());
+        assert!(list.partial);
+        assert!(list.partial_reason.is_some());
+        assert_eq!(list.continue_token, None);
+    }
+
+    #[test]
+    fn test_into_workload_list_other_errors_fail() {
+        assert!(into_workload_list(Err(api_error(403, "Forbidden")), false).is_err());
+        assert!(into_workload_list(Err(api_error(500, "InternalError")), true).is_err());
+    }
+
+    /// Stand-in for the API server serving `names` as a list of `kind`: the continue
+    /// token is the offset of the next page
+    fn serve_page<K: Clone + serde::de::DeserializeOwned>(
+        api_version: &str,
+        kind: &str,
+        names: &[&str],
+        lp: &ListParams,
+    ) -> ObjectList<K> {
+        let start: usize = lp
+            .continue_token
+            .as_deref()
+            .map_or(0, |t| t.parse().unwrap());
+        let end = lp.limit.map_or(names.len(), |limit| {
+            (start + limit as usize).min(names.len())
+        });
+        let items: Vec<serde_json::Value> = names[start..end]
+            .iter()
+            .map(|name| {
+                serde_json::json!({
+                    "apiVersion": api_version,
+                    "kind": kind,
+                    "metadata": { "name": name, "namespace": "default" },
+                })
+            })
+            .collect();
+        let continue_token = if end < names.len() {
+            end.to_string()
+        } else {
+            String::new()
+        };
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": api_version,
+            "kind": format!("{}List", kind),
+            "metadata": { "continue": continue_token, "resourceVersion": "1" },
+            "items": items,
+        }))
+        .unwrap()
+    }
+
+    fn page_names(items: &[WorkloadSummary]) -> Vec<String> {
+        items.iter().map(|s| s.name.clone()).collect()
+    }
+
+    #[test]
+    fn test_deployment_pages_follow_continue_token() {
+        let names = ["a", "b", "c", "d", "e"];
+        let mut seen = Vec::new();
+        let mut token = None;
+        let mut pages = 0;
+        loop {
+            let lp = page_params(ListParams::default(), Some(2), token);
+            let list: ObjectList<Deployment> = serve_page("apps/v1", "Deployment", &names, &lp);
+            let (items, next) = summarize_page(list, map_deployment_to_summary);
+            assert!(items.len() <= 2);
+            seen.extend(page_names(&items));
+            pages += 1;
+            token = next;
+            if token.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(pages, 3);
+        assert_eq!(seen, names);
+    }
+
+    #[test]
+    fn test_hpa_page_honors_limit() {
+        let names = ["web", "api", "worker"];
+        let lp = page_params(ListParams::default(), Some(2), None);
+
+        let v2: ObjectList<v2::HorizontalPodAutoscaler> =
+            serve_page("autoscaling/v2", "HorizontalPodAutoscaler", &names, &lp);
+        let (items, next) = summarize_page(v2, map_hpa_to_summary);
+        assert_eq!(page_names(&items), vec!["web", "api"]);
+        assert_eq!(next.as_deref(), Some("2"));
+
+        // The v1 fallback pages the same way
+        let lp = page_params(ListParams::default(), Some(2), next);
+        let v1: ObjectList<v1::HorizontalPodAutoscaler> =
+            serve_page("autoscaling/v1", "HorizontalPodAutoscaler", &names, &lp);
+        let (items, next) = summarize_page(v1, map_hpa_v1_to_summary);
+        assert_eq!(page_names(&items), vec!["worker"]);
+        assert_eq!(next, None);
+    }
+
+    fn cronjob(yaml: &str) -> CronJob {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn utc(time: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+    }
+
+    const NIGHTLY: &str = r#"
+metadata:
+  name: nightly-report
+  namespace: batch
+  uid: 1234-abcd
+spec:
+  schedule: "0 2 * * *"
+  jobTemplate:
+    metadata:
+      labels:
+        app: report
+    spec:
+      template:
+        spec:
+          restartPolicy: Never
+          containers:
+          - name: report
+            image: report:1.0
+status:
+  lastScheduleTime: "2024-05-01T02:00:00Z"
+  active:
+  - name: nightly-report-28577520
+"#;
+
+    #[test]
+    fn test_manual_job_from_cronjob() {
+        let job = manual_job_from_cronjob(&cronjob(NIGHTLY), "ab12c").unwrap();
+
+        assert_eq!(
+            job.metadata.name.as_deref(),
+            Some("nightly-report-manual-ab12c")
+        );
+        assert_eq!(job.metadata.namespace.as_deref(), Some("batch"));
+        assert_eq!(
+            job.metadata.labels.unwrap().get("app").map(String::as_str),
+            Some("report")
+        );
+        assert_eq!(
+            job.metadata
+                .annotations
+                .unwrap()
+                .get("cronjob.kubernetes.io/instantiate")
+                .map(String::as_str),
+            Some("manual")
+        );
+
+        let owners = job.metadata.owner_references.unwrap();
+        assert_eq!(owners.len(), 1);
+        assert_eq!(owners[0].kind, "CronJob");
+        assert_eq!(owners[0].name, "nightly-report");
+        assert_eq!(owners[0].uid, "1234-abcd");
+        assert_eq!(owners[0].controller, Some(true));
+
+        let containers = job.spec.unwrap().template.spec.unwrap().containers;
+        assert_eq!(containers[0].image.as_deref(), Some("report:1.0"));
+    }
+
+    #[test]
+    fn test_manual_job_name_fits_label_limit() {
+        let mut long = cronjob(NIGHTLY);
+        // Truncation at the limit would leave a trailing dash
+        long.metadata.name = Some(format!("{}-{}", "a".repeat(49), "b".repeat(20)));
+
+        let name = manual_job_from_cronjob(&long, "ab12c")
+            .unwrap()
+            .metadata
+            .name
+            .unwrap();
+        assert!(name.len() <= 63, "{} is {} long", name, name.len());
+        assert_eq!(name, format!("{}-manual-ab12c", "a".repeat(49)));
+    }
+
+    #[test]
+    fn test_manual_job_requires_spec() {
+        assert!(manual_job_from_cronjob(&cronjob("metadata:\n  name: empty\n"), "x").is_err());
+    }
+
+    #[test]
+    fn test_estimate_next_run() {
+        let last = utc("2024-05-01T02:00:00Z");
+
+        assert_eq!(
+            estimate_next_run("0 2 * * *", Some(last), utc("2024-05-01T12:00:00Z")),
+            Some(utc("2024-05-02T02:00:00Z"))
+        );
+        // Overdue: the run after `last` has passed, so estimate from now
+        assert_eq!(
+            estimate_next_run("0 2 * * *", Some(last), utc("2024-05-03T12:00:00Z")),
+            Some(utc("2024-05-04T02:00:00Z"))
+        );
+        // Never run yet
+        assert_eq!(
+            estimate_next_run("*/15 * * * *", None, utc("2024-05-01T10:07:00Z")),
+            Some(utc("2024-05-01T10:15:00Z"))
+        );
+        assert_eq!(
+            estimate_next_run("not a schedule", Some(last), utc("2024-05-01T12:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_map_cronjob_details() {
+        let now = utc("2024-05-01T12:00:00Z");
+        let details = map_cronjob_details(cronjob(NIGHTLY), now);
+
+        assert_eq!(details.schedule, "0 2 * * *");
+        assert!(!details.suspend);
+        assert_eq!(details.concurrency_policy, "Allow");
+        assert_eq!(details.active_jobs, vec!["nightly-report-28577520"]);
+        assert_eq!(
+            details.last_schedule_time,
+            Some(utc("2024-05-01T02:00:00Z").to_rfc3339())
+        );
+        assert_eq!(
+            details.next_estimated_run,
+            Some(utc("2024-05-02T02:00:00Z").to_rfc3339())
+        );
+
+        let mut suspended = cronjob(NIGHTLY);
+        suspended.spec.as_mut().unwrap().suspend = Some(true);
+        assert_eq!(map_cronjob_details(suspended, now).next_estimated_run, None);
+
+        let mut zoned = cronjob(NIGHTLY);
+        zoned.spec.as_mut().unwrap().time_zone = Some("Europe/Berlin".to_string());
+        let details = map_cronjob_details(zoned, now);
+        assert_eq!(details.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(details.next_estimated_run, None);
+    }
+}
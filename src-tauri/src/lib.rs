@@ -1,10 +1,13 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 mod cluster_manager;
 mod config;
+mod error;
 mod image_utils;
 mod import;
 mod input_validation;
 mod k8s;
+mod settings;
+mod telemetry;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -14,15 +17,20 @@ fn greet(name: &str) -> String {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Init directories
-    let _ = config::init_directories();
+    if let Err(e) = config::init_directories() {
+        eprintln!("Warning: Failed to initialize app directories: {}", e);
+    }
+    telemetry::init_tracing();
 
     // Initialize cluster manager
     let db_path = config::get_app_config_dir().join("clusters.db");
     let cluster_manager = cluster_manager::ClusterManager::new(db_path)
         .expect("Failed to initialize cluster manager");
-    let cluster_manager_state = cluster_manager::ClusterManagerState(std::sync::Arc::new(
-        std::sync::Mutex::new(cluster_manager),
-    ));
+    let cluster_manager_state = cluster_manager::ClusterManagerState(
+        std::sync::Arc::new(std::sync::Mutex::new(cluster_manager)),
+        std::sync::Arc::new(k8s::client::ClientCache::default()),
+        std::sync::Arc::new(k8s::RequestLimiterState::default()),
+    );
 
     tauri::Builder::default()
         .plugin(tauri_plugin_websocket::init())
@@ -33,6 +41,8 @@ pub fn run() {
         .plugin(tauri_plugin_opener::init())
         .manage(cluster_manager_state)
         .manage(k8s::WatcherState::default())
+        .manage(k8s::MetricsHistoryState::default())
+        .manage(k8s::ExecSessionsState::default())
         .invoke_handler(tauri::generate_handler![
             greet,
             // Legacy k8s commands (deprecated, kept for backwards compatibility)
@@ -44,82 +54,209 @@ pub fn run() {
             k8s::stream_container_logs,
             k8s::start_pod_watch,
             // NEW: Cluster-based k8s commands
+            k8s::list_context_clusters,
+            k8s::cluster_check_health,
             k8s::cluster_list_namespaces,
+            k8s::cluster_list_nodes,
+            k8s::cluster_cordon_node,
+            k8s::cluster_uncordon_node,
+            k8s::cluster_drain_node,
+            k8s::cluster_list_namespace_details,
+            k8s::cluster_force_finalize_namespace,
+            k8s::cluster_create_namespace,
+            k8s::cluster_delete_namespace,
             k8s::cluster_list_pods,
+            k8s::cluster_list_pods_sorted,
             k8s::cluster_delete_pod,
+            k8s::cluster_delete_pods_by_selector,
             k8s::cluster_get_pod_events,
+            k8s::cluster_get_pod_init_status,
+            k8s::cluster_get_pod_raw,
+            k8s::cluster_export_pod_bundle,
+            k8s::cluster_explain_pod_scheduling,
+            k8s::cluster_get_pod_network_policies,
+            k8s::cluster_find_config_consumers,
             k8s::cluster_stream_container_logs,
+            k8s::cluster_download_container_logs,
+            k8s::cluster_stream_logs_with_previous,
+            k8s::cluster_exec_pod,
+            k8s::cluster_exec_write,
+            k8s::cluster_exec_resize,
+            k8s::cluster_port_forward_pod,
+            k8s::cluster_stop_port_forward,
+            k8s::cluster_get_latest_failure_logs,
             k8s::cluster_start_pod_watch,
+            k8s::cluster_watch_pod,
+            k8s::cluster_start_workload_watch,
+            k8s::cluster_stop_watch,
+            k8s::cluster_list_active_watches,
+            k8s::cluster_watch_pvc,
+            k8s::cluster_watch_job,
+            k8s::reconnect_watches,
             k8s::cluster_get_metrics,
+            k8s::cluster_get_dashboard,
+            k8s::cluster_start_metrics_poll,
+            k8s::cluster_get_metrics_history,
+            k8s::cluster_get_node_usage,
+            k8s::cluster_get_pod_usage,
+            k8s::cluster_top_pods,
+            k8s::cluster_get_pod_stats,
             k8s::cluster_get_events,
+            k8s::cluster_get_events_grouped,
+            k8s::cluster_start_event_watch,
             // Workload commands
             k8s::cluster_list_deployments,
+            k8s::cluster_list_deployments_filtered,
             k8s::cluster_delete_deployment,
             k8s::cluster_list_statefulsets,
+            k8s::cluster_list_statefulsets_filtered,
             k8s::cluster_delete_statefulset,
             k8s::cluster_list_daemonsets,
+            k8s::cluster_list_daemonsets_filtered,
             k8s::cluster_delete_daemonset,
             k8s::cluster_list_replicasets,
+            k8s::cluster_list_replicasets_filtered,
             k8s::cluster_delete_replicaset,
             k8s::cluster_list_jobs,
+            k8s::cluster_list_jobs_filtered,
             k8s::cluster_delete_job,
             k8s::cluster_list_cronjobs,
+            k8s::cluster_list_cronjobs_filtered,
             k8s::cluster_delete_cronjob,
             // Config & Network & Storage
             k8s::cluster_list_config_maps,
+            k8s::cluster_list_config_maps_filtered,
             k8s::cluster_delete_config_map,
             k8s::cluster_list_secrets,
+            k8s::cluster_list_secrets_filtered,
             k8s::cluster_delete_secret,
             k8s::cluster_list_resource_quotas,
+            k8s::cluster_list_resource_quotas_filtered,
             k8s::cluster_delete_resource_quota,
             k8s::cluster_list_limit_ranges,
+            k8s::cluster_list_limit_ranges_filtered,
             k8s::cluster_delete_limit_range,
             k8s::cluster_list_hpa,
+            k8s::cluster_list_hpa_filtered,
             k8s::cluster_delete_hpa,
             k8s::cluster_list_pdb,
+            k8s::cluster_list_pdb_filtered,
             k8s::cluster_delete_pdb,
             k8s::cluster_list_services,
+            k8s::cluster_list_services_filtered,
             k8s::cluster_delete_service,
             k8s::cluster_list_endpoints,
+            k8s::cluster_list_endpoints_filtered,
             k8s::cluster_delete_endpoint,
+            k8s::cluster_list_endpoint_slices,
+            k8s::cluster_list_endpoint_slices_filtered,
+            k8s::cluster_delete_endpoint_slice,
             k8s::cluster_list_ingresses,
+            k8s::cluster_list_ingresses_filtered,
             k8s::cluster_delete_ingress,
             k8s::cluster_list_network_policies,
+            k8s::cluster_list_network_policies_filtered,
             k8s::cluster_delete_network_policy,
             k8s::cluster_list_pvc,
+            k8s::cluster_list_pvc_filtered,
             k8s::cluster_delete_pvc,
             k8s::cluster_list_pv,
+            k8s::cluster_list_pv_filtered,
             k8s::cluster_delete_pv,
             k8s::cluster_list_storage_classes,
+            k8s::cluster_list_storage_classes_filtered,
             k8s::cluster_delete_storage_class,
             k8s::cluster_list_service_accounts,
+            k8s::cluster_list_service_accounts_filtered,
             k8s::cluster_delete_service_account,
             k8s::cluster_list_roles,
+            k8s::cluster_list_roles_filtered,
             k8s::cluster_delete_role,
             k8s::cluster_list_cluster_roles,
+            k8s::cluster_list_cluster_roles_filtered,
             k8s::cluster_delete_cluster_role,
             // Deployment details, pods, and events
             k8s::cluster_get_deployment_details,
             k8s::cluster_get_deployment_pods,
             k8s::cluster_get_deployment_replicasets,
+            k8s::cluster_rollback_deployment,
             k8s::cluster_get_deployment_events,
+            k8s::cluster_scale_deployment,
             // StatefulSet details, pods, and events
             k8s::cluster_get_statefulset_details,
             k8s::cluster_get_statefulset_pods,
             k8s::cluster_get_statefulset_events,
+            k8s::cluster_scale_statefulset,
+            // HPA details
+            k8s::cluster_get_hpa_details,
+            // ResourceQuota details
+            k8s::cluster_get_resource_quota_details,
+            // LimitRange details
+            k8s::cluster_get_limit_range_details,
+            // Secret inspection
+            k8s::cluster_inspect_tls_secret,
+            k8s::cluster_get_secret_data,
+            // CronJob details, manual runs and suspension
+            k8s::cluster_get_cronjob_details,
+            k8s::cluster_trigger_cronjob,
+            k8s::cluster_set_cronjob_suspend,
+            k8s::cluster_set_job_suspend,
+            // ConfigMap details
+            k8s::cluster_get_configmap_details,
+            // Service details and backends
+            k8s::cluster_get_service_details,
+            k8s::cluster_get_service_backends,
+            // Admission webhooks
+            k8s::cluster_list_webhook_configs,
+            // Upgrade readiness
+            k8s::cluster_check_deprecated_apis,
+            // Cluster comparison
+            k8s::compare_clusters,
+            // Namespace snapshots
+            k8s::cluster_export_namespace,
+            k8s::cluster_apply_directory,
+            // Raw resource YAML
+            k8s::cluster_get_resource_yaml,
+            k8s::cluster_apply_resource_yaml,
+            // RBAC permission checks
+            k8s::cluster_can_i,
             // Cluster management commands
             cluster_manager::db_list_clusters,
             cluster_manager::db_get_cluster,
             cluster_manager::db_migrate_legacy_configs,
             cluster_manager::db_update_cluster,
+            cluster_manager::db_set_cluster_default_namespace,
             cluster_manager::db_update_last_accessed,
+            cluster_manager::db_refresh_cluster_version,
+            cluster_manager::db_set_cluster_pinned,
+            cluster_manager::db_set_cluster_accept_invalid_certs,
+            cluster_manager::db_list_groups,
             cluster_manager::db_delete_cluster,
+            cluster_manager::db_validate_clusters,
+            cluster_manager::db_find_duplicate_clusters,
+            cluster_manager::db_get_cluster_context_info,
+            cluster_manager::db_export_cluster_kubeconfig,
+            cluster_manager::get_cluster_icon,
+            cluster_manager::db_find_orphaned_configs,
+            cluster_manager::db_delete_orphaned_configs,
+            cluster_manager::db_normalize_cluster_config,
+            cluster_manager::db_backup,
+            cluster_manager::db_restore,
             // Import commands
             import::import_discover_file,
             import::import_discover_folder,
             import::import_add_cluster,
+            import::import_add_clusters_bulk,
+            // Settings
+            settings::get_settings,
+            settings::set_settings,
+            // Config directory permissions
+            config::config_audit_permissions,
+            config::config_repair_permissions,
             // Image processing
             image_utils::process_icon_file,
+            // Diagnostics
+            telemetry::get_command_timings,
             // Legacy config
             config::import_kubeconfig
         ])
@@ -0,0 +1,297 @@
+use crate::cluster_manager::ClusterManagerState;
+use kube::api::{DeleteParams, PropagationPolicy};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::State;
+
+const DEFAULT_LOG_TAIL_LINES: i64 = 1000;
+const DEFAULT_LOG_MAX_BUFFER: i64 = 10_000;
+const MAX_LOG_TAIL_LINES: i64 = 100_000;
+const MAX_LOG_BUFFER: i64 = 1_000_000;
+const DEFAULT_MAX_CONCURRENT_REQUESTS: i64 = 8;
+const MAX_CONCURRENT_REQUESTS: i64 = 64;
+
+/// User-adjustable application settings, stored as key/value rows in the `settings` table
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppSettings {
+    /// Lines of history fetched when a log stream starts
+    pub log_tail_lines: i64,
+    /// Lines the log viewer keeps before dropping the oldest
+    pub log_max_buffer: i64,
+    /// Grace period for deletes that don't specify one; `None` keeps the object's own
+    pub default_delete_grace_period: Option<u32>,
+    /// Whether the UI asks before deleting resources
+    pub require_delete_confirmation: bool,
+    /// Keep the API server's RFC3339 timestamp at the start of each log line
+    pub default_log_timestamps: bool,
+    /// API requests allowed in flight per cluster at once
+    pub max_concurrent_requests: i64,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            log_tail_lines: DEFAULT_LOG_TAIL_LINES,
+            log_max_buffer: DEFAULT_LOG_MAX_BUFFER,
+            default_delete_grace_period: None,
+            require_delete_confirmation: true,
+            default_log_timestamps: false,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        }
+    }
+}
+
+impl AppSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=MAX_LOG_TAIL_LINES).contains(&self.log_tail_lines) {
+            return Err(format!(
+                "log_tail_lines must be between 1 and {}",
+                MAX_LOG_TAIL_LINES
+            ));
+        }
+        if !(1..=MAX_LOG_BUFFER).contains(&self.log_max_buffer) {
+            return Err(format!(
+                "log_max_buffer must be between 1 and {}",
+                MAX_LOG_BUFFER
+            ));
+        }
+        if self.log_max_buffer < self.log_tail_lines {
+            return Err("log_max_buffer must be at least log_tail_lines".to_string());
+        }
+        if !(1..=MAX_CONCURRENT_REQUESTS).contains(&self.max_concurrent_requests) {
+            return Err(format!(
+                "max_concurrent_requests must be between 1 and {}",
+                MAX_CONCURRENT_REQUESTS
+            ));
+        }
+        Ok(())
+    }
+
+    /// Key/value rows to persist
+    pub fn to_rows(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("log_tail_lines", self.log_tail_lines.to_string()),
+            ("log_max_buffer", self.log_max_buffer.to_string()),
+            (
+                "default_delete_grace_period",
+                self.default_delete_grace_period
+                    .map(|s| s.to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "require_delete_confirmation",
+                self.require_delete_confirmation.to_string(),
+            ),
+            (
+                "default_log_timestamps",
+                self.default_log_timestamps.to_string(),
+            ),
+            (
+                "max_concurrent_requests",
+                self.max_concurrent_requests.to_string(),
+            ),
+        ]
+    }
+
+    /// Build settings from stored rows; missing or unparsable values keep their default
+    pub fn from_rows(rows: &HashMap<String, String>) -> Self {
+        let defaults = Self::default();
+        let get_i64 = |key: &str, default: i64| {
+            rows.get(key)
+                .and_then(|v| v.parse::<i64>().ok())
+                .unwrap_or(default)
+        };
+        let get_bool = |key: &str, default: bool| {
+            rows.get(key)
+                .and_then(|v| v.parse::<bool>().ok())
+                .unwrap_or(default)
+        };
+        // Stored as an empty string when unset
+        let grace_period = match rows.get("default_delete_grace_period") {
+            Some(v) if v.is_empty() => None,
+            Some(v) => v
+                .parse::<u32>()
+                .ok()
+                .or(defaults.default_delete_grace_period),
+            None => defaults.default_delete_grace_period,
+        };
+
+        Self {
+            log_tail_lines: get_i64("log_tail_lines", defaults.log_tail_lines),
+            log_max_buffer: get_i64("log_max_buffer", defaults.log_max_buffer),
+            default_delete_grace_period: grace_period,
+            require_delete_confirmation: get_bool(
+                "require_delete_confirmation",
+                defaults.require_delete_confirmation,
+            ),
+            default_log_timestamps: get_bool(
+                "default_log_timestamps",
+                defaults.default_log_timestamps,
+            ),
+            max_concurrent_requests: get_i64(
+                "max_concurrent_requests",
+                defaults.max_concurrent_requests,
+            ),
+        }
+    }
+
+    /// Delete options for a request, using the configured grace period unless one is given
+    pub fn delete_params(&self, grace_period_seconds: Option<u32>) -> DeleteParams {
+        DeleteParams {
+            grace_period_seconds: grace_period_seconds.or(self.default_delete_grace_period),
+            ..Default::default()
+        }
+    }
+
+    /// Delete options for a pod. `force` removes it immediately like
+    /// `kubectl delete --force --grace-period=0`, without waiting for the kubelet to
+    /// confirm termination, so containers may keep running on an unreachable node.
+    pub fn pod_delete_params(
+        &self,
+        grace_period_seconds: Option<u32>,
+        force: bool,
+    ) -> DeleteParams {
+        if !force {
+            return self.delete_params(grace_period_seconds);
+        }
+        DeleteParams {
+            grace_period_seconds: Some(0),
+            propagation_policy: Some(PropagationPolicy::Background),
+            ..Default::default()
+        }
+    }
+}
+
+/// Current settings, falling back to defaults if the database can't be read
+pub fn load_settings(state: &ClusterManagerState) -> AppSettings {
+    state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))
+        .and_then(|manager| manager.get_settings())
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: using default settings: {}", e);
+            AppSettings::default()
+        })
+}
+
+#[tauri::command]
+pub fn get_settings(state: State<ClusterManagerState>) -> Result<AppSettings, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.get_settings()
+}
+
+#[tauri::command]
+pub fn set_settings(
+    settings: AppSettings,
+    state: State<ClusterManagerState>,
+) -> Result<AppSettings, String> {
+    let manager = state
+        .0
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+    manager.set_settings(&settings)?;
+    // Cached clients carry the previous request limit
+    state.1.clear();
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_rows_falls_back_to_defaults() {
+        let rows = HashMap::from([
+            ("log_tail_lines".to_string(), "250".to_string()),
+            ("log_max_buffer".to_string(), "lots".to_string()),
+        ]);
+
+        let settings = AppSettings::from_rows(&rows);
+
+        assert_eq!(settings.log_tail_lines, 250);
+        assert_eq!(settings.log_max_buffer, DEFAULT_LOG_MAX_BUFFER);
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_values() {
+        let mut settings = AppSettings::default();
+        assert!(settings.validate().is_ok());
+
+        settings.log_tail_lines = 0;
+        assert!(settings.validate().is_err());
+
+        settings.log_tail_lines = 500;
+        settings.log_max_buffer = 100;
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn rows_round_trip_optional_grace_period() {
+        let settings = AppSettings {
+            default_delete_grace_period: Some(5),
+            require_delete_confirmation: false,
+            default_log_timestamps: true,
+            ..Default::default()
+        };
+        let rows: HashMap<String, String> = settings
+            .to_rows()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        assert_eq!(AppSettings::from_rows(&rows), settings);
+
+        let unset = AppSettings::default();
+        let rows: HashMap<String, String> = unset
+            .to_rows()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        assert_eq!(
+            AppSettings::from_rows(&rows).default_delete_grace_period,
+            None
+        );
+    }
+
+    #[test]
+    fn delete_params_prefers_explicit_grace_period() {
+        let settings = AppSettings {
+            default_delete_grace_period: Some(30),
+            ..Default::default()
+        };
+
+        assert_eq!(settings.delete_params(None).grace_period_seconds, Some(30));
+        assert_eq!(
+            settings.delete_params(Some(0)).grace_period_seconds,
+            Some(0)
+        );
+        assert_eq!(
+            AppSettings::default()
+                .delete_params(None)
+                .grace_period_seconds,
+            None
+        );
+    }
+
+    #[test]
+    fn pod_delete_params_force_skips_grace_period() {
+        let settings = AppSettings {
+            default_delete_grace_period: Some(30),
+            ..Default::default()
+        };
+
+        let params = settings.pod_delete_params(Some(10), true);
+        assert_eq!(params.grace_period_seconds, Some(0));
+        assert!(matches!(
+            params.propagation_policy,
+            Some(PropagationPolicy::Background)
+        ));
+
+        let params = settings.pod_delete_params(None, false);
+        assert_eq!(params.grace_period_seconds, Some(30));
+        assert!(params.propagation_policy.is_none());
+    }
+}
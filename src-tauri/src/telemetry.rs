@@ -0,0 +1,214 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// Number of recent samples kept per span name for latency stats
+const MAX_SAMPLES_PER_SPAN: usize = 200;
+const MAX_LOG_FILES: usize = 7;
+
+/// Keeps the non-blocking log writer flushing for the lifetime of the app
+static LOG_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+static TIMINGS: OnceLock<Mutex<CommandTimings>> = OnceLock::new();
+
+/// Latency summary for one instrumented command or helper
+#[derive(Debug, Clone, serde::Serialize, PartialEq)]
+pub struct CommandTiming {
+    pub name: String,
+    /// Total calls since startup
+    pub count: u64,
+    pub last_ms: f64,
+    pub avg_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Default)]
+struct SpanSamples {
+    count: u64,
+    durations_ms: VecDeque<f64>,
+}
+
+#[derive(Debug, Default)]
+struct CommandTimings {
+    spans: HashMap<String, SpanSamples>,
+}
+
+impl CommandTimings {
+    fn record(&mut self, name: &str, duration_ms: f64) {
+        let samples = self.spans.entry(name.to_string()).or_default();
+        samples.count += 1;
+        if samples.durations_ms.len() >= MAX_SAMPLES_PER_SPAN {
+            samples.durations_ms.pop_front();
+        }
+        samples.durations_ms.push_back(duration_ms);
+    }
+
+    /// Stats over the recent samples, slowest (by p95) first
+    fn summarize(&self) -> Vec<CommandTiming> {
+        let mut timings: Vec<CommandTiming> = self
+            .spans
+            .iter()
+            .filter(|(_, s)| !s.durations_ms.is_empty())
+            .map(|(name, samples)| {
+                let mut sorted: Vec<f64> = samples.durations_ms.iter().copied().collect();
+                sorted.sort_by(|a, b| a.total_cmp(b));
+                let p95_index = ((sorted.len() as f64 * 0.95).ceil() as usize).saturating_sub(1);
+
+                CommandTiming {
+                    name: name.clone(),
+                    count: samples.count,
+                    last_ms: *samples.durations_ms.back().unwrap_or(&0.0),
+                    avg_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+                    p95_ms: sorted[p95_index],
+                    max_ms: *sorted.last().unwrap_or(&0.0),
+                }
+            })
+            .collect();
+
+        timings.sort_by(|a, b| b.p95_ms.total_cmp(&a.p95_ms));
+        timings
+    }
+}
+
+fn timings() -> &'static Mutex<CommandTimings> {
+    TIMINGS.get_or_init(|| Mutex::new(CommandTimings::default()))
+}
+
+/// Records how long each of our own spans stays open (wall-clock, including awaits)
+struct TimingLayer;
+
+struct SpanStart(Instant);
+
+impl<S> Layer<S> for TimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        // Only time this crate's spans, not kube/hyper internals
+        if !attrs
+            .metadata()
+            .target()
+            .starts_with(env!("CARGO_CRATE_NAME"))
+        {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanStart(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(start) = span.extensions().get::<SpanStart>().map(|s| s.0) else {
+            return;
+        };
+
+        let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+        if let Ok(mut timings) = timings().lock() {
+            timings.record(span.name(), duration_ms);
+        }
+    }
+}
+
+/// Install the tracing subscriber: span timings in memory, plus a daily-rotated
+/// log file under the app config dir
+pub fn init_tracing() {
+    let log_dir = crate::config::get_app_config_dir().join("logs");
+
+    let file_layer = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("kore")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(&log_dir)
+        .map_err(|e| eprintln!("Failed to open log file in {:?}: {}", log_dir, e))
+        .ok()
+        .map(|appender| {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let _ = LOG_GUARD.set(guard);
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false)
+                .with_span_events(FmtSpan::CLOSE)
+                .with_filter(LevelFilter::INFO)
+        });
+
+    if let Err(e) = tracing_subscriber::registry()
+        .with(TimingLayer)
+        .with(file_layer)
+        .try_init()
+    {
+        eprintln!("Failed to initialize tracing: {}", e);
+    }
+}
+
+/// Recent latency stats for instrumented commands, slowest first
+///
+/// Only request/response commands that call the Kubernetes API are
+/// instrumented. Database and local file commands, and most starters for
+/// long-lived streams, watches, exec sessions and port-forwards, are not
+/// timed.
+#[tauri::command]
+pub fn get_command_timings() -> Result<Vec<CommandTiming>, String> {
+    let timings = timings()
+        .lock()
+        .map_err(|e| format!("Timings lock poisoned: {}", e))?;
+    Ok(timings.summarize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_computes_stats() {
+        let mut timings = CommandTimings::default();
+        for ms in 1..=20 {
+            timings.record("cluster_list_pods", ms as f64);
+        }
+
+        let summary = timings.summarize();
+
+        assert_eq!(summary.len(), 1);
+        assert_eq!(summary[0].count, 20);
+        assert_eq!(summary[0].last_ms, 20.0);
+        assert_eq!(summary[0].avg_ms, 10.5);
+        assert_eq!(summary[0].p95_ms, 19.0);
+        assert_eq!(summary[0].max_ms, 20.0);
+    }
+
+    #[test]
+    fn test_record_keeps_bounded_samples() {
+        let mut timings = CommandTimings::default();
+        for _ in 0..(MAX_SAMPLES_PER_SPAN + 50) {
+            timings.record("create_client_for_cluster", 1.0);
+        }
+
+        let samples = &timings.spans["create_client_for_cluster"];
+        assert_eq!(samples.count, (MAX_SAMPLES_PER_SPAN + 50) as u64);
+        assert_eq!(samples.durations_ms.len(), MAX_SAMPLES_PER_SPAN);
+    }
+
+    #[test]
+    fn test_summarize_sorts_slowest_first() {
+        let mut timings = CommandTimings::default();
+        timings.record("fast", 1.0);
+        timings.record("slow", 500.0);
+
+        let summary = timings.summarize();
+
+        assert_eq!(summary[0].name, "slow");
+    }
+}
@@ -0,0 +1,13 @@
+import { invoke } from "@tauri-apps/api/core";
+import { confirm } from "@tauri-apps/plugin-dialog";
+
+// Ask before deleting, unless confirmations are turned off in the app settings
+export async function confirmDelete(message: string, title: string): Promise<boolean> {
+  try {
+    const settings = await invoke<{ require_delete_confirmation: boolean }>("get_settings");
+    if (!settings.require_delete_confirmation) return true;
+  } catch (e) {
+    console.error("Failed to load settings:", e);
+  }
+  return confirm(message, { title, kind: "warning" });
+}
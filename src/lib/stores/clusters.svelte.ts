@@ -9,6 +9,11 @@ export interface Cluster {
   tags: string; // JSON-encoded array
   created_at: number;
   last_accessed: number;
+  server_version?: string | null;
+  pinned: boolean;
+  group_name?: string | null;
+  default_namespace?: string | null;
+  accept_invalid_certs: boolean;
 }
 
 class ClustersStore {